@@ -0,0 +1,69 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use reflectix::{TypeInfo, TypeInfoDynamic};
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Particle {
+    pub id: u64,
+    pub mass: f32,
+    pub charge: f32,
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+fn field_access(c: &mut Criterion) {
+    let mut particle = Particle::default();
+
+    c.bench_function("field by name", |b| {
+        let erased: &dyn TypeInfoDynamic = &particle;
+        b.iter(|| {
+            let z = black_box(erased).field(black_box("z").into()).unwrap();
+            *z.downcast_ref::<f64>().unwrap()
+        })
+    });
+
+    let z = Particle::INFO.handle("z").unwrap();
+    c.bench_function("field by handle", |b| {
+        b.iter(|| {
+            *z.get(black_box(&particle))
+                .unwrap()
+                .downcast_ref::<f64>()
+                .unwrap()
+        })
+    });
+
+    c.bench_function("set by name", |b| {
+        let erased: &mut dyn TypeInfoDynamic = &mut particle;
+        b.iter(|| {
+            erased
+                .set_field(black_box("z").into(), Box::new(1.0f64))
+                .unwrap()
+        })
+    });
+
+    c.bench_function("set by handle", |b| {
+        b.iter(|| z.set(black_box(&mut particle), 1.0f64).unwrap())
+    });
+}
+
+fn construction(c: &mut Criterion) {
+    let prototype = Particle::default();
+
+    c.bench_function("construct_struct", |b| {
+        b.iter(|| {
+            prototype
+                .construct_struct(vec![
+                    Box::new(1u64),
+                    Box::new(1.0f32),
+                    Box::new(0.0f32),
+                    Box::new(0.0f64),
+                    Box::new(0.0f64),
+                    Box::new(0.0f64),
+                ])
+                .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, field_access, construction);
+criterion_main!(benches);
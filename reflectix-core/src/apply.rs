@@ -0,0 +1,311 @@
+//! Deep copy of reflected values into existing ones
+//!
+//! [`TypeInfo::clone_from_dynamic`] copies erased value into value of the same type in place,
+//! reusing allocations of destination where possible:
+//!
+//! - primitives are copied with [`Clone::clone_from`], so strings keep their buffers
+//! - fields of structs and of matching enum variants, as well as elements of arrays, are copied one by one
+//! - lists keep common prefix of elements, only extra elements are pushed or removed
+//!
+//! New values are constructed (with [`TypeInfoDynamic::construct_struct`] and [`TypeInfoDynamic::construct_enum`])
+//! only when there is nothing to reuse: when active variant differs, when list grows, and for entries of maps.
+//! Meant for per-frame synchronization paths, where allocation churn matters
+//!
+//! [`TypeInfo::clone_from_dynamic`]: crate::TypeInfo::clone_from_dynamic
+use std::any::Any;
+use std::net::IpAddr;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::{
+    Data, FieldAccessError, FieldId, Fields, RuntimeConstructError, TypeInfoDynamic, UnsizeableMut,
+};
+
+/// Failure of copying value into another one
+#[derive(thiserror::Error, Debug)]
+pub enum ApplyError {
+    /// Source is of different type than destination
+    #[error("Can't copy `{actual}` into `{expected}`")]
+    UnmatchingType {
+        /// Name of destination type
+        expected: &'static str,
+        /// Name of source type
+        actual: &'static str,
+    },
+
+    /// Values of this type can't be copied reflectively
+    #[error("Can't copy value of `{0}`")]
+    Unsupported(&'static str),
+
+    /// Field of source or destination is inaccessible
+    #[error(transparent)]
+    Access(#[from] FieldAccessError),
+
+    /// Failed to construct new value for destination
+    #[error(transparent)]
+    Construct(#[from] RuntimeConstructError),
+}
+
+// Leaf type, which is copied with it's `Clone` implementation
+struct Leaf {
+    clone_into: fn(&UnsizeableMut<'_>, &dyn Any) -> bool,
+    clone_boxed: fn(&dyn Any) -> Option<Box<dyn Any>>,
+}
+
+// `false` if either of values is not `T`
+fn clone_leaf_into<T: Clone + 'static>(dst: &UnsizeableMut<'_>, src: &dyn Any) -> bool {
+    match (dst.downcast_mut::<T>(), src.downcast_ref::<T>()) {
+        (Some(dst), Some(src)) => {
+            dst.clone_from(src);
+            true
+        }
+        _ => false,
+    }
+}
+
+fn clone_leaf_boxed<T: Clone + 'static>(src: &dyn Any) -> Option<Box<dyn Any>> {
+    src.downcast_ref::<T>()
+        .map(|src| Box::new(src.clone()) as Box<dyn Any>)
+}
+
+macro_rules! leaves {
+    ($($ty:ty),*) => {
+        &[$(Leaf {
+            clone_into: clone_leaf_into::<$ty>,
+            clone_boxed: clone_leaf_boxed::<$ty>,
+        }),*]
+    };
+}
+
+macro_rules! primitive_leaves {
+    ($($ty:ty),*) => {
+        static PRIMITIVES: &[Leaf] = leaves!($($ty),*);
+    };
+}
+
+macro_rules! nonzero_leaves {
+    ($($ty:ty),*) => {
+        static NONZERO: &[Leaf] = leaves!($($ty),*);
+    };
+}
+
+for_each_primitive!(
+    primitive_leaves,
+    &'static str,
+    std::borrow::Cow<'static, str>
+);
+for_each_nonzero!(nonzero_leaves);
+macro_rules! opaque_leaves {
+    ($($ty:ty),*) => {
+        static OPAQUE: &[Leaf] = leaves!($($ty),*);
+    };
+}
+
+for_each_opaque!(opaque_leaves);
+// json values change their metadata depending on contents, so they are copied as a whole
+#[cfg(feature = "json")]
+static JSON: &[Leaf] = leaves!(serde_json::Value);
+
+fn leaves() -> impl Iterator<Item = &'static Leaf> {
+    let leaves = PRIMITIVES.iter().chain(NONZERO).chain(OPAQUE);
+    #[cfg(feature = "json")]
+    let leaves = leaves.chain(JSON);
+    leaves
+}
+
+/// Copy `src` into value, which `dst` points to
+pub(crate) fn clone_into(
+    dst: UnsizeableMut<'_>,
+    src: &dyn TypeInfoDynamic,
+) -> Result<(), ApplyError> {
+    let ty = src.get_dynamic();
+    let src_any: &dyn Any = src;
+    if dst.target_id != src_any.type_id() {
+        return Err(ApplyError::UnmatchingType {
+            expected: dst.as_dynamic().get_dynamic().ident,
+            actual: ty.ident,
+        });
+    }
+
+    if leaves().any(|leaf| (leaf.clone_into)(&dst, src_any)) {
+        return Ok(());
+    }
+    // wrappers, such as `Box<T>`, are copied through the values they wrap
+    if let Some(src) = src.pointee() {
+        return clone_into(dst.into_dynamic().pointee_mut()?, src);
+    }
+    // and values behind interior mutability are copied while guard of source is held
+    if let Some(shared) = src.as_shared() {
+        let src = shared.read()?;
+        return clone_into(dst.into_dynamic().pointee_mut()?, &*src);
+    }
+
+    match &ty.data {
+        Data::Struct(fields) | Data::Tuple(fields) => clone_fields(dst.into_dynamic(), src, fields),
+        Data::Enum(_) => match (dst.as_dynamic().variant(), src.variant()) {
+            (Some(current), Some(variant)) if current.ident == variant.ident => {
+                clone_fields(dst.into_dynamic(), src, &variant.fields)
+            }
+            _ => replace(dst, src),
+        },
+        Data::Optional(_) => {
+            let is_some = dst.as_dynamic().field(FieldId::Index(0)).is_ok();
+            match src.field(FieldId::Index(0)) {
+                Ok(value) if is_some => {
+                    clone_into(dst.field_mut(FieldId::Index(0))?, value.as_dynamic())
+                }
+                _ => replace(dst, src),
+            }
+        }
+        Data::List(_) => clone_list(dst.into_dynamic(), src),
+        Data::Array { len, .. } => clone_elements(dst.into_dynamic(), src, *len),
+        Data::Map { .. } => clone_map(dst.into_dynamic(), src),
+        Data::Unit => Ok(()),
+        Data::Primitive | Data::Union(_) => Err(ApplyError::Unsupported(ty.ident)),
+    }
+}
+
+fn clone_fields(
+    dst: &mut dyn TypeInfoDynamic,
+    src: &dyn TypeInfoDynamic,
+    fields: &Fields,
+) -> Result<(), ApplyError> {
+    for field in fields.as_slice() {
+        let value = src.field(field.id.clone())?;
+        clone_into(dst.field_mut(field.id.clone())?, value.as_dynamic())?;
+    }
+    Ok(())
+}
+
+fn clone_list(dst: &mut dyn TypeInfoDynamic, src: &dyn TypeInfoDynamic) -> Result<(), ApplyError> {
+    let unsupported = || ApplyError::Unsupported(src.get_dynamic().ident);
+    let len = src.as_list().ok_or_else(unsupported)?.len();
+
+    let list = dst.as_list_mut().ok_or_else(unsupported)?;
+    while list.len() > len {
+        list.remove(list.len() - 1)?;
+    }
+
+    let common = list.len();
+    clone_elements(dst, src, common)?;
+    for index in common..len {
+        let value = dynamic_clone(src.field(FieldId::Index(index))?.as_dynamic())?;
+        dst.as_list_mut().ok_or_else(unsupported)?.push(value)?;
+    }
+    Ok(())
+}
+
+// copies first `len` elements in place
+fn clone_elements(
+    dst: &mut dyn TypeInfoDynamic,
+    src: &dyn TypeInfoDynamic,
+    len: usize,
+) -> Result<(), ApplyError> {
+    for index in 0..len {
+        let value = src.field(FieldId::Index(index))?;
+        clone_into(dst.field_mut(FieldId::Index(index))?, value.as_dynamic())?;
+    }
+    Ok(())
+}
+
+// values of maps can't be borrowed mutably by arbitrary key,
+// so entries are replaced, and entries missing in `src` are removed
+fn clone_map(dst: &mut dyn TypeInfoDynamic, src: &dyn TypeInfoDynamic) -> Result<(), ApplyError> {
+    let unsupported = || ApplyError::Unsupported(src.get_dynamic().ident);
+    let source = src.as_map().ok_or_else(unsupported)?;
+
+    let mut stale = Vec::new();
+    for (key, _) in dst.as_map().ok_or_else(unsupported)?.iter() {
+        if source.get(dynamic_clone(key.as_dynamic())?).is_err() {
+            stale.push(dynamic_clone(key.as_dynamic())?);
+        }
+    }
+
+    let map = dst.as_map_mut().ok_or_else(unsupported)?;
+    for key in stale {
+        map.remove(key)?;
+    }
+    for (key, value) in source.iter() {
+        map.insert(
+            dynamic_clone(key.as_dynamic())?,
+            dynamic_clone(value.as_dynamic())?,
+        )?;
+    }
+    Ok(())
+}
+
+fn replace(dst: UnsizeableMut<'_>, src: &dyn TypeInfoDynamic) -> Result<(), ApplyError> {
+    dst.set(dynamic_clone(src)?)?;
+    Ok(())
+}
+
+/// Construct deep copy of `src`
+pub(crate) fn dynamic_clone(src: &dyn TypeInfoDynamic) -> Result<Box<dyn Any>, ApplyError> {
+    let src_any: &dyn Any = src;
+    if let Some(value) = leaves().find_map(|leaf| (leaf.clone_boxed)(src_any)) {
+        return Ok(value);
+    }
+
+    // wrappers, such as `Box<T>`, are rebuilt around copy of the value they wrap
+    if let Some(inner) = src.pointee() {
+        return Ok(src.construct_wrapper(dynamic_clone(inner)?)?.into_any());
+    }
+    // and values behind interior mutability are copied while guard is held
+    if let Some(shared) = src.as_shared() {
+        let inner = dynamic_clone(&*shared.read()?)?;
+        return Ok(src.construct_wrapper(inner)?.into_any());
+    }
+
+    let ty = src.get_dynamic();
+    let unsupported = || ApplyError::Unsupported(ty.ident);
+    let constructed = match &ty.data {
+        Data::Struct(fields) | Data::Tuple(fields) => {
+            src.construct_struct(clone_args(src, fields)?)?
+        }
+        Data::Enum(_) => {
+            let variant = src.variant().ok_or_else(unsupported)?;
+            src.construct_enum(variant.ident, clone_args(src, &variant.fields)?)?
+        }
+        Data::Optional(_) => match src.field(FieldId::Index(0)) {
+            Ok(value) => src.construct_enum("Some", vec![dynamic_clone(value.as_dynamic())?])?,
+            Err(_) => src.construct_enum("None", Vec::new())?,
+        },
+        Data::List(_) => {
+            let len = src.as_list().ok_or_else(unsupported)?.len();
+            src.construct_struct(clone_elements_args(src, len)?)?
+        }
+        Data::Array { len, .. } => src.construct_struct(clone_elements_args(src, *len)?)?,
+        Data::Map { .. } => {
+            let mut entries = Vec::new();
+            for (key, value) in src.as_map().ok_or_else(unsupported)?.iter() {
+                entries.push(dynamic_clone(key.as_dynamic())?);
+                entries.push(dynamic_clone(value.as_dynamic())?);
+            }
+            src.construct_struct(entries)?
+        }
+        Data::Unit => src.construct_struct(Vec::new())?,
+        Data::Primitive | Data::Union(_) => return Err(unsupported()),
+    };
+    Ok(constructed.into_any())
+}
+
+fn clone_elements_args(
+    src: &dyn TypeInfoDynamic,
+    len: usize,
+) -> Result<Vec<Box<dyn Any>>, ApplyError> {
+    (0..len)
+        .map(|index| dynamic_clone(src.field(FieldId::Index(index))?.as_dynamic()))
+        .collect()
+}
+
+fn clone_args(src: &dyn TypeInfoDynamic, fields: &Fields) -> Result<Vec<Box<dyn Any>>, ApplyError> {
+    fields
+        .as_slice()
+        .iter()
+        .map(|field| dynamic_clone(src.field(field.id.clone())?.as_dynamic()))
+        .collect()
+}
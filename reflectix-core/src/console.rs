@@ -0,0 +1,349 @@
+//! REPL-style console over live reflected values
+//!
+//! [`Console`] holds a set of named roots (mutable references to [`TypeInfoDynamic`] values)
+//! and executes simple text commands against them:
+//!
+//! - `ls` lists registered roots, `ls <path>` lists fields of value at `path`
+//! - `get <path>` prints value at `path`
+//! - `set <path> <value>` parses `value` as type of field and assigns it
+//! - `construct <path> [Variant] <args>...` builds new value of field's type with
+//!   [`TypeInfoDynamic::construct_struct`]/[`TypeInfoDynamic::construct_enum`] and assigns it
+//!
+//! Paths are dot-separated, starting with root name: `player.position.x`.
+//! Tuple fields are addressed by their index: `player.0`
+//!
+//! Only primitive values can be parsed from command arguments
+use std::any::Any;
+use std::collections::BTreeMap;
+
+use crate::{
+    Data, FieldAccessError, FieldId, Fields, RuntimeConstructError, Type, TypeInfoDynamic,
+    UnsizeableOwned,
+};
+
+/// Failure of console command
+#[derive(thiserror::Error, Debug)]
+pub enum ConsoleError {
+    /// Command is not one of known commands
+    #[error("Unknown command `{0}`")]
+    UnknownCommand(String),
+
+    /// Command requires more arguments
+    #[error("Missing argument: {0}")]
+    MissingArgument(&'static str),
+
+    /// First segment of path doesn't name registered root
+    #[error("Unknown root `{0}`")]
+    UnknownRoot(String),
+
+    /// Path segment doesn't name field of corresponding type
+    #[error("Type `{ty}` doesn't have field `{field}`")]
+    UnknownField {
+        /// Name of type which was accessed
+        ty: &'static str,
+        #[allow(missing_docs)]
+        field: String,
+    },
+
+    /// Enum doesn't have variant with such name
+    #[error("Type `{ty}` doesn't have variant `{variant}`")]
+    UnknownVariant {
+        /// Name of enum type
+        ty: &'static str,
+        #[allow(missing_docs)]
+        variant: String,
+    },
+
+    /// Argument can't be parsed as value of required type
+    #[error("Can't parse `{value}` as `{ty}`")]
+    Parse {
+        /// Name of required type
+        ty: &'static str,
+        #[allow(missing_docs)]
+        value: String,
+    },
+
+    /// Roots themselves can't be replaced, only their fields
+    #[error("Can't replace root, only it's fields")]
+    RootAssignment,
+
+    #[error(transparent)]
+    #[allow(missing_docs)]
+    FieldAccess(#[from] FieldAccessError),
+
+    #[error(transparent)]
+    #[allow(missing_docs)]
+    Construct(#[from] RuntimeConstructError),
+}
+
+/// Single parsed console command
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Command<'l> {
+    /// List roots, or fields of value at path
+    Ls(Option<&'l str>),
+    /// Print value at path
+    Get(&'l str),
+    /// Parse and assign value at path
+    Set {
+        #[allow(missing_docs)]
+        path: &'l str,
+        #[allow(missing_docs)]
+        value: &'l str,
+    },
+    /// Construct new value at path from arguments
+    ///
+    /// If value at path is enum, first argument is variant name
+    Construct {
+        #[allow(missing_docs)]
+        path: &'l str,
+        #[allow(missing_docs)]
+        args: Vec<&'l str>,
+    },
+}
+
+impl<'l> Command<'l> {
+    /// Parse single command line
+    ///
+    /// Arguments are separated by whitespace, except for value of `set`, which spans until the end of line
+    pub fn parse(line: &'l str) -> Result<Self, ConsoleError> {
+        let split_word = |line: &'l str| {
+            let line = line.trim();
+            line.split_once(char::is_whitespace)
+                .map(|(word, rest)| (word, rest.trim()))
+                .unwrap_or((line, ""))
+        };
+
+        let (command, rest) = split_word(line);
+        let (path, rest) = split_word(rest);
+
+        let path = || match path {
+            "" => Err(ConsoleError::MissingArgument("path")),
+            path => Ok(path),
+        };
+
+        let command = match command {
+            "" => return Err(ConsoleError::MissingArgument("command")),
+            "ls" => Command::Ls(path().ok()),
+            "get" => Command::Get(path()?),
+            "set" => Command::Set {
+                path: path()?,
+                value: match rest {
+                    "" => return Err(ConsoleError::MissingArgument("value")),
+                    value => value,
+                },
+            },
+            "construct" => Command::Construct {
+                path: path()?,
+                args: rest.split_whitespace().collect(),
+            },
+            unknown => return Err(ConsoleError::UnknownCommand(unknown.to_string())),
+        };
+
+        Ok(command)
+    }
+}
+
+/// Registry of live roots, which commands operate on
+#[derive(Default)]
+pub struct Console<'a> {
+    roots: BTreeMap<String, &'a mut dyn TypeInfoDynamic>,
+}
+
+impl<'a> Console<'a> {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make `root` accessible under `name`
+    ///
+    /// If there was root with same name, it will be replaced
+    pub fn register(&mut self, name: impl Into<String>, root: &'a mut dyn TypeInfoDynamic) {
+        self.roots.insert(name.into(), root);
+    }
+
+    /// Parse and execute single command, returning it's textual output
+    pub fn execute(&mut self, line: &str) -> Result<String, ConsoleError> {
+        match Command::parse(line)? {
+            Command::Ls(None) => Ok(self
+                .roots
+                .iter()
+                .map(|(name, root)| format!("{}: {}", name, root.get_dynamic().ident))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            Command::Ls(Some(path)) => {
+                let value = self.resolve_mut(path)?;
+                Ok(list_fields(value.get_dynamic()).join("\n"))
+            }
+            Command::Get(path) => Ok(display(self.resolve_mut(path)?)),
+            Command::Set { path, value } => {
+                let (parent, field) = self.resolve_parent_mut(path)?;
+                let id = lookup_field(parent.get_dynamic(), field)?;
+                let field = parent.field_mut(id)?;
+
+                let parsed = parse(field.as_dynamic().get_dynamic(), value)?;
+                field.set(parsed)?;
+                Ok(String::new())
+            }
+            Command::Construct { path, args } => {
+                let (parent, field) = self.resolve_parent_mut(path)?;
+                let id = lookup_field(parent.get_dynamic(), field)?;
+                let field = parent.field_mut(id)?;
+
+                let constructed = construct(field.as_dynamic(), &args)?;
+                field.set(constructed.into_any())?;
+                Ok(String::new())
+            }
+        }
+    }
+
+    fn root_mut(&mut self, name: &str) -> Result<&mut dyn TypeInfoDynamic, ConsoleError> {
+        match self.roots.get_mut(name) {
+            Some(root) => Ok(&mut **root),
+            None => Err(ConsoleError::UnknownRoot(name.to_string())),
+        }
+    }
+
+    fn resolve_mut(&mut self, path: &str) -> Result<&mut dyn TypeInfoDynamic, ConsoleError> {
+        let mut segments = path.split('.');
+        let root = segments.next().unwrap_or_default();
+
+        let mut current = self.root_mut(root)?;
+        for segment in segments {
+            let id = lookup_field(current.get_dynamic(), segment)?;
+            current = current.field_mut(id)?.into_dynamic();
+        }
+        Ok(current)
+    }
+
+    fn resolve_parent_mut<'p>(
+        &mut self,
+        path: &'p str,
+    ) -> Result<(&mut dyn TypeInfoDynamic, &'p str), ConsoleError> {
+        match path.rsplit_once('.') {
+            Some((parent, field)) => Ok((self.resolve_mut(parent)?, field)),
+            None => Err(ConsoleError::RootAssignment),
+        }
+    }
+}
+
+// `FieldId::Named` requires static string, so runtime name is matched against metadata.
+// Elements of lists and arrays have no metadata, they are addressed by their index
+fn lookup_field(ty: &'static Type, segment: &str) -> Result<FieldId, ConsoleError> {
+    if let (Data::List(_) | Data::Array { .. }, Ok(index)) = (&ty.data, segment.parse()) {
+        return Ok(FieldId::Index(index));
+    }
+    ty.find_field(segment)
+        .map(|field| field.id.clone())
+        .ok_or_else(|| ConsoleError::UnknownField {
+            ty: ty.ident,
+            field: segment.to_string(),
+        })
+}
+
+fn list_fields(ty: &'static Type) -> Vec<String> {
+    let fields = match &ty.data {
+        Data::Struct(fields) | Data::Tuple(fields) | Data::Union(fields) => {
+            fields.as_slice().iter().collect()
+        }
+        Data::Enum(variants) => variants
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.as_slice())
+            .collect(),
+        Data::Primitive
+        | Data::Unit
+        | Data::List(_)
+        | Data::Array { .. }
+        | Data::Optional(_)
+        | Data::Map { .. } => Vec::new(),
+    };
+
+    fields
+        .into_iter()
+        .map(|field| format!("{}: {}", field.id, field.ty.ident))
+        .collect()
+}
+
+fn construct(
+    prototype: &dyn TypeInfoDynamic,
+    args: &[&str],
+) -> Result<UnsizeableOwned, ConsoleError> {
+    let ty = prototype.get_dynamic();
+
+    let parse_args = |fields: &'static Fields, args: &[&str]| {
+        let fields = fields.as_slice();
+        // extra arguments would be silently dropped by `zip`
+        if args.len() > fields.len() {
+            return Err(ConsoleError::Construct(RuntimeConstructError::TooManyArgs));
+        }
+        fields
+            .iter()
+            .zip(args)
+            .map(|(field, arg)| parse(field.ty.get(), arg))
+            .collect::<Result<Vec<_>, _>>()
+    };
+
+    match &ty.data {
+        Data::Enum(variants) => {
+            let (variant, args) = args
+                .split_first()
+                .ok_or(ConsoleError::MissingArgument("variant"))?;
+            let variant = variants
+                .find(variant)
+                .ok_or_else(|| ConsoleError::UnknownVariant {
+                    ty: ty.ident,
+                    variant: variant.to_string(),
+                })?;
+
+            let args = parse_args(&variant.fields, args)?;
+            Ok(prototype.construct_enum(variant.ident, args)?)
+        }
+        Data::Struct(fields) => {
+            let args = parse_args(fields, args)?;
+            Ok(prototype.construct_struct(args)?)
+        }
+        _ => match args.is_empty() {
+            true => Ok(prototype.construct_struct(Vec::new())?),
+            false => Err(RuntimeConstructError::TooManyArgs.into()),
+        },
+    }
+}
+
+fn parse(ty: &'static Type, value: &str) -> Result<Box<dyn Any>, ConsoleError> {
+    crate::containers::parse_primitive(ty, value).ok_or_else(|| ConsoleError::Parse {
+        ty: ty.ident,
+        value: value.to_string(),
+    })
+}
+
+fn display(value: &dyn TypeInfoDynamic) -> String {
+    if let Some(primitive) = crate::diff::display_primitive(value) {
+        return primitive;
+    }
+
+    let ty = value.get_dynamic();
+    match &ty.data {
+        Data::Struct(Fields::Named(fields)) => {
+            let fields = fields
+                .iter()
+                .map(|field| match value.field(field.id.clone()) {
+                    Ok(x) => format!("{}: {}", field.id, display(x.as_dynamic())),
+                    Err(_) => format!("{}: ?", field.id),
+                })
+                .collect::<Vec<_>>();
+            format!("{} {{ {} }}", ty.ident, fields.join(", "))
+        }
+        Data::Struct(Fields::Indexed(fields)) | Data::Tuple(Fields::Indexed(fields)) => {
+            let fields = fields
+                .iter()
+                .map(|field| match value.field(field.id.clone()) {
+                    Ok(x) => display(x.as_dynamic()),
+                    Err(_) => "?".to_string(),
+                })
+                .collect::<Vec<_>>();
+            format!("{}({})", ty.ident, fields.join(", "))
+        }
+        _ => ty.ident.to_string(),
+    }
+}
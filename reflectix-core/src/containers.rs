@@ -0,0 +1,1271 @@
+//! Reflection of std containers, so they can be used as field types
+//!
+//! - `Vec<T>` is [`Data::List`], elements are accessed by [`FieldId::Index`].
+//!   [`TypeInfoDynamic::construct_struct`] builds vector out of elements, [`ReflectList`] allows to push and remove them
+//! - `Box<[T]>` is [`Data::List`] as well, pushing to it or removing from it reallocates the slice.
+//!   `&'static [T]` is [`Data::List`], elements of which can only be read
+//! - `[T; N]` is [`Data::Array`], elements are accessed by [`FieldId::Index`].
+//!   [`TypeInfoDynamic::construct_struct`] builds array out of exactly `N` elements
+//! - `()` is [`Data::Unit`], which is constructed without arguments
+//! - tuples of up to 12 elements are [`Data::Tuple`], elements are accessed by [`FieldId::Index`].
+//!   [`TypeInfoDynamic::construct_struct`] builds tuple out of all of it's elements
+//! - `Option<T>` is [`Data::Optional`], contained value is accessed by `FieldId::Index(0)`.
+//!   [`TypeInfoDynamic::construct_enum`] accepts `"Some"` with single argument and `"None"` without arguments
+//! - `HashMap<K, V>` and `BTreeMap<K, V>` are [`Data::Map`]. Entries are accessed by [`FieldId::Named`],
+//!   which is parsed into key type if keys aren't strings (including unit enums, by variant name). [`TypeInfoDynamic::construct_struct`] builds map out of
+//!   key-value pairs of arguments, [`ReflectMap`] allows to insert and remove entries
+//! - `Result<T, E>` is [`Data::Enum`] with variants `Ok` and `Err`, each of which has single field `FieldId::Index(0)`
+//! - `Box<T>` is transparent: it shares metadata of `T` and forwards everything to boxed value.
+//!   Together with `Option` it allows recursive types, such as `Option<Box<Self>>` fields
+//! - `Rc<T>` and `Arc<T>` are transparent too, but fields of shared value are accessed mutably only while
+//!   pointer is unique (see [`Rc::get_mut`]), otherwise it fails with [`FieldAccessError::ReadOnly`]
+//! - `Cow<'static, B>` shares metadata of it's owned form, such as `String` for `Cow<'static, str>`.
+//!   Borrowed value is copied into owned form on first mutable access, constructors produce owned value
+//! - `Pin<Box<T>>` is transparent as well, but fields of pinned value can't be accessed mutably.
+//!   Pinning of `T` may be structural for some of it's fields, and erased field can't be checked for `Unpin`,
+//!   so [`TypeInfoDynamic::field_mut`] fails with [`FieldAccessError::Pinned`]. Fields of `Unpin` types
+//!   are reachable mutably through [`Pin::get_mut`] instead
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::pin::Pin;
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::{
+    Data, DiscriminantRepr, Field, FieldAccessError, FieldId, Fields, GenericParamInfo,
+    GenericParamKind, Ownership, ReflectList, ReflectMap, ReflectShared, RuntimeConstructError,
+    Type, TypeInfo, TypeInfoDynamic, TypeRef, UnknownVariants, Unsizeable, UnsizeableMut,
+    UnsizeableOwned, Variant, Variants, Visibility,
+};
+
+// takes value, built by constructor of wrapped type, so it can be put back into wrapper
+pub(crate) fn downcast_constructed<T: TypeInfo>(
+    constructed: UnsizeableOwned,
+) -> Result<Box<T>, RuntimeConstructError> {
+    constructed
+        .downcast::<T>()
+        .map_err(|constructed| RuntimeConstructError::ForeignValue {
+            expected: T::INFO.ident,
+            found: constructed.get_dynamic().ident,
+        })
+}
+
+pub(crate) fn downcast_arg<T: TypeInfo>(
+    index: usize,
+    arg: Box<dyn Any>,
+    variant: Option<&'static str>,
+) -> Result<T, RuntimeConstructError> {
+    arg.downcast::<T>()
+        .map(|arg| *arg)
+        .map_err(|_| RuntimeConstructError::UnexpectedType {
+            index,
+            expected: T::INFO.ident,
+            field: FieldId::Index(index),
+            variant,
+        })
+}
+
+impl<T: TypeInfo> TypeInfoDynamic for Vec<T> {
+    fn get_dynamic(&self) -> &'static Type {
+        Self::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let elements = args
+            .into_iter()
+            .enumerate()
+            .map(|(index, arg)| downcast_arg::<T>(index, arg, None))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(UnsizeableOwned::new(elements))
+    }
+
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::NotEnum)
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        match id {
+            FieldId::Index(index) => self
+                .get(index)
+                .map(Unsizeable::new)
+                .ok_or(FieldAccessError::NotFound),
+            FieldId::Named(_) => Err(FieldAccessError::NotFound),
+        }
+    }
+
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        match id {
+            FieldId::Index(index) => self
+                .get_mut(index)
+                .map(UnsizeableMut::new)
+                .ok_or(FieldAccessError::NotFound),
+            FieldId::Named(_) => Err(FieldAccessError::NotFound),
+        }
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        Some(self)
+    }
+
+    fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+        Some(self)
+    }
+}
+
+impl<T: TypeInfo> ReflectList for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn push(&mut self, value: Box<dyn Any>) -> Result<(), FieldAccessError> {
+        let value = value
+            .downcast::<T>()
+            .map_err(|_| FieldAccessError::UnmatchingType)?;
+        Vec::push(self, *value);
+        Ok(())
+    }
+
+    fn remove(&mut self, index: usize) -> Result<UnsizeableOwned, FieldAccessError> {
+        if index >= Vec::len(self) {
+            return Err(FieldAccessError::NotFound);
+        }
+        Ok(UnsizeableOwned::new(Vec::remove(self, index)))
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for Vec<T> {
+    const INFO: &'static Type = &Type {
+        ident: "Vec",
+        data: Data::List(TypeRef::of::<T>()),
+        meta: &[],
+        docs: "",
+        non_exhaustive: false,
+        generics: &[GenericParamInfo {
+            name: "T",
+            kind: GenericParamKind::Type(TypeRef::of::<T>()),
+        }],
+        type_id: std::any::TypeId::of::<Vec<T>>(),
+        size: std::mem::size_of::<Vec<T>>(),
+        align: std::mem::align_of::<Vec<T>>(),
+    };
+}
+
+impl<T: TypeInfo> TypeInfoDynamic for Box<[T]> {
+    fn get_dynamic(&self) -> &'static Type {
+        Self::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let elements = args
+            .into_iter()
+            .enumerate()
+            .map(|(index, arg)| downcast_arg::<T>(index, arg, None))
+            .collect::<Result<Box<[_]>, _>>()?;
+        Ok(UnsizeableOwned::new(elements))
+    }
+
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::NotEnum)
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        match id {
+            FieldId::Index(index) => self
+                .get(index)
+                .map(Unsizeable::new)
+                .ok_or(FieldAccessError::NotFound),
+            FieldId::Named(_) => Err(FieldAccessError::NotFound),
+        }
+    }
+
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        match id {
+            FieldId::Index(index) => self
+                .get_mut(index)
+                .map(UnsizeableMut::new)
+                .ok_or(FieldAccessError::NotFound),
+            FieldId::Named(_) => Err(FieldAccessError::NotFound),
+        }
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        Some(self)
+    }
+
+    fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+        Some(self)
+    }
+}
+
+// boxed slice can't grow in place, so it's reallocated as vector
+impl<T: TypeInfo> ReflectList for Box<[T]> {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn push(&mut self, value: Box<dyn Any>) -> Result<(), FieldAccessError> {
+        let value = value
+            .downcast::<T>()
+            .map_err(|_| FieldAccessError::UnmatchingType)?;
+        let mut elements = std::mem::take(self).into_vec();
+        elements.push(*value);
+        *self = elements.into_boxed_slice();
+        Ok(())
+    }
+
+    fn remove(&mut self, index: usize) -> Result<UnsizeableOwned, FieldAccessError> {
+        if index >= <[T]>::len(self) {
+            return Err(FieldAccessError::NotFound);
+        }
+        let mut elements = std::mem::take(self).into_vec();
+        let removed = elements.remove(index);
+        *self = elements.into_boxed_slice();
+        Ok(UnsizeableOwned::new(removed))
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for Box<[T]> {
+    const INFO: &'static Type = &Type {
+        ident: "Slice",
+        data: Data::List(TypeRef::of::<T>()),
+        meta: &[],
+        docs: "",
+        non_exhaustive: false,
+        generics: &[GenericParamInfo {
+            name: "T",
+            kind: GenericParamKind::Type(TypeRef::of::<T>()),
+        }],
+        type_id: std::any::TypeId::of::<Box<[T]>>(),
+        size: std::mem::size_of::<Box<[T]>>(),
+        align: std::mem::align_of::<Box<[T]>>(),
+    };
+}
+
+/// Static slices can only be read, just as other static references
+impl<T: TypeInfo> TypeInfoDynamic for &'static [T] {
+    fn get_dynamic(&self) -> &'static Type {
+        Self::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Reference)
+    }
+
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Reference)
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        match id {
+            FieldId::Index(index) => self
+                .get(index)
+                .map(Unsizeable::new)
+                .ok_or(FieldAccessError::NotFound),
+            FieldId::Named(_) => Err(FieldAccessError::NotFound),
+        }
+    }
+
+    fn field_mut<'s>(&'s mut self, _id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        Err(FieldAccessError::ReadOnly)
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        Some(self)
+    }
+
+    // so that attempts to modify are reported as `ReadOnly` rather than as wrong type
+    fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+        Some(self)
+    }
+}
+
+impl<T: TypeInfo> ReflectList for &'static [T] {
+    fn len(&self) -> usize {
+        <[T]>::len(self)
+    }
+
+    fn push(&mut self, _value: Box<dyn Any>) -> Result<(), FieldAccessError> {
+        Err(FieldAccessError::ReadOnly)
+    }
+
+    fn remove(&mut self, _index: usize) -> Result<UnsizeableOwned, FieldAccessError> {
+        Err(FieldAccessError::ReadOnly)
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for &'static [T] {
+    const INFO: &'static Type = &Type {
+        ident: "Slice",
+        data: Data::List(TypeRef::of::<T>()),
+        meta: &[],
+        docs: "",
+        non_exhaustive: false,
+        generics: &[GenericParamInfo {
+            name: "T",
+            kind: GenericParamKind::Type(TypeRef::of::<T>()),
+        }],
+        type_id: std::any::TypeId::of::<&'static [T]>(),
+        size: std::mem::size_of::<&'static [T]>(),
+        align: std::mem::align_of::<&'static [T]>(),
+    };
+}
+
+impl<T: TypeInfo, const N: usize> TypeInfoDynamic for [T; N] {
+    fn get_dynamic(&self) -> &'static Type {
+        Self::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        match args.len().cmp(&N) {
+            std::cmp::Ordering::Less => return Err(RuntimeConstructError::NotEnoughArgs),
+            std::cmp::Ordering::Greater => return Err(RuntimeConstructError::TooManyArgs),
+            std::cmp::Ordering::Equal => {}
+        }
+
+        let elements = args
+            .into_iter()
+            .enumerate()
+            .map(|(index, arg)| downcast_arg::<T>(index, arg, None))
+            .collect::<Result<Vec<_>, _>>()?;
+        let Ok(array) = <[T; N]>::try_from(elements) else {
+            unreachable!("number of elements is checked above")
+        };
+        Ok(UnsizeableOwned::new(array))
+    }
+
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::NotEnum)
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        match id {
+            FieldId::Index(index) => self
+                .get(index)
+                .map(Unsizeable::new)
+                .ok_or(FieldAccessError::NotFound),
+            FieldId::Named(_) => Err(FieldAccessError::NotFound),
+        }
+    }
+
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        match id {
+            FieldId::Index(index) => self
+                .get_mut(index)
+                .map(UnsizeableMut::new)
+                .ok_or(FieldAccessError::NotFound),
+            FieldId::Named(_) => Err(FieldAccessError::NotFound),
+        }
+    }
+}
+
+impl<T: TypeInfo, const N: usize> TypeInfo for [T; N] {
+    const INFO: &'static Type = &Type {
+        ident: "Array",
+        data: Data::Array {
+            element: TypeRef::of::<T>(),
+            len: N,
+        },
+        meta: &[],
+        docs: "",
+        non_exhaustive: false,
+        generics: &[
+            GenericParamInfo {
+                name: "T",
+                kind: GenericParamKind::Type(TypeRef::of::<T>()),
+            },
+            GenericParamInfo {
+                name: "N",
+                kind: GenericParamKind::Const(N as i128),
+            },
+        ],
+        type_id: std::any::TypeId::of::<[T; N]>(),
+        size: std::mem::size_of::<[T; N]>(),
+        align: std::mem::align_of::<[T; N]>(),
+    };
+}
+
+macro_rules! reflect_tuples {
+    ($(($($element:ident $index:tt),+)),+ $(,)?) => {$(
+        impl<$($element: TypeInfo),+> TypeInfoDynamic for ($($element,)+) {
+            fn get_dynamic(&self) -> &'static Type {
+                Self::INFO
+            }
+
+            fn construct_struct(
+                &self,
+                args: Vec<Box<dyn Any>>,
+            ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                match args.len().cmp(&[$($index),+].len()) {
+                    std::cmp::Ordering::Less => return Err(RuntimeConstructError::NotEnoughArgs),
+                    std::cmp::Ordering::Greater => return Err(RuntimeConstructError::TooManyArgs),
+                    std::cmp::Ordering::Equal => {}
+                }
+
+                let mut args = args.into_iter();
+                let tuple = ($(
+                    downcast_arg::<$element>($index, args.next().expect("number of arguments is checked above"), None)?,
+                )+);
+                Ok(UnsizeableOwned::new(tuple))
+            }
+
+            fn construct_enum(
+                &self,
+                _variant: &'static str,
+                _args: Vec<Box<dyn Any>>,
+            ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                Err(RuntimeConstructError::NotEnum)
+            }
+
+            fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+                match id {
+                    $(FieldId::Index($index) => Ok(Unsizeable::new(&self.$index)),)+
+                    _ => Err(FieldAccessError::NotFound),
+                }
+            }
+
+            fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+                match id {
+                    $(FieldId::Index($index) => Ok(UnsizeableMut::new(&mut self.$index)),)+
+                    _ => Err(FieldAccessError::NotFound),
+                }
+            }
+        }
+
+        impl<$($element: TypeInfo),+> TypeInfo for ($($element,)+) {
+            const INFO: &'static Type = &Type {
+                ident: "Tuple",
+                data: Data::Tuple(Fields::Indexed(&[$(Field {
+                    id: FieldId::Index($index),
+                    ty: TypeRef::of::<$element>(),
+                    type_id: std::any::TypeId::of::<$element>(),
+                    meta: &[],
+                    docs: "",
+                    vis: Visibility::Public,
+                    ownership: Ownership::Owned,
+                    offset: None,
+                    has_default: false,
+                }),+])),
+                meta: &[],
+                docs: "",
+                non_exhaustive: false,
+                generics: &[$(GenericParamInfo {
+                    name: stringify!($element),
+                    kind: GenericParamKind::Type(TypeRef::of::<$element>()),
+                }),+],
+                type_id: std::any::TypeId::of::<($($element,)+)>(),
+                size: std::mem::size_of::<($($element,)+)>(),
+                align: std::mem::align_of::<($($element,)+)>(),
+            };
+        }
+    )+};
+}
+
+impl TypeInfoDynamic for () {
+    fn get_dynamic(&self) -> &'static Type {
+        Self::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        if !args.is_empty() {
+            return Err(RuntimeConstructError::TooManyArgs);
+        }
+        Ok(UnsizeableOwned::new(()))
+    }
+
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::NotEnum)
+    }
+
+    fn field<'s>(&'s self, _id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        Err(FieldAccessError::Unit)
+    }
+
+    fn field_mut<'s>(&'s mut self, _id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        Err(FieldAccessError::Unit)
+    }
+}
+
+impl TypeInfo for () {
+    const INFO: &'static Type = &Type {
+        ident: "()",
+        data: Data::Unit,
+        meta: &[],
+        docs: "",
+        non_exhaustive: false,
+        generics: &[],
+        type_id: std::any::TypeId::of::<()>(),
+        size: 0,
+        align: 1,
+    };
+}
+
+reflect_tuples!(
+    (A 0),
+    (A 0, B 1),
+    (A 0, B 1, C 2),
+    (A 0, B 1, C 2, D 3),
+    (A 0, B 1, C 2, D 3, E 4),
+    (A 0, B 1, C 2, D 3, E 4, F 5),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10),
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11),
+);
+
+impl<T: TypeInfo> TypeInfoDynamic for Option<T> {
+    fn get_dynamic(&self) -> &'static Type {
+        Self::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::NotStruct)
+    }
+
+    fn construct_enum(
+        &self,
+        variant: &'static str,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let expected = match variant {
+            "Some" => 1,
+            "None" => 0,
+            _ => return Err(RuntimeConstructError::InvalidVariant),
+        };
+        match args.len().cmp(&expected) {
+            std::cmp::Ordering::Less => return Err(RuntimeConstructError::NotEnoughArgs),
+            std::cmp::Ordering::Greater => return Err(RuntimeConstructError::TooManyArgs),
+            std::cmp::Ordering::Equal => {}
+        }
+
+        let value = match args.into_iter().next() {
+            Some(arg) => Some(downcast_arg::<T>(0, arg, Some("Some"))?),
+            None => None,
+        };
+        Ok(UnsizeableOwned::new(value))
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        match (id, self) {
+            (FieldId::Index(0), Some(value)) => Ok(Unsizeable::new(value)),
+            _ => Err(FieldAccessError::NotFound),
+        }
+    }
+
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        match (id, self) {
+            (FieldId::Index(0), Some(value)) => Ok(UnsizeableMut::new(value)),
+            _ => Err(FieldAccessError::NotFound),
+        }
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for Option<T> {
+    const INFO: &'static Type = &Type {
+        ident: "Option",
+        data: Data::Optional(TypeRef::of::<T>()),
+        meta: &[],
+        docs: "",
+        non_exhaustive: false,
+        generics: &[GenericParamInfo {
+            name: "T",
+            kind: GenericParamKind::Type(TypeRef::of::<T>()),
+        }],
+        type_id: std::any::TypeId::of::<Option<T>>(),
+        size: std::mem::size_of::<Option<T>>(),
+        align: std::mem::align_of::<Option<T>>(),
+    };
+}
+
+impl<T: TypeInfo, E: TypeInfo> TypeInfoDynamic for Result<T, E> {
+    fn get_dynamic(&self) -> &'static Type {
+        Self::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::NotStruct)
+    }
+
+    fn construct_enum(
+        &self,
+        variant: &'static str,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        if !matches!(variant, "Ok" | "Err") {
+            return Err(RuntimeConstructError::InvalidVariant);
+        }
+        match args.len().cmp(&1) {
+            std::cmp::Ordering::Less => return Err(RuntimeConstructError::NotEnoughArgs),
+            std::cmp::Ordering::Greater => return Err(RuntimeConstructError::TooManyArgs),
+            std::cmp::Ordering::Equal => {}
+        }
+
+        let arg = args.into_iter().next().unwrap();
+        let value: Result<T, E> = match variant {
+            "Ok" => Ok(downcast_arg::<T>(0, arg, Some("Ok"))?),
+            _ => Err(downcast_arg::<E>(0, arg, Some("Err"))?),
+        };
+        Ok(UnsizeableOwned::new(value))
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        match (id, self) {
+            (FieldId::Index(0), Ok(value)) => Ok(Unsizeable::new(value)),
+            (FieldId::Index(0), Err(error)) => Ok(Unsizeable::new(error)),
+            _ => Err(FieldAccessError::NotFound),
+        }
+    }
+
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        match (id, self) {
+            (FieldId::Index(0), Ok(value)) => Ok(UnsizeableMut::new(value)),
+            (FieldId::Index(0), Err(error)) => Ok(UnsizeableMut::new(error)),
+            _ => Err(FieldAccessError::NotFound),
+        }
+    }
+
+    fn variant(&self) -> Option<&'static Variant> {
+        let Data::Enum(variants) = &Self::INFO.data else {
+            unreachable!()
+        };
+        variants.variants.get(self.is_err() as usize)
+    }
+}
+
+impl<T: TypeInfo, E: TypeInfo> TypeInfo for Result<T, E> {
+    const INFO: &'static Type = &Type {
+        ident: "Result",
+        data: Data::Enum(Variants {
+            variants: &[
+                Variant {
+                    ident: "Ok",
+                    discriminator: 0,
+                    fields: Fields::Indexed(&[Field {
+                        id: FieldId::Index(0),
+                        ty: TypeRef::of::<T>(),
+                        type_id: std::any::TypeId::of::<T>(),
+                        meta: &[],
+                        docs: "",
+                        vis: Visibility::Public,
+                        ownership: Ownership::Owned,
+                        offset: None,
+                        has_default: false,
+                    }]),
+                    meta: &[],
+                    docs: "",
+                    non_exhaustive: false,
+                },
+                Variant {
+                    ident: "Err",
+                    discriminator: 1,
+                    fields: Fields::Indexed(&[Field {
+                        id: FieldId::Index(0),
+                        ty: TypeRef::of::<E>(),
+                        type_id: std::any::TypeId::of::<E>(),
+                        meta: &[],
+                        docs: "",
+                        vis: Visibility::Public,
+                        ownership: Ownership::Owned,
+                        offset: None,
+                        has_default: false,
+                    }]),
+                    meta: &[],
+                    docs: "",
+                    non_exhaustive: false,
+                },
+            ],
+            repr: DiscriminantRepr::Isize,
+            contiguous: true,
+            unknown: UnknownVariants::Error,
+        }),
+        meta: &[],
+        docs: "",
+        non_exhaustive: false,
+        generics: &[
+            GenericParamInfo {
+                name: "T",
+                kind: GenericParamKind::Type(TypeRef::of::<T>()),
+            },
+            GenericParamInfo {
+                name: "E",
+                kind: GenericParamKind::Type(TypeRef::of::<E>()),
+            },
+        ],
+        type_id: std::any::TypeId::of::<Result<T, E>>(),
+        size: std::mem::size_of::<Result<T, E>>(),
+        align: std::mem::align_of::<Result<T, E>>(),
+    };
+}
+
+impl<T: TypeInfo> TypeInfoDynamic for Box<T> {
+    fn get_dynamic(&self) -> &'static Type {
+        T::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let inner = downcast_constructed::<T>((**self).construct_struct(args)?)?;
+        Ok(UnsizeableOwned::new(inner))
+    }
+
+    fn construct_enum(
+        &self,
+        variant: &'static str,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let inner = downcast_constructed::<T>((**self).construct_enum(variant, args)?)?;
+        Ok(UnsizeableOwned::new(inner))
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        (**self).field(id)
+    }
+
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        (**self).field_mut(id)
+    }
+
+    fn variant(&self) -> Option<&'static Variant> {
+        (**self).variant()
+    }
+
+    fn field_at<'s>(&'s self, position: usize) -> Result<Unsizeable<'s>, FieldAccessError> {
+        (**self).field_at(position)
+    }
+
+    fn field_at_mut<'s>(
+        &'s mut self,
+        position: usize,
+    ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        (**self).field_at_mut(position)
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        (**self).as_list()
+    }
+
+    fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+        (**self).as_list_mut()
+    }
+
+    fn as_map(&self) -> Option<&dyn ReflectMap> {
+        (**self).as_map()
+    }
+
+    fn as_map_mut(&mut self) -> Option<&mut dyn ReflectMap> {
+        (**self).as_map_mut()
+    }
+
+    fn as_shared(&self) -> Option<&dyn ReflectShared> {
+        (**self).as_shared()
+    }
+
+    fn pointee(&self) -> Option<&dyn TypeInfoDynamic> {
+        Some(&**self)
+    }
+
+    fn pointee_mut(&mut self) -> Result<UnsizeableMut<'_>, FieldAccessError> {
+        Ok(UnsizeableMut::new(&mut **self))
+    }
+
+    fn construct_wrapper(
+        &self,
+        inner: Box<dyn Any>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let inner = downcast_arg::<T>(0, inner, None)?;
+        Ok(UnsizeableOwned::new(Box::new(inner)))
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for Box<T> {
+    const INFO: &'static Type = T::INFO;
+}
+
+macro_rules! impl_shared {
+    ($($pointer:ident),*) => {
+        $(
+            impl<T: TypeInfo> TypeInfoDynamic for $pointer<T> {
+                fn get_dynamic(&self) -> &'static Type {
+                    T::INFO
+                }
+
+                fn construct_struct(
+                    &self,
+                    args: Vec<Box<dyn Any>>,
+                ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                    let inner = downcast_constructed::<T>((**self).construct_struct(args)?)?;
+                    Ok(UnsizeableOwned::new($pointer::new(*inner)))
+                }
+
+                fn construct_enum(
+                    &self,
+                    variant: &'static str,
+                    args: Vec<Box<dyn Any>>,
+                ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                    let inner = downcast_constructed::<T>((**self).construct_enum(variant, args)?)?;
+                    Ok(UnsizeableOwned::new($pointer::new(*inner)))
+                }
+
+                fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+                    (**self).field(id)
+                }
+
+                // value is mutable only while it's not shared
+                fn field_mut<'s>(
+                    &'s mut self,
+                    id: FieldId,
+                ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+                    $pointer::get_mut(self)
+                        .ok_or(FieldAccessError::ReadOnly)?
+                        .field_mut(id)
+                }
+
+                fn variant(&self) -> Option<&'static Variant> {
+                    (**self).variant()
+                }
+
+                fn field_at<'s>(&'s self, position: usize) -> Result<Unsizeable<'s>, FieldAccessError> {
+                    (**self).field_at(position)
+                }
+
+                fn field_at_mut<'s>(
+                    &'s mut self,
+                    position: usize,
+                ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+                    $pointer::get_mut(self)
+                        .ok_or(FieldAccessError::ReadOnly)?
+                        .field_at_mut(position)
+                }
+
+                fn as_list(&self) -> Option<&dyn ReflectList> {
+                    (**self).as_list()
+                }
+
+                fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+                    $pointer::get_mut(self)?.as_list_mut()
+                }
+
+                fn as_map(&self) -> Option<&dyn ReflectMap> {
+                    (**self).as_map()
+                }
+
+                fn as_map_mut(&mut self) -> Option<&mut dyn ReflectMap> {
+                    $pointer::get_mut(self)?.as_map_mut()
+                }
+
+                // shared state, such as `Arc<Mutex<T>>`, is reached through guard
+                fn as_shared(&self) -> Option<&dyn ReflectShared> {
+                    (**self).as_shared()
+                }
+
+                fn pointee(&self) -> Option<&dyn TypeInfoDynamic> {
+                    Some(&**self)
+                }
+
+                fn pointee_mut(&mut self) -> Result<UnsizeableMut<'_>, FieldAccessError> {
+                    $pointer::get_mut(self)
+                        .map(UnsizeableMut::new)
+                        .ok_or(FieldAccessError::ReadOnly)
+                }
+
+                fn construct_wrapper(
+                    &self,
+                    inner: Box<dyn Any>,
+                ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                    let inner = downcast_arg::<T>(0, inner, None)?;
+                    Ok(UnsizeableOwned::new($pointer::new(inner)))
+                }
+            }
+
+            impl<T: TypeInfo> TypeInfo for $pointer<T> {
+                const INFO: &'static Type = T::INFO;
+            }
+        )*
+    };
+}
+
+impl_shared!(Rc, Arc);
+
+impl<B> TypeInfoDynamic for Cow<'static, B>
+where
+    B: ToOwned + ?Sized + 'static,
+    B::Owned: TypeInfo,
+    &'static B: TypeInfoDynamic,
+{
+    fn get_dynamic(&self) -> &'static Type {
+        B::Owned::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let constructed = match self {
+            Cow::Borrowed(borrowed) => (*borrowed).to_owned().construct_struct(args),
+            Cow::Owned(owned) => owned.construct_struct(args),
+        };
+        wrap_cow::<B>(constructed?)
+    }
+
+    fn construct_enum(
+        &self,
+        variant: &'static str,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let constructed = match self {
+            Cow::Borrowed(borrowed) => (*borrowed).to_owned().construct_enum(variant, args),
+            Cow::Owned(owned) => owned.construct_enum(variant, args),
+        };
+        wrap_cow::<B>(constructed?)
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        match self {
+            Cow::Borrowed(borrowed) => borrowed.field(id),
+            Cow::Owned(owned) => owned.field(id),
+        }
+    }
+
+    // borrowed value is copied on first mutable access
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        self.to_mut().field_mut(id)
+    }
+
+    fn variant(&self) -> Option<&'static Variant> {
+        match self {
+            Cow::Borrowed(borrowed) => borrowed.variant(),
+            Cow::Owned(owned) => owned.variant(),
+        }
+    }
+
+    fn field_at<'s>(&'s self, position: usize) -> Result<Unsizeable<'s>, FieldAccessError> {
+        match self {
+            Cow::Borrowed(borrowed) => borrowed.field_at(position),
+            Cow::Owned(owned) => owned.field_at(position),
+        }
+    }
+
+    fn field_at_mut<'s>(
+        &'s mut self,
+        position: usize,
+    ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        self.to_mut().field_at_mut(position)
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        match self {
+            Cow::Borrowed(borrowed) => borrowed.as_list(),
+            Cow::Owned(owned) => owned.as_list(),
+        }
+    }
+
+    fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+        self.to_mut().as_list_mut()
+    }
+
+    fn as_map(&self) -> Option<&dyn ReflectMap> {
+        match self {
+            Cow::Borrowed(borrowed) => borrowed.as_map(),
+            Cow::Owned(owned) => owned.as_map(),
+        }
+    }
+
+    fn as_map_mut(&mut self) -> Option<&mut dyn ReflectMap> {
+        self.to_mut().as_map_mut()
+    }
+}
+
+impl<B> TypeInfo for Cow<'static, B>
+where
+    B: ToOwned + ?Sized + 'static,
+    B::Owned: TypeInfo,
+    &'static B: TypeInfoDynamic,
+{
+    const INFO: &'static Type = B::Owned::INFO;
+}
+
+// wraps value, constructed by owned form, into `Cow`
+fn wrap_cow<B>(constructed: UnsizeableOwned) -> Result<UnsizeableOwned, RuntimeConstructError>
+where
+    B: ToOwned + ?Sized + 'static,
+    B::Owned: TypeInfo,
+    &'static B: TypeInfoDynamic,
+{
+    let owned = downcast_constructed::<B::Owned>(constructed)?;
+    Ok(UnsizeableOwned::new(Cow::<'static, B>::Owned(*owned)))
+}
+
+impl<T: TypeInfo> TypeInfoDynamic for Pin<Box<T>> {
+    fn get_dynamic(&self) -> &'static Type {
+        T::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let inner = downcast_constructed::<T>(self.as_ref().get_ref().construct_struct(args)?)?;
+        Ok(UnsizeableOwned::new(Box::into_pin(inner)))
+    }
+
+    fn construct_enum(
+        &self,
+        variant: &'static str,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let constructed = self.as_ref().get_ref().construct_enum(variant, args)?;
+        let inner = downcast_constructed::<T>(constructed)?;
+        Ok(UnsizeableOwned::new(Box::into_pin(inner)))
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        self.as_ref().get_ref().field(id)
+    }
+
+    // handing out `&mut` of field would allow to move it out of pinned value
+    fn field_mut<'s>(&'s mut self, _id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        Err(FieldAccessError::Pinned)
+    }
+
+    fn variant(&self) -> Option<&'static Variant> {
+        self.as_ref().get_ref().variant()
+    }
+
+    fn field_at<'s>(&'s self, position: usize) -> Result<Unsizeable<'s>, FieldAccessError> {
+        self.as_ref().get_ref().field_at(position)
+    }
+
+    fn field_at_mut<'s>(
+        &'s mut self,
+        _position: usize,
+    ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        Err(FieldAccessError::Pinned)
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        self.as_ref().get_ref().as_list()
+    }
+
+    fn as_map(&self) -> Option<&dyn ReflectMap> {
+        self.as_ref().get_ref().as_map()
+    }
+
+    fn pointee(&self) -> Option<&dyn TypeInfoDynamic> {
+        Some(self.as_ref().get_ref())
+    }
+
+    fn pointee_mut(&mut self) -> Result<UnsizeableMut<'_>, FieldAccessError> {
+        Err(FieldAccessError::Pinned)
+    }
+
+    fn construct_wrapper(
+        &self,
+        inner: Box<dyn Any>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let inner = downcast_arg::<T>(0, inner, None)?;
+        Ok(UnsizeableOwned::new(Box::pin(inner)))
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for Pin<Box<T>> {
+    const INFO: &'static Type = T::INFO;
+}
+
+macro_rules! parse_primitives {
+    ($($ty:ty),*) => {
+        /// Parse `value` as primitive type, described by `ty`
+        pub(crate) fn parse_primitive(ty: &'static Type, value: &str) -> Option<Box<dyn Any>> {
+            $(
+                if ty == <$ty as TypeInfo>::INFO {
+                    return value.parse::<$ty>().ok().map(|value| Box::new(value) as Box<dyn Any>);
+                }
+            )*
+            None
+        }
+    };
+}
+
+for_each_primitive!(parse_primitives);
+
+// accepts key of map's key type as is, and parses string keys into primitive key type or unit variant
+pub(crate) fn map_key<K: TypeInfo>(key: Box<dyn Any>) -> Result<K, FieldAccessError> {
+    let key = match key.downcast::<K>() {
+        Ok(key) => return Ok(*key),
+        Err(key) => key,
+    };
+
+    let text = match key.downcast::<String>() {
+        Ok(text) => *text,
+        Err(key) => key
+            .downcast::<&'static str>()
+            .map_err(|_| FieldAccessError::UnmatchingType)?
+            .to_string(),
+    };
+    parse_primitive(K::INFO, &text)
+        .and_then(|key| key.downcast::<K>().ok())
+        .map(|key| *key)
+        .or_else(|| K::from_unit_variant(&text))
+        .ok_or(FieldAccessError::UnmatchingType)
+}
+
+macro_rules! reflect_map {
+    ($map:ident, $ident:literal, $($key_bound:tt)+) => {
+        impl<K: TypeInfo + $($key_bound)+, V: TypeInfo> TypeInfoDynamic for $map<K, V> {
+            fn get_dynamic(&self) -> &'static Type {
+                Self::INFO
+            }
+
+            fn construct_struct(
+                &self,
+                args: Vec<Box<dyn Any>>,
+            ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                if args.len() % 2 != 0 {
+                    return Err(RuntimeConstructError::NotEnoughArgs);
+                }
+
+                let mut map = $map::new();
+                let mut args = args.into_iter().enumerate();
+                while let (Some((key_index, key)), Some((value_index, value))) = (args.next(), args.next()) {
+                    let key = downcast_arg::<K>(key_index, key, None)?;
+                    let value = downcast_arg::<V>(value_index, value, None)?;
+                    map.insert(key, value);
+                }
+                Ok(UnsizeableOwned::new(map))
+            }
+
+            fn construct_enum(
+                &self,
+                _variant: &'static str,
+                _args: Vec<Box<dyn Any>>,
+            ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                Err(RuntimeConstructError::NotEnum)
+            }
+
+            fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+                match id {
+                    FieldId::Named(name) => ReflectMap::get(self, Box::new(name)),
+                    FieldId::Index(_) => Err(FieldAccessError::NotFound),
+                }
+            }
+
+            fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+                let FieldId::Named(name) = id else {
+                    return Err(FieldAccessError::NotFound);
+                };
+                let key = map_key::<K>(Box::new(name)).map_err(|_| FieldAccessError::NotFound)?;
+                self.get_mut(&key)
+                    .map(UnsizeableMut::new)
+                    .ok_or(FieldAccessError::NotFound)
+            }
+
+            fn as_map(&self) -> Option<&dyn ReflectMap> {
+                Some(self)
+            }
+
+            fn as_map_mut(&mut self) -> Option<&mut dyn ReflectMap> {
+                Some(self)
+            }
+        }
+
+        impl<K: TypeInfo + $($key_bound)+, V: TypeInfo> ReflectMap for $map<K, V> {
+            fn len(&self) -> usize {
+                $map::len(self)
+            }
+
+            fn get(&self, key: Box<dyn Any>) -> Result<Unsizeable<'_>, FieldAccessError> {
+                let key = map_key::<K>(key).map_err(|_| FieldAccessError::NotFound)?;
+                $map::get(self, &key)
+                    .map(Unsizeable::new)
+                    .ok_or(FieldAccessError::NotFound)
+            }
+
+            fn insert(
+                &mut self,
+                key: Box<dyn Any>,
+                value: Box<dyn Any>,
+            ) -> Result<Option<UnsizeableOwned>, FieldAccessError> {
+                let key = map_key::<K>(key)?;
+                let value = value
+                    .downcast::<V>()
+                    .map_err(|_| FieldAccessError::UnmatchingType)?;
+                Ok($map::insert(self, key, *value).map(UnsizeableOwned::new))
+            }
+
+            fn remove(&mut self, key: Box<dyn Any>) -> Result<UnsizeableOwned, FieldAccessError> {
+                let key = map_key::<K>(key).map_err(|_| FieldAccessError::NotFound)?;
+                $map::remove(self, &key)
+                    .map(UnsizeableOwned::new)
+                    .ok_or(FieldAccessError::NotFound)
+            }
+
+            fn iter(&self) -> Box<dyn Iterator<Item = (Unsizeable<'_>, Unsizeable<'_>)> + '_> {
+                Box::new($map::iter(self).map(|(key, value)| (Unsizeable::new(key), Unsizeable::new(value))))
+            }
+        }
+
+        impl<K: TypeInfo + $($key_bound)+, V: TypeInfo> TypeInfo for $map<K, V> {
+            const INFO: &'static Type = &Type {
+                ident: $ident,
+                data: Data::Map {
+                    key: TypeRef::of::<K>(),
+                    value: TypeRef::of::<V>(),
+                },
+                meta: &[],
+                docs: "",
+                non_exhaustive: false,
+                generics: &[GenericParamInfo { name: "K", kind: GenericParamKind::Type(TypeRef::of::<K>()) }, GenericParamInfo { name: "V", kind: GenericParamKind::Type(TypeRef::of::<V>()) }],
+                type_id: std::any::TypeId::of::<$map<K, V>>(),
+                size: std::mem::size_of::<$map<K, V>>(),
+                align: std::mem::align_of::<$map<K, V>>(),
+            };
+        }
+    };
+}
+
+reflect_map!(HashMap, "HashMap", Eq + std::hash::Hash);
+reflect_map!(BTreeMap, "BTreeMap", Ord);
@@ -0,0 +1,281 @@
+//! Import and export of flat tabular data as CSV
+//!
+//! [`to_csv`] writes rows of struct with named fields one per line, with names of fields as header.
+//! [`from_csv`] reads them back: columns are matched to fields by header, so their order doesn't matter,
+//! and trailing fields may be left out if constructor has defaults for them (see [`crate::named`]).
+//!
+//! Only primitive fields are supported, they are written with [`Display`](std::fmt::Display) and parsed
+//! with [`FromStr`](std::str::FromStr) of their type. Values are quoted as in RFC 4180 when they contain separators,
+//! quotes or line breaks
+use std::any::Any;
+use std::io::Read;
+
+use crate::named::{construct_struct_named, UnknownFields};
+use crate::{
+    Data, Field, FieldId, Fields, RuntimeConstructError, Type, TypeInfoDynamic, UnsizeableOwned,
+};
+
+/// Failure of CSV import or export
+#[derive(thiserror::Error, Debug)]
+pub enum CsvError {
+    /// Type is not a struct with named fields
+    #[error("Type `{0}` is not a struct with named fields")]
+    NotNamedStruct(&'static str),
+    /// Field is not of primitive type
+    #[error("Field `{field}` of `{ty}` is not primitive")]
+    Unsupported {
+        #[allow(missing_docs)]
+        ty: &'static str,
+        #[allow(missing_docs)]
+        field: FieldId,
+    },
+    /// Rows are of different types
+    #[error("Row of type `{actual}` in table of `{expected}`")]
+    UnmatchingType {
+        #[allow(missing_docs)]
+        expected: &'static str,
+        #[allow(missing_docs)]
+        actual: &'static str,
+    },
+    /// Input is not a valid CSV
+    #[error("Malformed CSV at line {line}: {reason}")]
+    Syntax {
+        /// Line, on which record starts, counted from 1
+        line: usize,
+        #[allow(missing_docs)]
+        reason: &'static str,
+    },
+    /// Value can't be parsed as type of it's field
+    #[error("Can't parse `{value}` as `{field}` at line {line}")]
+    Parse {
+        #[allow(missing_docs)]
+        line: usize,
+        #[allow(missing_docs)]
+        field: FieldId,
+        #[allow(missing_docs)]
+        value: String,
+    },
+    /// Row can't be constructed from it's values
+    #[error("Can't construct row at line {line}")]
+    Construct {
+        #[allow(missing_docs)]
+        line: usize,
+        #[source]
+        error: RuntimeConstructError,
+    },
+    #[allow(missing_docs)]
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Write `rows` as CSV table with header
+///
+/// All rows must be of the same type. Empty table is written as empty string, since there is no type to take header from
+pub fn to_csv(rows: &[&dyn TypeInfoDynamic]) -> Result<String, CsvError> {
+    let mut out = String::new();
+    let Some(first) = rows.first() else {
+        return Ok(out);
+    };
+
+    let ty = first.get_dynamic();
+    let type_id = <dyn Any>::type_id(*first);
+    let fields = named_fields(ty)?;
+    write_record(&mut out, fields.iter().map(|field| field.id.to_string()));
+
+    for row in rows {
+        if <dyn Any>::type_id(*row) != type_id {
+            return Err(CsvError::UnmatchingType {
+                expected: ty.ident,
+                actual: row.get_dynamic().ident,
+            });
+        }
+
+        let values = fields
+            .iter()
+            .map(|field| {
+                row.field(field.id.clone())
+                    .ok()
+                    .and_then(|value| crate::diff::display_primitive(value.as_dynamic()))
+                    .ok_or_else(|| CsvError::Unsupported {
+                        ty: ty.ident,
+                        field: field.id.clone(),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        write_record(&mut out, values);
+    }
+    Ok(out)
+}
+
+/// Read CSV table with header into values of the same type as `prototype`
+///
+/// Columns, which don't belong to any field, are [`RuntimeConstructError::UnknownField`]
+pub fn from_csv(
+    prototype: &dyn TypeInfoDynamic,
+    mut reader: impl Read,
+) -> Result<Vec<UnsizeableOwned>, CsvError> {
+    let ty = prototype.get_dynamic();
+    let fields = named_fields(ty)?;
+
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    let mut records = Records::new(&input);
+
+    let Some((_, header)) = records.next().transpose()? else {
+        return Ok(Vec::new());
+    };
+    // type of every column, `None` for columns, which don't belong to any field
+    let columns = header
+        .iter()
+        .map(|name| {
+            let Some(field) = fields.iter().find(|field| field.id.matches(name)) else {
+                return Ok(None);
+            };
+            match field.ty.get().data {
+                Data::Primitive => Ok(Some((field.id.clone(), field.ty.get()))),
+                _ => Err(CsvError::Unsupported {
+                    ty: ty.ident,
+                    field: field.id.clone(),
+                }),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut rows = Vec::new();
+    for record in records {
+        let (line, values) = record?;
+        if values.len() != header.len() {
+            return Err(CsvError::Syntax {
+                line,
+                reason: "number of values doesn't match header",
+            });
+        }
+
+        let args = header
+            .iter()
+            .zip(&columns)
+            .zip(values)
+            .map(|((name, column), value)| {
+                let arg: Box<dyn Any> = match column {
+                    Some((field, ty)) => crate::containers::parse_primitive(ty, &value)
+                        .ok_or_else(|| CsvError::Parse {
+                            line,
+                            field: field.clone(),
+                            value,
+                        })?,
+                    // passed as is, so constructor reports it as unknown
+                    None => Box::new(value),
+                };
+                Ok((name.as_str(), arg))
+            })
+            .collect::<Result<Vec<_>, CsvError>>()?;
+
+        let row = construct_struct_named(prototype, args, UnknownFields::Error)
+            .map_err(|error| CsvError::Construct { line, error })?;
+        rows.push(row.value);
+    }
+    Ok(rows)
+}
+
+fn named_fields(ty: &'static Type) -> Result<&'static [Field], CsvError> {
+    match &ty.data {
+        Data::Struct(Fields::Named(fields)) => Ok(fields),
+        _ => Err(CsvError::NotNamedStruct(ty.ident)),
+    }
+}
+
+fn write_record(out: &mut String, values: impl IntoIterator<Item = String>) {
+    let values = values.into_iter().collect::<Vec<_>>();
+    // single empty value would be a blank line, which is skipped on reading
+    let lone = values.len() == 1;
+    for (index, value) in values.into_iter().enumerate() {
+        if index > 0 {
+            out.push(',');
+        }
+        if value.contains([',', '"', '\n', '\r']) || (lone && value.is_empty()) {
+            out.push('"');
+            out.push_str(&value.replace('"', "\"\""));
+            out.push('"');
+        } else {
+            out.push_str(&value);
+        }
+    }
+    out.push('\n');
+}
+
+// Records of CSV input together with line they start on
+struct Records<'i> {
+    chars: std::iter::Peekable<std::str::Chars<'i>>,
+    line: usize,
+}
+
+impl<'i> Records<'i> {
+    fn new(input: &'i str) -> Self {
+        Self {
+            chars: input.chars().peekable(),
+            line: 1,
+        }
+    }
+
+    fn record(&mut self) -> Result<Vec<String>, CsvError> {
+        let start = self.line;
+        let mut values = Vec::new();
+        let mut value = String::new();
+        let mut quoted = false;
+
+        while let Some(char) = self.chars.next() {
+            match char {
+                '"' if quoted => {
+                    if self.chars.next_if_eq(&'"').is_some() {
+                        value.push('"');
+                    } else {
+                        quoted = false;
+                    }
+                }
+                '"' if value.is_empty() => quoted = true,
+                '"' => {
+                    return Err(CsvError::Syntax {
+                        line: start,
+                        reason: "quote inside of unquoted value",
+                    })
+                }
+                '\n' if quoted => {
+                    self.line += 1;
+                    value.push(char);
+                }
+                ',' if !quoted => values.push(std::mem::take(&mut value)),
+                '\r' if !quoted && self.chars.peek() == Some(&'\n') => {}
+                '\n' => {
+                    self.line += 1;
+                    values.push(value);
+                    return Ok(values);
+                }
+                _ => value.push(char),
+            }
+        }
+
+        if quoted {
+            return Err(CsvError::Syntax {
+                line: start,
+                reason: "unterminated quoted value",
+            });
+        }
+        values.push(value);
+        Ok(values)
+    }
+}
+
+impl Iterator for Records<'_> {
+    type Item = Result<(usize, Vec<String>), CsvError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // blank lines between records are skipped
+        while self.chars.next_if_eq(&'\n').is_some() {
+            self.line += 1;
+        }
+        self.chars.peek()?;
+
+        let line = self.line;
+        Some(self.record().map(|values| (line, values)))
+    }
+}
@@ -0,0 +1,426 @@
+//! Structural comparison of reflected values
+//!
+//! [`diff`] walks both values field by field and reports every mismatch with path to it,
+//! which is what [`assert_reflect_eq`](crate::assert_reflect_eq) prints on failure.
+//!
+//! Primitive leaves (and opaque std leaves, such as `Duration`, rendered with their `Debug`, and scalars of `serde_json::Value`
+//! with `json` feature) are compared by value, enums are compared by active variant first
+//! and then by fields of that variant. Lists, arrays and optional values are compared element-wise.
+//! Entries of maps are matched by key (in order of [canonical encoding](crate::hash::canonical_bytes) of keys,
+//! so iteration order doesn't matter), and reported under rendering of their key.
+//! Wrappers are compared by values they wrap, values behind interior mutability are compared while their guards are held.
+use std::any::Any;
+use std::cmp::Ordering;
+use std::net::IpAddr;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+
+use crate::hash::canonical_bytes;
+use crate::{Data, FieldAccessError, Fields, ReflectMap, SharedRef, TypeInfoDynamic, Unsizeable};
+
+/// Single mismatch between two values
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Difference {
+    /// Dot-separated path of mismatching field, empty if values differ at root
+    pub path: String,
+    /// Rendering of left value at `path`
+    pub left: String,
+    /// Rendering of right value at `path`
+    pub right: String,
+}
+
+impl std::fmt::Display for Difference {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = match self.path.as_str() {
+            "" => "<root>",
+            path => path,
+        };
+        write!(f, "{}: {} != {}", path, self.left, self.right)
+    }
+}
+
+/// Compare two values field by field
+///
+/// Returns all found differences, so empty list means that values are structurally equal
+pub fn diff(left: &dyn TypeInfoDynamic, right: &dyn TypeInfoDynamic) -> Vec<Difference> {
+    let mut differences = Vec::new();
+    walk(left, right, String::new(), &mut differences);
+    differences
+}
+
+/// Assert that two reflected values are structurally equal
+///
+/// On failure, panics with list of mismatching fields, as reported by [`diff::diff`](crate::diff::diff)
+///
+/// ```ignore
+/// assert_reflect_eq!(actual, expected);
+/// assert_reflect_eq!(actual, expected, "after {} steps", steps);
+/// ```
+#[macro_export]
+macro_rules! assert_reflect_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::assert_reflect_eq!($left, $right, "assertion `left == right` failed")
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let differences = $crate::diff::diff(left, right);
+                if !differences.is_empty() {
+                    let differences = differences
+                        .iter()
+                        .map(|difference| ::std::format!("  {}", difference))
+                        .collect::<::std::vec::Vec<_>>();
+                    ::std::panic!(
+                        "{}\n{}",
+                        ::std::format_args!($($arg)+),
+                        differences.join("\n")
+                    );
+                }
+            }
+        }
+    };
+}
+
+fn walk(
+    left: &dyn TypeInfoDynamic,
+    right: &dyn TypeInfoDynamic,
+    path: String,
+    differences: &mut Vec<Difference>,
+) {
+    // wrappers, such as `Box<T>`, are compared by the values they wrap
+    let (left, right) = (left.innermost(), right.innermost());
+    if let (Some(left_shared), Some(right_shared)) = (left.as_shared(), right.as_shared()) {
+        // the same lock can't be taken twice, and value is equal to itself anyway
+        if std::ptr::addr_eq(left, right) {
+            return;
+        }
+        match (left_shared.read(), right_shared.read()) {
+            (Ok(left), Ok(right)) => walk(&*left, &*right, path, differences),
+            (left, right) => differences.push(Difference {
+                path,
+                left: render_shared(left),
+                right: render_shared(right),
+            }),
+        }
+        return;
+    }
+
+    let mut report = |left: String, right: String| {
+        differences.push(Difference {
+            path: path.clone(),
+            left,
+            right,
+        })
+    };
+
+    let (left_ty, right_ty) = (left.get_dynamic(), right.get_dynamic());
+    let (left_any, right_any): (&dyn Any, &dyn Any) = (left, right);
+    if left_any.type_id() != right_any.type_id() || left_ty != right_ty {
+        report(left_ty.ident.to_string(), right_ty.ident.to_string());
+        return;
+    }
+
+    if let Some(equal) = leaf_eq(left, right) {
+        if !equal {
+            report(render(left), render(right));
+        }
+        return;
+    }
+
+    match &left_ty.data {
+        Data::Struct(fields) | Data::Tuple(fields) => {
+            walk_fields(left, right, fields, &path, differences)
+        }
+        Data::Enum(_) => match (left.variant(), right.variant()) {
+            (Some(left_variant), Some(right_variant))
+                if left_variant.ident == right_variant.ident =>
+            {
+                walk_fields(left, right, &left_variant.fields, &path, differences)
+            }
+            _ => report(render(left), render(right)),
+        },
+        Data::List(_) | Data::Array { .. } | Data::Optional(_) => {
+            let missing = match left_ty.data {
+                Data::Optional(_) => "None",
+                _ => "<missing>",
+            };
+            for index in 0.. {
+                let field_path = join(&path, &index);
+                match (left.field(index.into()), right.field(index.into())) {
+                    (Ok(left), Ok(right)) => walk(
+                        left.as_dynamic(),
+                        right.as_dynamic(),
+                        field_path,
+                        differences,
+                    ),
+                    (Ok(left), Err(_)) => differences.push(Difference {
+                        path: field_path,
+                        left: render(left.as_dynamic()),
+                        right: missing.to_string(),
+                    }),
+                    (Err(_), Ok(right)) => differences.push(Difference {
+                        path: field_path,
+                        left: missing.to_string(),
+                        right: render(right.as_dynamic()),
+                    }),
+                    (Err(_), Err(_)) => break,
+                }
+            }
+        }
+        Data::Map { .. } => {
+            if let (Some(left), Some(right)) = (left.as_map(), right.as_map()) {
+                walk_entries(left, right, &path, differences);
+            }
+        }
+        // fields of union can't be read, so unions are compared by type only
+        Data::Primitive | Data::Unit | Data::Union(_) => {}
+    }
+}
+
+fn walk_fields(
+    left: &dyn TypeInfoDynamic,
+    right: &dyn TypeInfoDynamic,
+    fields: &'static Fields,
+    path: &str,
+    differences: &mut Vec<Difference>,
+) {
+    for field in fields.as_slice() {
+        // both values are of the same type and variant, so field is present in both
+        if let (Ok(left), Ok(right)) = (left.field(field.id.clone()), right.field(field.id.clone()))
+        {
+            walk(
+                left.as_dynamic(),
+                right.as_dynamic(),
+                join(path, &field.id),
+                differences,
+            );
+        }
+    }
+}
+
+fn walk_entries(
+    left: &dyn ReflectMap,
+    right: &dyn ReflectMap,
+    path: &str,
+    differences: &mut Vec<Difference>,
+) {
+    let (left, right) = (sorted_entries(left), sorted_entries(right));
+    let (mut left, mut right) = (left.into_iter().peekable(), right.into_iter().peekable());
+    loop {
+        let order = match (left.peek(), right.peek()) {
+            (Some((left_key, ..)), Some((right_key, ..))) => left_key.cmp(right_key),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => break,
+        };
+        match order {
+            Ordering::Equal => {
+                let (_, key, left) = left.next().unwrap();
+                let (_, _, right) = right.next().unwrap();
+                walk(
+                    left.as_dynamic(),
+                    right.as_dynamic(),
+                    join(path, &key),
+                    differences,
+                );
+            }
+            Ordering::Less => {
+                let (_, key, left) = left.next().unwrap();
+                differences.push(Difference {
+                    path: join(path, &key),
+                    left: render(left.as_dynamic()),
+                    right: "<missing>".to_string(),
+                });
+            }
+            Ordering::Greater => {
+                let (_, key, right) = right.next().unwrap();
+                differences.push(Difference {
+                    path: join(path, &key),
+                    left: "<missing>".to_string(),
+                    right: render(right.as_dynamic()),
+                });
+            }
+        }
+    }
+}
+
+// entries with rendered keys, sorted by encoding of key
+fn sorted_entries(map: &dyn ReflectMap) -> Vec<(Vec<u8>, String, Unsizeable<'_>)> {
+    let mut entries = map
+        .iter()
+        .map(|(key, value)| {
+            let key = key.as_dynamic();
+            let rendered = render(key);
+            let encoded = canonical_bytes(key).unwrap_or_else(|_| rendered.clone().into_bytes());
+            (encoded, rendered, value)
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by(|(left, ..), (right, ..)| left.cmp(right));
+    entries
+}
+
+fn join(path: &str, segment: &dyn std::fmt::Display) -> String {
+    match path {
+        "" => segment.to_string(),
+        path => format!("{}.{}", path, segment),
+    }
+}
+
+fn render(value: &dyn TypeInfoDynamic) -> String {
+    let value = value.innermost();
+    if let Some(shared) = value.as_shared() {
+        return render_shared(shared.read());
+    }
+    if let Some(leaf) = display_primitive(value)
+        .or_else(|| debug_opaque(value))
+        .or_else(|| display_json(value))
+    {
+        return leaf;
+    }
+
+    let ident = value.get_dynamic().ident;
+    match value.variant() {
+        Some(variant) => format!("{}::{}", ident, variant.ident),
+        None => ident.to_string(),
+    }
+}
+
+// value, which can't be read, is rendered as error
+fn render_shared(value: Result<SharedRef<'_>, FieldAccessError>) -> String {
+    match value {
+        Ok(value) => render(&*value),
+        Err(error) => format!("<{}>", error),
+    }
+}
+
+// `None` if values are not leaves
+fn leaf_eq(left: &dyn TypeInfoDynamic, right: &dyn TypeInfoDynamic) -> Option<bool> {
+    primitive_eq(left, right)
+        .or_else(|| nonzero_eq(left, right))
+        .or_else(|| opaque_eq(left, right))
+        .or_else(|| json_eq(left, right))
+}
+
+// json scalars are described as primitives, arrays and objects are walked as lists and maps
+#[cfg(feature = "json")]
+fn json_scalar(value: &dyn TypeInfoDynamic) -> Option<&serde_json::Value> {
+    let value: &dyn Any = value;
+    value
+        .downcast_ref::<serde_json::Value>()
+        .filter(|value| !value.is_array() && !value.is_object())
+}
+
+#[cfg(feature = "json")]
+fn json_eq(left: &dyn TypeInfoDynamic, right: &dyn TypeInfoDynamic) -> Option<bool> {
+    Some(json_scalar(left)? == json_scalar(right)?)
+}
+
+#[cfg(not(feature = "json"))]
+fn json_eq(_left: &dyn TypeInfoDynamic, _right: &dyn TypeInfoDynamic) -> Option<bool> {
+    None
+}
+
+// strings are rendered without quotes, like `String` leaves
+#[cfg(feature = "json")]
+fn display_json(value: &dyn TypeInfoDynamic) -> Option<String> {
+    match json_scalar(value)? {
+        serde_json::Value::String(string) => Some(string.clone()),
+        scalar => Some(scalar.to_string()),
+    }
+}
+
+#[cfg(not(feature = "json"))]
+fn display_json(_value: &dyn TypeInfoDynamic) -> Option<String> {
+    None
+}
+
+macro_rules! diff_primitives {
+    ($($ty:ty),*) => {
+        // `None` if values are not primitives
+        fn primitive_eq(left: &dyn TypeInfoDynamic, right: &dyn TypeInfoDynamic) -> Option<bool> {
+            let (left, right): (&dyn Any, &dyn Any) = (left, right);
+            $(
+                if let (Some(left), Some(right)) = (left.downcast_ref::<$ty>(), right.downcast_ref::<$ty>()) {
+                    return Some(left == right);
+                }
+            )*
+            None
+        }
+
+        pub(crate) fn display_primitive(value: &dyn TypeInfoDynamic) -> Option<String> {
+            let value = value.innermost();
+            if let Some(shared) = value.as_shared() {
+                return display_primitive(&*shared.read().ok()?);
+            }
+            let value: &dyn Any = value;
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(value.to_string());
+                }
+            )*
+            display_nonzero(value)
+        }
+    };
+}
+
+macro_rules! diff_nonzero {
+    ($($ty:ty),*) => {
+        fn nonzero_eq(left: &dyn TypeInfoDynamic, right: &dyn TypeInfoDynamic) -> Option<bool> {
+            let (left, right): (&dyn Any, &dyn Any) = (left, right);
+            $(
+                if let (Some(left), Some(right)) = (left.downcast_ref::<$ty>(), right.downcast_ref::<$ty>()) {
+                    return Some(left == right);
+                }
+            )*
+            None
+        }
+
+        fn display_nonzero(value: &dyn Any) -> Option<String> {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(value.to_string());
+                }
+            )*
+            None
+        }
+    };
+}
+
+for_each_nonzero!(diff_nonzero);
+
+// opaque leaves don't implement `Display`, so they are rendered with `Debug`
+macro_rules! diff_opaque {
+    ($($ty:ty),*) => {
+        fn opaque_eq(left: &dyn TypeInfoDynamic, right: &dyn TypeInfoDynamic) -> Option<bool> {
+            let (left, right): (&dyn Any, &dyn Any) = (left, right);
+            $(
+                if let (Some(left), Some(right)) = (left.downcast_ref::<$ty>(), right.downcast_ref::<$ty>()) {
+                    return Some(left == right);
+                }
+            )*
+            None
+        }
+
+        fn debug_opaque(value: &dyn TypeInfoDynamic) -> Option<String> {
+            let value: &dyn Any = value;
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(format!("{:?}", value));
+                }
+            )*
+            None
+        }
+    };
+}
+
+for_each_opaque!(diff_opaque);
+
+for_each_primitive!(
+    diff_primitives,
+    &'static str,
+    std::borrow::Cow<'static, str>
+);
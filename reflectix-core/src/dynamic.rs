@@ -0,0 +1,132 @@
+//! Records, assembled at run-time
+//!
+//! [`DynamicStruct`] is an ordered set of borrowed fields, which doesn't correspond to any declared type.
+//! It's used where reflective code hands out a group of fields on it's own, e.g. fields of active variant
+//! in [`crate::router::Router`].
+//!
+//! It also serves as partial pattern for [`matches_shape`], which checks only fields, listed in pattern,
+//! and as partial view of value, selected by [`project`]
+use crate::{Data, Field, FieldAccessError, FieldId, TypeInfoDynamic, Unsizeable};
+
+/// Ordered set of borrowed fields, not backed by any declared type
+#[derive(Default)]
+pub struct DynamicStruct<'a> {
+    fields: Vec<(FieldId, Unsizeable<'a>)>,
+}
+
+impl<'a> DynamicStruct<'a> {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect all fields of `value` in order of their positions
+    ///
+    /// For enums, fields of active variant are collected
+    pub fn from_value(value: &'a dyn TypeInfoDynamic) -> Self {
+        let mut collected = Self::new();
+        for (position, field) in fields_of(value).iter().enumerate() {
+            if let Ok(borrowed) = value.field_at(position) {
+                collected.insert(field.id.clone(), borrowed);
+            }
+        }
+        collected
+    }
+
+    /// Add field, replacing previous field with the same id
+    pub fn insert(&mut self, id: FieldId, value: Unsizeable<'a>) {
+        match self.fields.iter_mut().find(|(existing, _)| *existing == id) {
+            Some((_, existing)) => *existing = value,
+            None => self.fields.push((id, value)),
+        }
+    }
+
+    /// Same as [`DynamicStruct::insert`], for chaining
+    pub fn with<T: TypeInfoDynamic>(mut self, id: impl Into<FieldId>, value: &'a T) -> Self {
+        self.insert(id.into(), Unsizeable::new(value));
+        self
+    }
+
+    #[allow(missing_docs)]
+    pub fn get(&self, id: FieldId) -> Result<&Unsizeable<'a>, FieldAccessError> {
+        self.fields
+            .iter()
+            .find(|(existing, _)| *existing == id)
+            .map(|(_, value)| value)
+            .ok_or(FieldAccessError::NotFound)
+    }
+
+    /// Borrow field as concrete type
+    pub fn get_as<T: 'static>(&self, id: FieldId) -> Result<&'a T, FieldAccessError> {
+        self.get(id)?
+            .downcast_ref()
+            .ok_or(FieldAccessError::UnmatchingType)
+    }
+
+    /// Fields in order of insertion
+    pub fn iter(&self) -> impl Iterator<Item = (&FieldId, &Unsizeable<'a>)> {
+        self.fields.iter().map(|(id, value)| (id, value))
+    }
+
+    #[allow(missing_docs)]
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    #[allow(missing_docs)]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+}
+
+/// Borrow fields of `value`, listed by `names`, into record, in order they are listed
+///
+/// Fields are matched by name, or by index written in decimal. For enums, fields of active variant are selected.
+/// Fails with [`FieldAccessError::NotFound`] if any of names doesn't refer to field of `value`
+pub fn project<'a>(
+    value: &'a dyn TypeInfoDynamic,
+    names: &[&str],
+) -> Result<DynamicStruct<'a>, FieldAccessError> {
+    let fields = fields_of(value);
+    let mut projected = DynamicStruct::new();
+    for name in names {
+        let field = fields
+            .iter()
+            .find(|field| field.id.matches(name))
+            .ok_or(FieldAccessError::NotFound)?;
+        projected.insert(field.id.clone(), value.field(field.id.clone())?);
+    }
+    Ok(projected)
+}
+
+// fields of struct or of active variant
+fn fields_of(value: &dyn TypeInfoDynamic) -> &'static [Field] {
+    match (value.variant(), &value.get_dynamic().data) {
+        (Some(variant), _) => variant.fields.as_slice(),
+        (None, Data::Struct(fields) | Data::Tuple(fields)) => fields.as_slice(),
+        (None, _) => &[],
+    }
+}
+
+/// Whether every field of `pattern` is present in `value` and is structurally equal (see [`crate::diff`])
+/// to field of `value` with the same id
+///
+/// Fields of `value`, which are not listed in pattern, are ignored. For enums, fields of active variant are matched
+pub fn matches_shape(value: &dyn TypeInfoDynamic, pattern: &DynamicStruct) -> bool {
+    pattern
+        .iter()
+        .all(|(id, expected)| match value.field(id.clone()) {
+            Ok(actual) => crate::diff::diff(actual.as_dynamic(), expected.as_dynamic()).is_empty(),
+            Err(_) => false,
+        })
+}
+
+impl std::fmt::Debug for DynamicStruct<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut map = f.debug_map();
+        for (id, value) in &self.fields {
+            map.entry(&id.to_string(), &value.as_dynamic().get_dynamic().ident);
+        }
+        map.finish()
+    }
+}
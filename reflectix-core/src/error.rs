@@ -0,0 +1,290 @@
+//! Unified error of reflective layers
+//!
+//! Every operation fails with it's own specific error ([`FieldAccessError`], [`RuntimeConstructError`],
+//! [`RegistryError`] and so on), which is convenient to match on, but inconvenient to expose from application.
+//! All of them convert into [`ReflectError`], which keeps original error as it's
+//! [`source`](std::error::Error::source) and carries context of failure: name of type, field and variant.
+//!
+//! Context, which is known to the original error (such as field of [`RuntimeConstructError::UnexpectedType`]),
+//! is filled on conversion, the rest can be attached by caller:
+//!
+//! ```ignore
+//! let field = value
+//!     .field(id.clone())
+//!     .map_err(|err| ReflectError::from(err).with_type(value.get_dynamic().ident).with_field(id))?;
+//! ```
+use std::fmt;
+
+use crate::apply::ApplyError;
+use crate::csv::CsvError;
+use crate::query::QueryError;
+use crate::registry::RegistryError;
+use crate::router::RouteError;
+use crate::{DowncastError, FieldAccessError, FieldId, RuntimeConstructError, UnknownVariantError};
+
+/// Original error, wrapped into [`ReflectError`]
+#[derive(Debug)]
+#[non_exhaustive]
+#[allow(missing_docs)]
+pub enum ReflectErrorKind {
+    Access(FieldAccessError),
+    Construct(RuntimeConstructError),
+    Downcast(DowncastError),
+    UnknownVariant(UnknownVariantError),
+    Registry(RegistryError),
+    Apply(ApplyError),
+    Route(RouteError),
+    Csv(CsvError),
+    Query(QueryError),
+    #[cfg(feature = "console")]
+    Console(crate::console::ConsoleError),
+    #[cfg(feature = "serde")]
+    Schema(crate::schema::SchemaMismatch),
+    #[cfg(feature = "xml")]
+    Xml(crate::xml::XmlError),
+}
+
+impl ReflectErrorKind {
+    fn as_error(&self) -> &(dyn std::error::Error + 'static) {
+        match self {
+            Self::Access(error) => error,
+            Self::Construct(error) => error,
+            Self::Downcast(error) => error,
+            Self::UnknownVariant(error) => error,
+            Self::Registry(error) => error,
+            Self::Apply(error) => error,
+            Self::Route(error) => error,
+            Self::Csv(error) => error,
+            Self::Query(error) => error,
+            #[cfg(feature = "console")]
+            Self::Console(error) => error,
+            #[cfg(feature = "serde")]
+            Self::Schema(error) => error,
+            #[cfg(feature = "xml")]
+            Self::Xml(error) => error,
+        }
+    }
+}
+
+/// Error of any reflective operation together with context of failure
+#[derive(Debug)]
+pub struct ReflectError {
+    // boxed, so results with this error stay small
+    kind: Box<ReflectErrorKind>,
+    ty: Option<&'static str>,
+    field: Option<FieldId>,
+    variant: Option<&'static str>,
+}
+
+impl ReflectError {
+    fn new(kind: ReflectErrorKind) -> Self {
+        Self {
+            kind: Box::new(kind),
+            ty: None,
+            field: None,
+            variant: None,
+        }
+    }
+
+    /// Set name of type, operation on which failed
+    pub fn with_type(mut self, ty: &'static str) -> Self {
+        self.ty = Some(ty);
+        self
+    }
+
+    /// Set field, operation on which failed
+    pub fn with_field(mut self, field: FieldId) -> Self {
+        self.field = Some(field);
+        self
+    }
+
+    /// Set variant, operation on which failed
+    pub fn with_variant(mut self, variant: &'static str) -> Self {
+        self.variant = Some(variant);
+        self
+    }
+
+    #[allow(missing_docs)]
+    pub fn kind(&self) -> &ReflectErrorKind {
+        &self.kind
+    }
+
+    #[allow(missing_docs)]
+    pub fn into_kind(self) -> ReflectErrorKind {
+        *self.kind
+    }
+
+    /// Name of type, operation on which failed, if known
+    pub fn ty(&self) -> Option<&'static str> {
+        self.ty
+    }
+
+    /// Field, operation on which failed, if known
+    pub fn field(&self) -> Option<&FieldId> {
+        self.field.as_ref()
+    }
+
+    /// Variant, operation on which failed, if known
+    pub fn variant(&self) -> Option<&'static str> {
+        self.variant
+    }
+}
+
+impl fmt::Display for ReflectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut context = Vec::new();
+        if let Some(ty) = self.ty {
+            context.push(format!("type `{}`", ty));
+        }
+        if let Some(variant) = self.variant {
+            context.push(format!("variant `{}`", variant));
+        }
+        if let Some(field) = &self.field {
+            context.push(format!("field `{}`", field));
+        }
+
+        // original error is reported as source, so it's not repeated here
+        f.write_str("Reflective operation failed")?;
+        if !context.is_empty() {
+            write!(f, " at {}", context.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ReflectError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.kind.as_error())
+    }
+}
+
+impl From<FieldAccessError> for ReflectError {
+    fn from(error: FieldAccessError) -> Self {
+        Self::new(ReflectErrorKind::Access(error))
+    }
+}
+
+impl From<RuntimeConstructError> for ReflectError {
+    fn from(error: RuntimeConstructError) -> Self {
+        let (field, variant) = match &error {
+            RuntimeConstructError::UnexpectedType { field, variant, .. } => {
+                (Some(field.clone()), *variant)
+            }
+            RuntimeConstructError::MissingField(field)
+            | RuntimeConstructError::DuplicateField(field) => (Some(field.clone()), None),
+            _ => (None, None),
+        };
+        Self {
+            field,
+            variant,
+            ..Self::new(ReflectErrorKind::Construct(error))
+        }
+    }
+}
+
+impl From<DowncastError> for ReflectError {
+    fn from(error: DowncastError) -> Self {
+        let ty = error.actual;
+        Self::new(ReflectErrorKind::Downcast(error)).with_type(ty)
+    }
+}
+
+impl From<UnknownVariantError> for ReflectError {
+    fn from(error: UnknownVariantError) -> Self {
+        let ty = error.ty;
+        Self::new(ReflectErrorKind::UnknownVariant(error)).with_type(ty)
+    }
+}
+
+impl From<RegistryError> for ReflectError {
+    fn from(error: RegistryError) -> Self {
+        let ty = match error {
+            RegistryError::AlreadyRegistered(ty) | RegistryError::NameConflict(ty) => ty,
+        };
+        Self::new(ReflectErrorKind::Registry(error)).with_type(ty)
+    }
+}
+
+impl From<ApplyError> for ReflectError {
+    fn from(error: ApplyError) -> Self {
+        Self::new(ReflectErrorKind::Apply(error))
+    }
+}
+
+impl From<RouteError> for ReflectError {
+    fn from(error: RouteError) -> Self {
+        let (ty, variant) = match &error {
+            RouteError::NotEnum(ty) | RouteError::UnknownVariant { ty, .. } => (Some(*ty), None),
+            RouteError::AlreadyRouted(variant) | RouteError::Unrouted(variant) => {
+                (None, Some(*variant))
+            }
+            _ => (None, None),
+        };
+        Self {
+            ty,
+            variant,
+            ..Self::new(ReflectErrorKind::Route(error))
+        }
+    }
+}
+
+impl From<CsvError> for ReflectError {
+    fn from(error: CsvError) -> Self {
+        let (ty, field) = match &error {
+            CsvError::NotNamedStruct(ty) => (Some(*ty), None),
+            CsvError::Unsupported { ty, field } => (Some(*ty), Some(field.clone())),
+            CsvError::Parse { field, .. } => (None, Some(field.clone())),
+            _ => (None, None),
+        };
+        Self {
+            ty,
+            field,
+            ..Self::new(ReflectErrorKind::Csv(error))
+        }
+    }
+}
+
+impl From<QueryError> for ReflectError {
+    fn from(error: QueryError) -> Self {
+        let ty = match &error {
+            QueryError::Unsupported(ty) | QueryError::Parse { ty, .. } => Some(*ty),
+            _ => None,
+        };
+        Self {
+            ty,
+            ..Self::new(ReflectErrorKind::Query(error))
+        }
+    }
+}
+
+#[cfg(feature = "console")]
+impl From<crate::console::ConsoleError> for ReflectError {
+    fn from(error: crate::console::ConsoleError) -> Self {
+        Self::new(ReflectErrorKind::Console(error))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<crate::schema::SchemaMismatch> for ReflectError {
+    fn from(error: crate::schema::SchemaMismatch) -> Self {
+        Self::new(ReflectErrorKind::Schema(error))
+    }
+}
+
+#[cfg(feature = "xml")]
+impl From<crate::xml::XmlError> for ReflectError {
+    fn from(error: crate::xml::XmlError) -> Self {
+        let (ty, field) = match &error {
+            crate::xml::XmlError::NotAttribute { ty, field } => (Some(*ty), Some(field.clone())),
+            crate::xml::XmlError::Unsupported(ty)
+            | crate::xml::XmlError::MissingVariant(ty)
+            | crate::xml::XmlError::NoPrototype(ty) => (Some(*ty), None),
+            _ => (None, None),
+        };
+        Self {
+            ty,
+            field,
+            ..Self::new(ReflectErrorKind::Xml(error))
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Read-only wrapper for values, which are shared with untrusted reflective code
+//!
+//! [`Frozen<T>`] reflects exactly as `T`, but refuses mutable access to it's fields with [`FieldAccessError::Frozen`],
+//! and so does everything built on [`TypeInfoDynamic::field_mut`] (setting fields, mutating lists and maps of fields).
+//! Fields are borrowed from frozen value only immutably, so the guarantee extends to every nested value,
+//! and scripts or plugins, which are handed frozen state, can inspect it, but not modify it.
+//!
+//! Owner of the value can still replace it as a whole or take it back with [`Frozen::into_inner`]
+use std::any::Any;
+
+use crate::containers::downcast_constructed;
+use crate::{
+    FieldAccessError, FieldId, ReflectList, ReflectMap, RuntimeConstructError, Type, TypeInfo,
+    TypeInfoDynamic, Unsizeable, UnsizeableMut, UnsizeableOwned, Variant,
+};
+
+/// Value, which can't be mutated reflectively
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct Frozen<T>(T);
+
+impl<T> Frozen<T> {
+    #[allow(missing_docs)]
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Unwrap value, making it mutable again
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> std::ops::Deref for Frozen<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Frozen<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: TypeInfo> TypeInfoDynamic for Frozen<T> {
+    fn get_dynamic(&self) -> &'static Type {
+        T::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let inner = downcast_constructed::<T>(self.0.construct_struct(args)?)?;
+        Ok(UnsizeableOwned::new(Frozen(*inner)))
+    }
+
+    fn construct_enum(
+        &self,
+        variant: &'static str,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let inner = downcast_constructed::<T>(self.0.construct_enum(variant, args)?)?;
+        Ok(UnsizeableOwned::new(Frozen(*inner)))
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        self.0.field(id)
+    }
+
+    fn field_mut<'s>(&'s mut self, _id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        Err(FieldAccessError::Frozen)
+    }
+
+    fn variant(&self) -> Option<&'static Variant> {
+        self.0.variant()
+    }
+
+    fn field_at<'s>(&'s self, position: usize) -> Result<Unsizeable<'s>, FieldAccessError> {
+        self.0.field_at(position)
+    }
+
+    fn field_at_mut<'s>(
+        &'s mut self,
+        _position: usize,
+    ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        Err(FieldAccessError::Frozen)
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        self.0.as_list()
+    }
+
+    fn as_map(&self) -> Option<&dyn ReflectMap> {
+        self.0.as_map()
+    }
+
+    fn pointee(&self) -> Option<&dyn TypeInfoDynamic> {
+        Some(&self.0)
+    }
+
+    fn pointee_mut(&mut self) -> Result<UnsizeableMut<'_>, FieldAccessError> {
+        Err(FieldAccessError::Frozen)
+    }
+
+    fn construct_wrapper(
+        &self,
+        inner: Box<dyn Any>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let inner = crate::containers::downcast_arg::<T>(0, inner, None)?;
+        Ok(UnsizeableOwned::new(Frozen(inner)))
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for Frozen<T> {
+    const INFO: &'static Type = T::INFO;
+}
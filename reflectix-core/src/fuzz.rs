@@ -0,0 +1,377 @@
+//! Fuzzing helpers for runtime constructors
+//!
+//! [`arbitrary_case`] turns unstructured fuzzer input into argument vector for
+//! [`TypeInfoDynamic::construct_struct`]/[`TypeInfoDynamic::construct_enum`]. Arguments are
+//! either valid, or broken in one particular way (wrong type, missing or extra arguments, unknown variant),
+//! and [`check_case`] verifies that constructor reacts to them as it should.
+//!
+//! [`exercise`] combines both and is meant to be called directly from fuzz target:
+//!
+//! ```ignore
+//! fuzz_target!(|data: &[u8]| {
+//!     reflectix::fuzz::exercise(&MyType::default(), data).unwrap();
+//! });
+//! ```
+//!
+//! Values for non-primitive fields are built recursively from prototype's own fields,
+//! so variants with such fields are generated only if they are active in prototype
+use std::any::Any;
+
+use arbitrary::{Arbitrary, Unstructured};
+
+use crate::{Data, Fields, RuntimeConstructError, Type, TypeInfoDynamic, UnsizeableOwned, Variant};
+
+/// Variant name which is never generated by derive, used to exercise [`RuntimeConstructError::InvalidVariant`]
+pub const UNKNOWN_VARIANT: &str = "__reflectix_fuzz_unknown_variant";
+
+/// How generated arguments relate to constructor's signature
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Shape {
+    /// Arguments match fields exactly
+    Valid,
+    /// Argument at `index` is of foreign type, the rest are valid
+    WrongType {
+        #[allow(missing_docs)]
+        index: usize,
+    },
+    /// Last `count` arguments are omitted. Accepted, if all omitted fields have defaults
+    Missing {
+        #[allow(missing_docs)]
+        count: usize,
+    },
+    /// `count` arguments are appended after valid ones
+    Extra {
+        #[allow(missing_docs)]
+        count: usize,
+    },
+    /// Requested variant doesn't exist
+    UnknownVariant,
+}
+
+/// Generated constructor call
+pub struct Case {
+    /// Variant to construct, if type is enum
+    pub variant: Option<&'static str>,
+    #[allow(missing_docs)]
+    pub args: Vec<Box<dyn Any>>,
+    #[allow(missing_docs)]
+    pub shape: Shape,
+    // rendering of primitive arguments, to compare with fields of constructed value
+    expected: Vec<Option<String>>,
+}
+
+impl std::fmt::Debug for Case {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Case")
+            .field("variant", &self.variant)
+            .field("args", &self.args.len())
+            .field("shape", &self.shape)
+            .finish()
+    }
+}
+
+/// Constructor didn't behave as expected for generated case
+#[derive(thiserror::Error, Debug)]
+#[error("Constructor of `{ty}` violated invariant for {shape:?} arguments: {reason}")]
+pub struct InvariantViolation {
+    /// Name of constructed type
+    pub ty: &'static str,
+    #[allow(missing_docs)]
+    pub shape: Shape,
+    /// Description of what went wrong
+    pub reason: String,
+}
+
+// argument of this type is never accepted by any constructor
+struct Foreign;
+
+// arguments alongside rendering of primitive ones
+type GeneratedArgs = (Vec<Box<dyn Any>>, Vec<Option<String>>);
+
+/// Generate constructor call for type of `prototype`
+///
+/// Returns [`arbitrary::Error::IncorrectFormat`] if type can't be constructed from generated values
+/// (e.g. it's primitive or some of it's fields can't be generated)
+pub fn arbitrary_case(
+    prototype: &dyn TypeInfoDynamic,
+    u: &mut Unstructured,
+) -> arbitrary::Result<Case> {
+    let (variant, fields) = arbitrary_signature(prototype, u)?;
+
+    if variant.is_some() && u.ratio(1u8, 16u8)? {
+        return Ok(Case {
+            variant: Some(UNKNOWN_VARIANT),
+            args: Vec::new(),
+            shape: Shape::UnknownVariant,
+            expected: Vec::new(),
+        });
+    }
+
+    let (mut args, mut expected) = valid_args(prototype, fields, u)?;
+
+    let shape = match u.int_in_range(0u8..=3)? {
+        1 if !args.is_empty() => {
+            let index = u.choose_index(args.len())?;
+            args[index] = Box::new(Foreign);
+            expected[index] = None;
+            Shape::WrongType { index }
+        }
+        2 if !args.is_empty() => {
+            let count = u.int_in_range(1..=args.len())?;
+            args.truncate(args.len() - count);
+            expected.truncate(args.len());
+            Shape::Missing { count }
+        }
+        3 => {
+            let count = u.int_in_range(1..=4)?;
+            args.extend((0..count).map(|_| Box::new(Foreign) as Box<dyn Any>));
+            Shape::Extra { count }
+        }
+        _ => Shape::Valid,
+    };
+
+    Ok(Case {
+        variant: variant.map(|variant| variant.ident),
+        args,
+        shape,
+        expected,
+    })
+}
+
+/// Run constructor of `prototype` with generated case and check it's result
+///
+/// Valid arguments must produce value of the same type (and requested variant),
+/// fields of which hold passed arguments. Any other shape must be rejected with matching error
+pub fn check_case(prototype: &dyn TypeInfoDynamic, case: Case) -> Result<(), InvariantViolation> {
+    let ty = prototype.get_dynamic();
+    let violation = |reason: String| InvariantViolation {
+        ty: ty.ident,
+        shape: case.shape.clone(),
+        reason,
+    };
+
+    let result = match case.variant {
+        Some(variant) => prototype.construct_enum(variant, case.args),
+        None => prototype.construct_struct(case.args),
+    };
+
+    let constructed = match (&case.shape, result) {
+        (Shape::Valid, Ok(constructed)) => constructed,
+        // omitted trailing fields are filled with their defaults
+        (Shape::Missing { count }, Ok(constructed)) if omits_defaults(ty, case.variant, *count) => {
+            constructed
+        }
+        (
+            Shape::WrongType { index },
+            Err(RuntimeConstructError::UnexpectedType { index: actual, .. }),
+        ) if *index == actual => return Ok(()),
+        (Shape::Missing { count }, Err(RuntimeConstructError::NotEnoughArgs))
+            if !omits_defaults(ty, case.variant, *count) =>
+        {
+            return Ok(())
+        }
+        (Shape::Extra { .. }, Err(RuntimeConstructError::TooManyArgs))
+        | (Shape::UnknownVariant, Err(RuntimeConstructError::InvalidVariant)) => return Ok(()),
+        (_, Ok(constructed)) => {
+            return Err(violation(format!(
+                "accepted arguments and returned `{}`",
+                constructed.get_dynamic().ident
+            )))
+        }
+        (_, Err(err)) => return Err(violation(format!("unexpected error: {}", err))),
+    };
+
+    check_constructed(prototype, &constructed, case.variant, &case.expected).map_err(violation)
+}
+
+/// Generate and check constructor calls until `data` is exhausted
+pub fn exercise(prototype: &dyn TypeInfoDynamic, data: &[u8]) -> Result<(), InvariantViolation> {
+    let mut u = Unstructured::new(data);
+    while !u.is_empty() {
+        match arbitrary_case(prototype, &mut u) {
+            Ok(case) => check_case(prototype, case)?,
+            Err(_) => break,
+        }
+    }
+    Ok(())
+}
+
+fn check_constructed(
+    prototype: &dyn TypeInfoDynamic,
+    constructed: &UnsizeableOwned,
+    variant: Option<&'static str>,
+    expected: &[Option<String>],
+) -> Result<(), String> {
+    let prototype_any: &dyn Any = prototype;
+    if constructed.type_id() != prototype_any.type_id() {
+        return Err(format!(
+            "returned value of foreign type `{}`",
+            constructed.get_dynamic().ident
+        ));
+    }
+
+    let constructed = constructed.as_dynamic();
+    let fields = match variant {
+        Some(variant) => match constructed.variant() {
+            Some(actual) if actual.ident == variant => &actual.fields,
+            actual => {
+                return Err(format!(
+                    "requested variant `{}`, got `{}`",
+                    variant,
+                    actual.map_or("<none>", |actual| actual.ident)
+                ))
+            }
+        },
+        None => match &constructed.get_dynamic().data {
+            Data::Struct(fields) | Data::Tuple(fields) => fields,
+            _ => return Ok(()),
+        },
+    };
+
+    for (field, expected) in fields.as_slice().iter().zip(expected) {
+        let value = constructed
+            .field(field.id.clone())
+            .map_err(|err| format!("field `{}` is inaccessible: {}", field.id, err))?;
+        let value = value.as_dynamic();
+
+        if value.get_dynamic() != field.ty.get() {
+            return Err(format!(
+                "field `{}` is `{}`, while metadata says `{}`",
+                field.id,
+                value.get_dynamic().ident,
+                field.ty.ident
+            ));
+        }
+
+        let actual = crate::diff::display_primitive(value);
+        if expected.is_some() && actual != *expected {
+            return Err(format!(
+                "field `{}` holds {:?}, while {:?} was passed",
+                field.id, actual, expected
+            ));
+        }
+    }
+    Ok(())
+}
+
+// whether last `count` fields of constructor's signature all have defaults
+fn omits_defaults(ty: &'static Type, variant: Option<&'static str>, count: usize) -> bool {
+    let fields = match (&ty.data, variant) {
+        (Data::Enum(variants), Some(variant)) => match variants.find(variant) {
+            Some(variant) => variant.fields.as_slice(),
+            None => return false,
+        },
+        (Data::Struct(fields) | Data::Tuple(fields), None) => fields.as_slice(),
+        _ => return false,
+    };
+    fields.len() >= count
+        && fields[fields.len() - count..]
+            .iter()
+            .all(|field| field.has_default)
+}
+
+fn arbitrary_signature(
+    prototype: &dyn TypeInfoDynamic,
+    u: &mut Unstructured,
+) -> arbitrary::Result<(Option<&'static Variant>, &'static Fields)> {
+    match &prototype.get_dynamic().data {
+        Data::Struct(fields) | Data::Tuple(fields) => Ok((None, fields)),
+        Data::Enum(variants) => {
+            // fields of inactive variants have no prototype to build non-primitive values from
+            let candidates = variants
+                .variants
+                .iter()
+                .filter(|variant| {
+                    Some(variant.ident) == prototype.variant().map(|active| active.ident)
+                        || variant
+                            .fields
+                            .as_slice()
+                            .iter()
+                            .all(|field| is_primitive(field.ty.get()))
+                })
+                .collect::<Vec<_>>();
+
+            if candidates.is_empty() {
+                return Err(arbitrary::Error::IncorrectFormat);
+            }
+            let variant = *u.choose(&candidates)?;
+            Ok((Some(variant), &variant.fields))
+        }
+        _ => Err(arbitrary::Error::IncorrectFormat),
+    }
+}
+
+fn valid_args(
+    prototype: &dyn TypeInfoDynamic,
+    fields: &'static Fields,
+    u: &mut Unstructured,
+) -> arbitrary::Result<GeneratedArgs> {
+    let mut args = Vec::new();
+    let mut expected = Vec::new();
+
+    for field in fields.as_slice() {
+        if let Some(generated) = arbitrary_primitive(field.ty.get(), u) {
+            let (value, rendered) = generated?;
+            args.push(value);
+            expected.push(Some(rendered));
+            continue;
+        }
+
+        let nested = prototype
+            .field(field.id.clone())
+            .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+        let nested = nested.as_dynamic();
+        let case = arbitrary_valid_case(nested, u)?;
+
+        let value = match case.variant {
+            Some(variant) => nested.construct_enum(variant, case.args),
+            None => nested.construct_struct(case.args),
+        }
+        .map_err(|_| arbitrary::Error::IncorrectFormat)?;
+
+        args.push(value.into_any());
+        expected.push(None);
+    }
+
+    Ok((args, expected))
+}
+
+fn arbitrary_valid_case(
+    prototype: &dyn TypeInfoDynamic,
+    u: &mut Unstructured,
+) -> arbitrary::Result<Case> {
+    let (variant, fields) = arbitrary_signature(prototype, u)?;
+    let (args, expected) = valid_args(prototype, fields, u)?;
+    Ok(Case {
+        variant: variant.map(|variant| variant.ident),
+        args,
+        shape: Shape::Valid,
+        expected,
+    })
+}
+
+macro_rules! fuzz_primitives {
+    ($($ty:ty),*) => {
+        fn is_primitive(ty: &'static Type) -> bool {
+            $(ty == <$ty as crate::TypeInfo>::INFO)||*
+        }
+
+        // `None` if `ty` is not primitive
+        fn arbitrary_primitive(
+            ty: &'static Type,
+            u: &mut Unstructured,
+        ) -> Option<arbitrary::Result<(Box<dyn Any>, String)>> {
+            $(
+                if ty == <$ty as crate::TypeInfo>::INFO {
+                    return Some(<$ty>::arbitrary(u).map(|value| {
+                        let rendered = value.to_string();
+                        (Box::new(value) as Box<dyn Any>, rendered)
+                    }));
+                }
+            )*
+            None
+        }
+    };
+}
+
+for_each_primitive!(fuzz_primitives);
@@ -0,0 +1,94 @@
+//! Pre-resolved field access
+//!
+//! [`Type::handle`] looks field up by name once and returns [`FieldHandle`], which then
+//! accesses the field by it's position with [`TypeInfoDynamic::field_at`]. This avoids
+//! comparing field names on every access, which matters in tight loops:
+//!
+//! ```ignore
+//! let x = Foo::INFO.handle("x").unwrap();
+//! for foo in foos.iter_mut() {
+//!     x.set(foo, 42i32)?;
+//! }
+//! ```
+use crate::{Data, Field, FieldAccessError, Type, TypeInfoDynamic, Unsizeable, UnsizeableMut};
+
+/// Field of particular type, resolved ahead of time
+#[derive(Clone, Debug)]
+pub struct FieldHandle {
+    owner: &'static Type,
+    field: &'static Field,
+    position: usize,
+}
+
+impl Type {
+    /// Resolve field by it's name (or index, if field is tuple-like) for repeated access
+    ///
+    /// Only structs are supported, because position of field in enum depends on active variant
+    pub fn handle(&'static self, name: &str) -> Option<FieldHandle> {
+        let (Data::Struct(fields) | Data::Tuple(fields)) = &self.data else {
+            return None;
+        };
+        let field = self.find_field(name)?;
+        let position = fields
+            .as_slice()
+            .iter()
+            .position(|candidate| std::ptr::eq(candidate, field))?;
+
+        Some(FieldHandle {
+            owner: self,
+            field,
+            position,
+        })
+    }
+}
+
+impl FieldHandle {
+    /// Metadata of the field
+    pub fn field(&self) -> &'static Field {
+        self.field
+    }
+
+    /// Borrow immutably the field of `obj`
+    ///
+    /// If `obj` is not of type handle was resolved for, [`FieldAccessError::UnmatchingType`] will be returned
+    pub fn get<'o>(
+        &self,
+        obj: &'o dyn TypeInfoDynamic,
+    ) -> Result<Unsizeable<'o>, FieldAccessError> {
+        self.check(obj)?;
+        obj.field_at(self.position)
+    }
+
+    /// Borrow mutably the field of `obj`
+    pub fn get_mut<'o>(
+        &self,
+        obj: &'o mut dyn TypeInfoDynamic,
+    ) -> Result<UnsizeableMut<'o>, FieldAccessError> {
+        self.check(obj)?;
+        obj.field_at_mut(self.position)
+    }
+
+    /// Replace value of the field of `obj`
+    ///
+    /// Unlike [`UnsizeableMut::set`], `value` doesn't need to be boxed
+    pub fn set<T: 'static>(
+        &self,
+        obj: &mut dyn TypeInfoDynamic,
+        value: T,
+    ) -> Result<(), FieldAccessError> {
+        let field = self.get_mut(obj)?;
+        *field
+            .downcast_mut::<T>()
+            .ok_or(FieldAccessError::UnmatchingType)? = value;
+        Ok(())
+    }
+
+    fn check(&self, obj: &dyn TypeInfoDynamic) -> Result<(), FieldAccessError> {
+        let ty = obj.get_dynamic();
+        // metadata is usually the same static, so comparing addresses is enough
+        match std::ptr::eq(ty, self.owner) || ty == self.owner {
+            true => Ok(()),
+            false => Err(FieldAccessError::UnmatchingType),
+        }
+    }
+}
@@ -0,0 +1,386 @@
+//! Content hashing and canonical encoding of reflected values
+//!
+//! [`canonical_bytes`] encodes value through it's reflected structure rather than memory layout,
+//! so the encoding is deterministic and suitable for signing:
+//!
+//! - every value starts with name of it's type
+//! - strings (including names) are prefixed with their length as `u64`
+//! - integers are little-endian of fixed width, `usize`/`isize` are widened to 64 bits, floats are encoded by their bits.
+//!   `bool` is single byte, `char` is encoded as it's `u32` code point
+//! - `Duration` is seconds and nanoseconds, `SystemTime` is `Duration` since or before unix epoch,
+//!   `PathBuf` is string, `IpAddr` is version followed by octets
+//! - named fields are sorted by name, tuple fields are kept in order. Each field is preceded by it's name or index
+//! - enums write name of active variant before it's fields, optional values write `0` or `1` before contents
+//! - lists, arrays and maps are prefixed with number of elements, entries of maps are sorted by their encoding,
+//!   so iteration order of map doesn't matter
+//!
+//! Every part of value must be readable to be encoded. If some field can't be accessed (it's private under
+//! `#[reflectix(public_only)]`, or it's value is borrowed or poisoned), encoding fails with [`FieldAccessError`]
+//! instead of leaving it out, since values differing only in that field would otherwise be encoded the same.
+//!
+//! [`content_hash`] is 64-bit FNV-1a of canonical encoding. Hash function is fixed, so equal values
+//! produce equal hashes in every process and with every compiler version, which makes hashes
+//! suitable for deduplication across processes
+use std::any::Any;
+use std::net::IpAddr;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::{Data, FieldAccessError, FieldId, Fields, TypeInfoDynamic};
+
+/// Stable 64-bit hash of `value`'s contents
+pub fn content_hash(value: &dyn TypeInfoDynamic) -> Result<u64, FieldAccessError> {
+    let mut hasher = Fnv::new();
+    encode_value(&mut hasher, value)?;
+    Ok(hasher.finish())
+}
+
+/// Deterministic encoding of `value`'s contents, see [module documentation](self) for format
+pub fn canonical_bytes(value: &dyn TypeInfoDynamic) -> Result<Vec<u8>, FieldAccessError> {
+    let mut bytes = Vec::new();
+    encode_value(&mut bytes, value)?;
+    Ok(bytes)
+}
+
+// Destination of canonical encoding
+trait Sink {
+    fn write(&mut self, bytes: &[u8]);
+
+    // length prefix keeps adjacent strings from merging ("ab" + "c" vs "a" + "bc")
+    fn write_str(&mut self, text: &str) {
+        self.write_len(text.len());
+        self.write(text.as_bytes());
+    }
+
+    fn write_len(&mut self, len: usize) {
+        self.write(&(len as u64).to_le_bytes());
+    }
+}
+
+impl Sink for Vec<u8> {
+    fn write(&mut self, bytes: &[u8]) {
+        self.extend_from_slice(bytes);
+    }
+}
+
+// 64-bit FNV-1a, which is simple enough to never change
+struct Fnv(u64);
+
+impl Fnv {
+    const OFFSET: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET)
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Sink for Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+}
+
+fn encode_value(sink: &mut dyn Sink, value: &dyn TypeInfoDynamic) -> Result<(), FieldAccessError> {
+    // wrappers, such as `Box<T>`, are encoded as the value they wrap
+    let value = value.innermost();
+    // and values behind interior mutability are encoded while guard is held
+    if let Some(shared) = value.as_shared() {
+        return encode_value(sink, &*shared.read()?);
+    }
+    let ty = value.get_dynamic();
+    sink.write_str(ty.ident);
+
+    if encode_leaf(sink, value) {
+        return Ok(());
+    }
+
+    if let Some(list) = value.as_list() {
+        return encode_elements(sink, value, list.len());
+    }
+    if let Some(map) = value.as_map() {
+        let mut entries = map
+            .iter()
+            .map(|(key, value)| {
+                let mut entry = Vec::new();
+                encode_value(&mut entry, key.as_dynamic())?;
+                encode_value(&mut entry, value.as_dynamic())?;
+                Ok(entry)
+            })
+            .collect::<Result<Vec<_>, FieldAccessError>>()?;
+        entries.sort_unstable();
+
+        sink.write_len(entries.len());
+        for entry in entries {
+            sink.write(&entry);
+        }
+        return Ok(());
+    }
+
+    match &ty.data {
+        Data::Struct(fields) | Data::Tuple(fields) => encode_fields(sink, value, fields)?,
+        Data::Enum(_) => {
+            if let Some(variant) = value.variant() {
+                sink.write_str(variant.ident);
+                encode_fields(sink, value, &variant.fields)?;
+            }
+        }
+        Data::Array { len, .. } => encode_elements(sink, value, *len)?,
+        Data::Optional(_) => match value.as_some()? {
+            Some(inner) => {
+                sink.write(&[1]);
+                encode_value(sink, inner.as_dynamic())?;
+            }
+            None => sink.write(&[0]),
+        },
+        // nothing but name of type to encode
+        _ => {}
+    }
+    Ok(())
+}
+
+fn encode_elements(
+    sink: &mut dyn Sink,
+    value: &dyn TypeInfoDynamic,
+    len: usize,
+) -> Result<(), FieldAccessError> {
+    sink.write_len(len);
+    for index in 0..len {
+        let element = value.field(FieldId::Index(index))?;
+        encode_value(sink, element.as_dynamic())?;
+    }
+    Ok(())
+}
+
+fn encode_fields(
+    sink: &mut dyn Sink,
+    value: &dyn TypeInfoDynamic,
+    fields: &Fields,
+) -> Result<(), FieldAccessError> {
+    let mut fields = fields.as_slice().iter().collect::<Vec<_>>();
+    fields.sort_by_key(|field| match field.id {
+        FieldId::Named(name) => (name, 0),
+        FieldId::Index(index) => ("", index),
+    });
+    sink.write_len(fields.len());
+    for field in fields {
+        match field.id {
+            FieldId::Named(name) => sink.write_str(name),
+            FieldId::Index(index) => sink.write_len(index),
+        }
+        let field = value.field(field.id.clone())?;
+        encode_value(sink, field.as_dynamic())?;
+    }
+    Ok(())
+}
+
+// Encoding of leaf, which doesn't depend on platform
+trait StableBytes {
+    fn encode(&self, sink: &mut dyn Sink);
+}
+
+macro_rules! integer_bytes {
+    ($($ty:ty),*) => {
+        $(impl StableBytes for $ty {
+            fn encode(&self, sink: &mut dyn Sink) {
+                sink.write(&self.to_le_bytes());
+            }
+        })*
+    };
+}
+
+integer_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+// `usize` and `isize` are widened, so encodings agree between 32-bit and 64-bit targets
+impl StableBytes for usize {
+    fn encode(&self, sink: &mut dyn Sink) {
+        (*self as u64).encode(sink);
+    }
+}
+
+impl StableBytes for isize {
+    fn encode(&self, sink: &mut dyn Sink) {
+        (*self as i64).encode(sink);
+    }
+}
+
+impl StableBytes for f32 {
+    fn encode(&self, sink: &mut dyn Sink) {
+        self.to_bits().encode(sink);
+    }
+}
+
+impl StableBytes for f64 {
+    fn encode(&self, sink: &mut dyn Sink) {
+        self.to_bits().encode(sink);
+    }
+}
+
+impl StableBytes for String {
+    fn encode(&self, sink: &mut dyn Sink) {
+        sink.write_str(self);
+    }
+}
+
+impl StableBytes for &'static str {
+    fn encode(&self, sink: &mut dyn Sink) {
+        sink.write_str(self);
+    }
+}
+
+// shares metadata with `String`, so it's encoded the same way
+impl StableBytes for std::borrow::Cow<'static, str> {
+    fn encode(&self, sink: &mut dyn Sink) {
+        sink.write_str(self);
+    }
+}
+
+impl StableBytes for bool {
+    fn encode(&self, sink: &mut dyn Sink) {
+        sink.write(&[*self as u8]);
+    }
+}
+
+impl StableBytes for char {
+    fn encode(&self, sink: &mut dyn Sink) {
+        (*self as u32).encode(sink);
+    }
+}
+
+impl StableBytes for Duration {
+    fn encode(&self, sink: &mut dyn Sink) {
+        self.as_secs().encode(sink);
+        self.subsec_nanos().encode(sink);
+    }
+}
+
+// offset from unix epoch, preceded by `0` for times before epoch and `1` for others
+impl StableBytes for SystemTime {
+    fn encode(&self, sink: &mut dyn Sink) {
+        match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => {
+                sink.write(&[1]);
+                after.encode(sink);
+            }
+            Err(before) => {
+                sink.write(&[0]);
+                before.duration().encode(sink);
+            }
+        }
+    }
+}
+
+// non-unicode parts of path are replaced, since raw encoding of `OsStr` differs between platforms
+impl StableBytes for PathBuf {
+    fn encode(&self, sink: &mut dyn Sink) {
+        sink.write_str(&self.to_string_lossy());
+    }
+}
+
+// octets, preceded by version of address
+impl StableBytes for IpAddr {
+    fn encode(&self, sink: &mut dyn Sink) {
+        match self {
+            IpAddr::V4(address) => {
+                sink.write(&[4]);
+                sink.write(&address.octets());
+            }
+            IpAddr::V6(address) => {
+                sink.write(&[6]);
+                sink.write(&address.octets());
+            }
+        }
+    }
+}
+
+macro_rules! nonzero_bytes {
+    ($($ty:ty),*) => {
+        $(impl StableBytes for $ty {
+            fn encode(&self, sink: &mut dyn Sink) {
+                self.get().encode(sink);
+            }
+        })*
+    };
+}
+
+for_each_nonzero!(nonzero_bytes);
+
+macro_rules! encode_primitives {
+    ($($ty:ty),*) => {
+        fn encode_primitive(sink: &mut dyn Sink, value: &dyn Any) -> bool {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    value.encode(sink);
+                    return true;
+                }
+            )*
+            false
+        }
+    };
+}
+
+macro_rules! encode_nonzero {
+    ($($ty:ty),*) => {
+        fn encode_nonzero(sink: &mut dyn Sink, value: &dyn Any) -> bool {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    value.encode(sink);
+                    return true;
+                }
+            )*
+            false
+        }
+    };
+}
+
+for_each_primitive!(
+    encode_primitives,
+    &'static str,
+    std::borrow::Cow<'static, str>
+);
+for_each_nonzero!(encode_nonzero);
+
+macro_rules! encode_opaque {
+    ($($ty:ty),*) => {
+        fn encode_opaque(sink: &mut dyn Sink, value: &dyn Any) -> bool {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    value.encode(sink);
+                    return true;
+                }
+            )*
+            false
+        }
+    };
+}
+
+for_each_opaque!(encode_opaque);
+
+// `false` if value is not a leaf
+fn encode_leaf(sink: &mut dyn Sink, value: &dyn TypeInfoDynamic) -> bool {
+    let value: &dyn Any = value;
+    if encode_primitive(sink, value) || encode_nonzero(sink, value) || encode_opaque(sink, value) {
+        return true;
+    }
+
+    #[cfg(feature = "json")]
+    if let Some(value) = value.downcast_ref::<serde_json::Value>() {
+        // arrays and objects are walked as lists and maps
+        if !value.is_array() && !value.is_object() {
+            sink.write_str(&value.to_string());
+            return true;
+        }
+    }
+    false
+}
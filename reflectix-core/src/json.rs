@@ -0,0 +1,214 @@
+//! Reflection of [`serde_json::Value`]
+//!
+//! JSON values are schema-less, so their [`Type`] depends on the value itself:
+//!
+//! - objects are [`Data::Map`] with string keys, entries are accessed by [`FieldId::Named`]
+//! - arrays are [`Data::List`], elements are accessed by [`FieldId::Index`]
+//! - strings reflect as [`String`], numbers and booleans as primitives and `null` as unit
+//!
+//! Statically (through [`TypeInfo::INFO`]) value is described as primitive `Value`, because it may be anything.
+//! Values can't be constructed reflectively
+use std::any::Any;
+
+use serde_json::{Map, Value};
+
+use crate::containers::map_key;
+use crate::{
+    Data, FieldAccessError, FieldId, ReflectList, ReflectMap, RuntimeConstructError, Type,
+    TypeInfo, TypeInfoDynamic, TypeRef, Unsizeable, UnsizeableMut, UnsizeableOwned,
+};
+
+static VALUE_INFO: Type = Type {
+    ident: "Value",
+    data: Data::Primitive,
+    meta: &[],
+    docs: "",
+    non_exhaustive: false,
+    generics: &[],
+    type_id: std::any::TypeId::of::<Value>(),
+    size: std::mem::size_of::<Value>(),
+    align: std::mem::align_of::<Value>(),
+};
+
+static NULL_INFO: Type = Type {
+    ident: "null",
+    data: Data::Unit,
+    meta: &[],
+    docs: "",
+    non_exhaustive: false,
+    generics: &[],
+    type_id: std::any::TypeId::of::<()>(),
+    size: std::mem::size_of::<()>(),
+    align: std::mem::align_of::<()>(),
+};
+
+static BOOL_INFO: Type = Type {
+    ident: "bool",
+    data: Data::Primitive,
+    meta: &[],
+    docs: "",
+    non_exhaustive: false,
+    generics: &[],
+    type_id: std::any::TypeId::of::<bool>(),
+    size: std::mem::size_of::<bool>(),
+    align: std::mem::align_of::<bool>(),
+};
+
+static NUMBER_INFO: Type = Type {
+    ident: "Number",
+    data: Data::Primitive,
+    meta: &[],
+    docs: "",
+    non_exhaustive: false,
+    generics: &[],
+    type_id: std::any::TypeId::of::<serde_json::Number>(),
+    size: std::mem::size_of::<serde_json::Number>(),
+    align: std::mem::align_of::<serde_json::Number>(),
+};
+
+static ARRAY_INFO: Type = Type {
+    ident: "Array",
+    data: Data::List(TypeRef::of::<Value>()),
+    meta: &[],
+    docs: "",
+    non_exhaustive: false,
+    generics: &[],
+    type_id: std::any::TypeId::of::<Vec<Value>>(),
+    size: std::mem::size_of::<Vec<Value>>(),
+    align: std::mem::align_of::<Vec<Value>>(),
+};
+
+static OBJECT_INFO: Type = Type {
+    ident: "Object",
+    data: Data::Map {
+        key: TypeRef::of::<String>(),
+        value: TypeRef::of::<Value>(),
+    },
+    meta: &[],
+    docs: "",
+    non_exhaustive: false,
+    generics: &[],
+    type_id: std::any::TypeId::of::<Map<String, Value>>(),
+    size: std::mem::size_of::<Map<String, Value>>(),
+    align: std::mem::align_of::<Map<String, Value>>(),
+};
+
+impl TypeInfoDynamic for Value {
+    fn get_dynamic(&self) -> &'static Type {
+        match self {
+            Value::Null => &NULL_INFO,
+            Value::Bool(_) => &BOOL_INFO,
+            Value::Number(_) => &NUMBER_INFO,
+            Value::String(_) => String::INFO,
+            Value::Array(_) => &ARRAY_INFO,
+            Value::Object(_) => &OBJECT_INFO,
+        }
+    }
+
+    fn construct_struct(
+        &self,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Primitive)
+    }
+
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Primitive)
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        let value = match (self, id) {
+            (Value::Object(map), FieldId::Named(key)) => map.get(key),
+            (Value::Array(array), FieldId::Index(index)) => array.get(index),
+            (Value::Object(_) | Value::Array(_), _) => None,
+            _ => return Err(FieldAccessError::Unit),
+        };
+
+        value.map(Unsizeable::new).ok_or(FieldAccessError::NotFound)
+    }
+
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        let value = match (self, id) {
+            (Value::Object(map), FieldId::Named(key)) => map.get_mut(key),
+            (Value::Array(array), FieldId::Index(index)) => array.get_mut(index),
+            (Value::Object(_) | Value::Array(_), _) => None,
+            _ => return Err(FieldAccessError::Unit),
+        };
+
+        value
+            .map(UnsizeableMut::new)
+            .ok_or(FieldAccessError::NotFound)
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        match self {
+            Value::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+        match self {
+            Value::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+
+    fn as_map(&self) -> Option<&dyn ReflectMap> {
+        match self {
+            Value::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    fn as_map_mut(&mut self) -> Option<&mut dyn ReflectMap> {
+        match self {
+            Value::Object(map) => Some(map),
+            _ => None,
+        }
+    }
+}
+
+impl ReflectMap for Map<String, Value> {
+    fn len(&self) -> usize {
+        Map::len(self)
+    }
+
+    fn get(&self, key: Box<dyn Any>) -> Result<Unsizeable<'_>, FieldAccessError> {
+        let key = map_key::<String>(key).map_err(|_| FieldAccessError::NotFound)?;
+        Map::get(self, &key)
+            .map(Unsizeable::new)
+            .ok_or(FieldAccessError::NotFound)
+    }
+
+    fn insert(
+        &mut self,
+        key: Box<dyn Any>,
+        value: Box<dyn Any>,
+    ) -> Result<Option<UnsizeableOwned>, FieldAccessError> {
+        let key = map_key::<String>(key)?;
+        let value = value
+            .downcast::<Value>()
+            .map_err(|_| FieldAccessError::UnmatchingType)?;
+        Ok(Map::insert(self, key, *value).map(UnsizeableOwned::new))
+    }
+
+    fn remove(&mut self, key: Box<dyn Any>) -> Result<UnsizeableOwned, FieldAccessError> {
+        let key = map_key::<String>(key).map_err(|_| FieldAccessError::NotFound)?;
+        Map::remove(self, &key)
+            .map(UnsizeableOwned::new)
+            .ok_or(FieldAccessError::NotFound)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Unsizeable<'_>, Unsizeable<'_>)> + '_> {
+        Box::new(Map::iter(self).map(|(key, value)| (Unsizeable::new(key), Unsizeable::new(value))))
+    }
+}
+
+impl TypeInfo for Value {
+    const INFO: &'static Type = &VALUE_INFO;
+}
@@ -0,0 +1,304 @@
+//! Owned, comparable keys, extracted out of reflected values
+//!
+//! [`extract_key`] follows dot-separated path (`address.city`, `items.0.price`) into value and copies
+//! leaf at it's end into [`KeyValue`], which is `Ord + Hash` regardless of what the leaf was.
+//! Keys are what sorting, indexing and grouping of erased values is built on.
+//!
+//! - integers are widened to `i128` (signed) or `u128` (unsigned), including non-zero integers
+//! - floats are compared by [`f64::total_cmp`], so `NaN` is ordered too and equal to itself
+//! - `String`, `&'static str` and `Cow<'static, str>` become [`KeyValue::Str`], unit variants of enums become [`KeyValue::Variant`]
+//! - optional values are transparent: `Some` is followed into, `None` anywhere on path is [`KeyValue::None`]
+//! - so are wrappers, such as `Box<T>`, and values behind interior mutability, which are read while guard is held
+//!
+//! Keys of different kinds are ordered by kind, in order of declaration of [`KeyValue`] variants.
+//!
+//! [`group_by`] buckets values by key at the same path. Values don't need to be of the same type,
+//! only to have key-able leaf at that path
+use std::any::Any;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+
+use crate::{Data, FieldAccessError, FieldId, TypeInfoDynamic};
+
+/// Failure of key extraction
+#[derive(thiserror::Error, Debug)]
+pub enum KeyError {
+    /// Segment of path doesn't refer to any field
+    #[error("Field `{segment}` is not found in `{ty}`")]
+    NotFound {
+        #[allow(missing_docs)]
+        ty: &'static str,
+        #[allow(missing_docs)]
+        segment: String,
+    },
+    /// Value at the end of path can't be used as key
+    #[error("Value of `{0}` can't be used as key")]
+    Unsupported(&'static str),
+    #[allow(missing_docs)]
+    #[error(transparent)]
+    Access(#[from] FieldAccessError),
+}
+
+/// Owned copy of primitive leaf, which can be compared and hashed
+#[derive(Clone, Debug)]
+pub enum KeyValue {
+    /// Optional value on path is `None`
+    None,
+    #[allow(missing_docs)]
+    Bool(bool),
+    /// Signed integer
+    Int(i128),
+    /// Unsigned integer
+    UInt(u128),
+    #[allow(missing_docs)]
+    Float(f64),
+    #[allow(missing_docs)]
+    Char(char),
+    #[allow(missing_docs)]
+    Str(String),
+    /// Name of unit variant
+    Variant(&'static str),
+}
+
+impl KeyValue {
+    // position of kind in declaration order
+    fn rank(&self) -> u8 {
+        match self {
+            KeyValue::None => 0,
+            KeyValue::Bool(_) => 1,
+            KeyValue::Int(_) => 2,
+            KeyValue::UInt(_) => 3,
+            KeyValue::Float(_) => 4,
+            KeyValue::Char(_) => 5,
+            KeyValue::Str(_) => 6,
+            KeyValue::Variant(_) => 7,
+        }
+    }
+}
+
+impl Ord for KeyValue {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (KeyValue::Bool(left), KeyValue::Bool(right)) => left.cmp(right),
+            (KeyValue::Int(left), KeyValue::Int(right)) => left.cmp(right),
+            (KeyValue::UInt(left), KeyValue::UInt(right)) => left.cmp(right),
+            (KeyValue::Float(left), KeyValue::Float(right)) => left.total_cmp(right),
+            (KeyValue::Char(left), KeyValue::Char(right)) => left.cmp(right),
+            (KeyValue::Str(left), KeyValue::Str(right)) => left.cmp(right),
+            (KeyValue::Variant(left), KeyValue::Variant(right)) => left.cmp(right),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for KeyValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for KeyValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for KeyValue {}
+
+impl Hash for KeyValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.rank().hash(state);
+        match self {
+            KeyValue::None => {}
+            KeyValue::Bool(value) => value.hash(state),
+            KeyValue::Int(value) => value.hash(state),
+            KeyValue::UInt(value) => value.hash(state),
+            // `total_cmp` tells floats apart exactly by their bits
+            KeyValue::Float(value) => value.to_bits().hash(state),
+            KeyValue::Char(value) => value.hash(state),
+            KeyValue::Str(value) => value.hash(state),
+            KeyValue::Variant(value) => value.hash(state),
+        }
+    }
+}
+
+impl std::fmt::Display for KeyValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KeyValue::None => write!(f, "None"),
+            KeyValue::Bool(value) => write!(f, "{}", value),
+            KeyValue::Int(value) => write!(f, "{}", value),
+            KeyValue::UInt(value) => write!(f, "{}", value),
+            KeyValue::Float(value) => write!(f, "{}", value),
+            KeyValue::Char(value) => write!(f, "{}", value),
+            KeyValue::Str(value) => write!(f, "{}", value),
+            KeyValue::Variant(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+/// Copy leaf at dot-separated `path` of `value` into key, see [module documentation](self)
+///
+/// Empty path refers to `value` itself
+pub fn extract_key(value: &dyn TypeInfoDynamic, path: &str) -> Result<KeyValue, KeyError> {
+    let segments = path
+        .split('.')
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>();
+    extract(value, &segments)
+}
+
+/// Group `items` by key at dot-separated `path`, keeping order of items within each group
+///
+/// Fails on the first item, key of which can't be extracted
+pub fn group_by<'v>(
+    items: impl IntoIterator<Item = &'v dyn TypeInfoDynamic>,
+    path: &str,
+) -> Result<HashMap<KeyValue, Vec<&'v dyn TypeInfoDynamic>>, KeyError> {
+    let mut groups: HashMap<KeyValue, Vec<&'v dyn TypeInfoDynamic>> = HashMap::new();
+    for item in items {
+        groups
+            .entry(extract_key(item, path)?)
+            .or_default()
+            .push(item);
+    }
+    Ok(groups)
+}
+
+// follows `segments` into value, looking through wrappers, guards and optional values
+fn extract(value: &dyn TypeInfoDynamic, segments: &[&str]) -> Result<KeyValue, KeyError> {
+    let value = value.innermost();
+    if let Some(shared) = value.as_shared() {
+        return extract(&*shared.read()?, segments);
+    }
+    if let Data::Optional(_) = value.get_dynamic().data {
+        return match value.as_some()? {
+            Some(inner) => extract(inner.as_dynamic(), segments),
+            None => Ok(KeyValue::None),
+        };
+    }
+
+    match segments.split_first() {
+        Some((segment, rest)) => {
+            let id = lookup_field(value, segment)?;
+            extract(value.field(id)?.as_dynamic(), rest)
+        }
+        None => to_key(value),
+    }
+}
+
+fn lookup_field(value: &dyn TypeInfoDynamic, segment: &str) -> Result<FieldId, KeyError> {
+    let ty = value.get_dynamic();
+    let not_found = || KeyError::NotFound {
+        ty: ty.ident,
+        segment: segment.to_string(),
+    };
+
+    let fields = match (&ty.data, value.variant()) {
+        (Data::List(_) | Data::Array { .. }, _) => {
+            return segment.parse().map(FieldId::Index).map_err(|_| not_found())
+        }
+        (_, Some(variant)) => variant.fields.as_slice(),
+        (Data::Struct(fields) | Data::Tuple(fields), None) => fields.as_slice(),
+        _ => &[],
+    };
+    fields
+        .iter()
+        .find(|field| field.id.matches(segment))
+        .map(|field| field.id.clone())
+        .ok_or_else(not_found)
+}
+
+fn to_key(value: &dyn TypeInfoDynamic) -> Result<KeyValue, KeyError> {
+    let ty = value.get_dynamic();
+    let any: &dyn Any = value;
+    if let Some(key) = primitive_key(any).or_else(|| nonzero_key(any)) {
+        return Ok(key);
+    }
+    match (&ty.data, value.variant()) {
+        (Data::Enum(_), Some(variant)) if variant.is_unit() => Ok(KeyValue::Variant(variant.ident)),
+        _ => Err(KeyError::Unsupported(ty.ident)),
+    }
+}
+
+trait IntoKey {
+    fn to_key(&self) -> KeyValue;
+}
+
+macro_rules! into_key {
+    ($variant:ident as $widened:ty: $($ty:ty),*) => {
+        $(impl IntoKey for $ty {
+            fn to_key(&self) -> KeyValue {
+                KeyValue::$variant(*self as $widened)
+            }
+        })*
+    };
+}
+
+into_key!(Int as i128: i8, i16, i32, i64, i128, isize);
+into_key!(UInt as u128: u8, u16, u32, u64, u128, usize);
+into_key!(Float as f64: f32, f64);
+
+impl IntoKey for bool {
+    fn to_key(&self) -> KeyValue {
+        KeyValue::Bool(*self)
+    }
+}
+
+impl IntoKey for char {
+    fn to_key(&self) -> KeyValue {
+        KeyValue::Char(*self)
+    }
+}
+
+impl IntoKey for String {
+    fn to_key(&self) -> KeyValue {
+        KeyValue::Str(self.clone())
+    }
+}
+
+impl IntoKey for &'static str {
+    fn to_key(&self) -> KeyValue {
+        KeyValue::Str(self.to_string())
+    }
+}
+
+impl IntoKey for std::borrow::Cow<'static, str> {
+    fn to_key(&self) -> KeyValue {
+        KeyValue::Str(self.to_string())
+    }
+}
+
+macro_rules! key_primitives {
+    ($($ty:ty),*) => {
+        fn primitive_key(value: &dyn Any) -> Option<KeyValue> {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(value.to_key());
+                }
+            )*
+            None
+        }
+    };
+}
+
+macro_rules! key_nonzero {
+    ($($ty:ty),*) => {
+        fn nonzero_key(value: &dyn Any) -> Option<KeyValue> {
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    return Some(value.get().to_key());
+                }
+            )*
+            None
+        }
+    };
+}
+
+for_each_primitive!(key_primitives, &'static str, std::borrow::Cow<'static, str>);
+for_each_nonzero!(key_nonzero);
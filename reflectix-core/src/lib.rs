@@ -1,7 +1,116 @@
 #![deny(missing_docs)]
 #![allow(missing_docs)]
 
+// Invokes `$callback!` with comma-separated list of all primitive types,
+// so that modules which need to handle primitives individually don't repeat the list.
+// Types, which can't be handled by every callback (such as `&'static str`, which can't be parsed), are appended by caller
+macro_rules! for_each_primitive {
+    ($callback:ident $(, $extra:ty)*) => {
+        $callback!(
+            u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, usize, isize, String, f32, f64, bool,
+            char $(, $extra)*
+        );
+    };
+}
+
+// Same as `for_each_primitive!`, but for non-zero integers. They are commonly used as ids in APIs,
+// so they are leaves of reflection too, but can't be constructed reflectively, just like other primitives
+macro_rules! for_each_nonzero {
+    ($callback:ident) => {
+        $callback!(
+            NonZeroU8,
+            NonZeroU16,
+            NonZeroU32,
+            NonZeroU64,
+            NonZeroU128,
+            NonZeroUsize,
+            NonZeroI8,
+            NonZeroI16,
+            NonZeroI32,
+            NonZeroI64,
+            NonZeroI128,
+            NonZeroIsize
+        );
+    };
+}
+
+// Same as `for_each_primitive!`, but for std types, which are common in real structs, but have
+// no reflectable structure of their own. They are opaque leaves: copied, compared and hashed as a whole
+macro_rules! for_each_opaque {
+    ($callback:ident) => {
+        $callback!(Duration, SystemTime, PathBuf, IpAddr);
+    };
+}
+
+pub mod apply;
+#[cfg(feature = "console")]
+pub mod console;
+mod containers;
+pub mod csv;
+pub mod diff;
+pub mod dynamic;
+pub mod error;
+pub mod frozen;
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+pub mod handle;
+pub mod hash;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod key;
+mod manual;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod named;
+#[cfg(feature = "rayon")]
+pub mod parallel;
+pub mod plan;
+pub mod pool;
+pub mod query;
+pub mod registry;
+pub mod router;
+#[cfg(feature = "serde")]
+pub mod schema;
+#[cfg(feature = "serde")]
+pub mod serde_variant;
+mod shared;
+pub mod size;
+pub mod test_support;
+#[cfg(feature = "tracing")]
+pub mod trace;
+pub mod type_map;
+pub mod view;
+#[cfg(feature = "xml")]
+pub mod xml;
+
+/// Generic parameter of [`Type`], together with it's argument
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GenericParamInfo {
+    /// Name of parameter, as declared
+    pub name: &'static str,
+    #[allow(missing_docs)]
+    pub kind: GenericParamKind,
+}
+
+/// Kind of generic parameter, together with argument of instantiation
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GenericParamKind {
+    /// Lifetime parameter. Reflected types are `'static`, so there is no argument to record
+    ///
+    /// Derive rejects types with lifetime parameters, since it would have to substitute `'static` into every field.
+    /// Manual implementations for instantiations such as `Foo<'static>` can still record them
+    Lifetime,
+    /// Type parameter and metadata of it's argument
+    Type(TypeRef),
+    /// Const parameter and it's value, cast to `i128`
+    Const(i128),
+}
+
 /// Information about type fields (if there is any)
+///
+/// Fields are listed in declaration order, which is stable and can be relied upon
+/// (e.g. by positional encodings). Derive allows to override position of particular field
+/// with `#[reflectix(order = N)]`, in which case the rest of fields fill remaining positions in declaration order
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub enum Fields {
     /// Type is structure-like and has fields with names
@@ -29,10 +138,41 @@ pub enum Data {
     /// Variants of this enum
     Enum(Variants),
 
+    /// Fields of union, recorded for layout inspection only
+    ///
+    /// Active field is unknown, so fields can't be accessed ([`FieldAccessError::Union`])
+    /// and union can't be constructed ([`RuntimeConstructError::Union`])
+    Union(Fields),
+
     /// Unit type, which means that type doesn't have any fields.
     ///
     /// **Note**: that this differs from [`Data::Primitive`] semantic meaning: you can define types which hold this data
     Unit,
+
+    /// Sequence of elements of same type, which are accessed by [`FieldId::Index`]
+    List(TypeRef),
+
+    /// Tuple, elements of which are [`Fields::Indexed`] and accessed by [`FieldId::Index`], just as fields of tuple struct
+    Tuple(Fields),
+
+    /// Fixed-size array of elements of same type, which are accessed by [`FieldId::Index`]
+    Array {
+        #[allow(missing_docs)]
+        element: TypeRef,
+        /// Number of elements
+        len: usize,
+    },
+
+    /// Value which may be absent, accessed by `FieldId::Index(0)` if present
+    Optional(TypeRef),
+
+    /// Collection of key-value pairs. Entries with string keys are accessed by [`FieldId::Named`]
+    Map {
+        #[allow(missing_docs)]
+        key: TypeRef,
+        #[allow(missing_docs)]
+        value: TypeRef,
+    },
 }
 
 /// Discriminant of particular field
@@ -54,8 +194,178 @@ pub struct Field {
     /// Acts as path in filesystem
     pub id: FieldId,
     /// Associated info of field's type
-    pub ty: &'static Type,
+    pub ty: TypeRef,
+    /// [`std::any::TypeId`] of field's type, exactly as declared
+    ///
+    /// Unlike metadata in [`Field::ty`], which is shared by transparent wrappers and their inner types,
+    /// this is the type constructors expect as argument for this field
+    pub type_id: std::any::TypeId,
+    /// Key-value pairs attached with `#[reflectix(meta(key = "value"))]`
+    pub meta: &'static [(&'static str, &'static str)],
+    /// Doc comment, captured with `#[reflectix(docs)]` on the type. Empty if not captured
+    pub docs: &'static str,
+    /// Visibility of field, as declared. Fields of enum variants are always public
+    pub vis: Visibility,
+    /// Whether field holds value itself or reference to it
+    ///
+    /// References are transparent, so [`Field::ty`] of reference field describes referenced type
+    pub ownership: Ownership,
+    /// Offset of field in bytes from start of the value
+    ///
+    /// Captured only for fields of `#[repr(C)]` structs, since layout of other types is unspecified
+    pub offset: Option<usize>,
+    /// Whether field has default, given with `#[reflectix(default)]`, so it can be omitted from trailing constructor arguments
+    pub has_default: bool,
+}
+
+/// The way [`Field`] holds it's value
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Ownership {
+    /// Value is stored in field
+    Owned,
+    /// `&'static T`
+    Shared,
+    /// `&'static mut T`
+    Mutable,
+}
+
+/// Declared visibility of [`Field`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Visibility {
+    /// `pub`
+    Public,
+    /// `pub(crate)`, `pub(super)` or `pub(in path)`
+    Restricted,
+    /// No visibility modifier or `pub(self)`
+    Private,
+}
+
+impl Field {
+    /// Whether field is declared as `pub`
+    pub fn is_public(&self) -> bool {
+        self.vis == Visibility::Public
+    }
+
+    /// Value of metadata attribute `key`
+    pub fn get_meta(&self, key: &str) -> Option<&'static str> {
+        find_meta(self.meta, key)
+    }
+}
+
+/// Metadata key of tags, given with `#[reflectix(tag = "...")]`
+pub const TAG_META: &str = "tag";
+
+fn find_meta(meta: &'static [(&'static str, &'static str)], key: &str) -> Option<&'static str> {
+    meta.iter()
+        .find(|(candidate, _)| *candidate == key)
+        .map(|(_, value)| *value)
+}
+
+/// Lazy reference to metadata of another type
+///
+/// Metadata of type, which contains itself (e.g. through `Option<Box<Self>>`), can't refer
+/// to it's own metadata directly, because evaluation of `static` would depend on itself.
+/// [`TypeRef`] defers resolution until metadata is actually accessed, so self-referential and
+/// mutually recursive types can be reflected.
+///
+/// Dereferences to [`Type`], so it can be used mostly like `&'static Type`
+#[derive(Clone, Copy)]
+pub struct TypeRef(fn() -> &'static Type);
+
+impl TypeRef {
+    /// Reference to metadata of `T`
+    pub const fn of<T: TypeInfo>() -> Self {
+        Self(|| T::INFO)
+    }
+
+    /// Reference to metadata, returned by `resolve`
+    pub const fn new(resolve: fn() -> &'static Type) -> Self {
+        Self(resolve)
+    }
+
+    /// Resolve referenced metadata
+    pub fn get(&self) -> &'static Type {
+        (self.0)()
+    }
+}
+
+impl std::ops::Deref for TypeRef {
+    type Target = Type;
+
+    fn deref(&self) -> &Self::Target {
+        self.get()
+    }
+}
+
+/// References are equal if they refer to the same type, so comparing metadata of recursive types always terminates
+impl PartialEq for TypeRef {
+    fn eq(&self, other: &Self) -> bool {
+        let (left, right) = (self.get(), other.get());
+        std::ptr::eq(left, right) || left.type_id == right.type_id
+    }
+}
+
+impl Eq for TypeRef {}
+
+impl PartialEq<&Type> for TypeRef {
+    fn eq(&self, other: &&Type) -> bool {
+        let resolved = self.get();
+        std::ptr::eq(resolved, *other) || resolved.type_id == other.type_id
+    }
+}
+
+/// Only name of referenced type is printed, since metadata of recursive types is cyclic
+impl std::fmt::Debug for TypeRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("TypeRef").field(&self.get().ident).finish()
+    }
+}
+
+impl FieldId {
+    /// Whether `name` refers to this field: by name, or by index written in decimal
+    pub fn matches(&self, name: &str) -> bool {
+        self.matches_with(name, NameMatching::Exact)
+    }
+
+    /// Same as [`FieldId::matches`], but names are compared according to `matching`
+    pub fn matches_with(&self, name: &str, matching: NameMatching) -> bool {
+        match self {
+            FieldId::Named(field_name) => matching.matches(field_name, name),
+            FieldId::Index(index) => name.parse() == Ok(*index),
+        }
+    }
+}
+
+/// How requested names are compared to declared names of fields
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum NameMatching {
+    /// Names must be equal
+    #[default]
+    Exact,
+    /// Case, `_` and `-` are ignored, so `userName`, `UserName` and `user-name` all match `user_name`
+    ///
+    /// Meant for integration with systems, which use different naming convention
+    Lenient,
+}
+
+impl NameMatching {
+    /// Whether `requested` name refers to `declared` one
+    pub fn matches(&self, declared: &str, requested: &str) -> bool {
+        match self {
+            Self::Exact => declared == requested,
+            Self::Lenient => {
+                let normalize = |name: &str| {
+                    name.chars()
+                        .filter(|ch| *ch != '_' && *ch != '-')
+                        .flat_map(char::to_lowercase)
+                        .collect::<String>()
+                };
+                normalize(declared) == normalize(requested)
+            }
+        }
+    }
 }
+
 impl From<&'static str> for FieldId {
     fn from(s: &'static str) -> Self {
         FieldId::Named(s)
@@ -68,31 +378,332 @@ impl From<usize> for FieldId {
     }
 }
 
+impl std::fmt::Display for FieldId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldId::Index(index) => write!(f, "{}", index),
+            FieldId::Named(name) => f.write_str(name),
+        }
+    }
+}
+
+impl Variant {
+    /// Value of metadata attribute `key`
+    pub fn get_meta(&self, key: &str) -> Option<&'static str> {
+        find_meta(self.meta, key)
+    }
+
+    /// Whether variant has no fields, so it can be built from name alone
+    pub fn is_unit(&self) -> bool {
+        matches!(self.fields, Fields::Unit)
+    }
+
+    /// Number of reflected fields of variant
+    pub fn field_count(&self) -> usize {
+        self.fields.as_slice().len()
+    }
+
+    /// Check that `args` match fields of this variant, as [`TypeInfoDynamic::construct_enum`] expects them
+    ///
+    /// Allows to validate arguments before giving them away to constructor
+    pub fn check_args(&self, args: &[&dyn Any]) -> Result<(), RuntimeConstructError> {
+        let fields = self.fields.as_slice();
+        match args.len().cmp(&fields.len()) {
+            std::cmp::Ordering::Less => return Err(RuntimeConstructError::NotEnoughArgs),
+            std::cmp::Ordering::Greater => return Err(RuntimeConstructError::TooManyArgs),
+            std::cmp::Ordering::Equal => {}
+        }
+
+        for (index, (field, arg)) in fields.iter().zip(args).enumerate() {
+            if Any::type_id(*arg) != field.type_id {
+                return Err(RuntimeConstructError::UnexpectedType {
+                    index,
+                    expected: field.ty.ident,
+                    field: field.id.clone(),
+                    variant: Some(self.ident),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Fields {
+    /// All fields, empty if type is unit
+    pub fn as_slice(&self) -> &'static [Field] {
+        match self {
+            Fields::Named(fields) | Fields::Indexed(fields) => fields,
+            Fields::Unit => &[],
+        }
+    }
+}
 
 /// Variant of enum type
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Variant {
     #[allow(missing_docs)]
     pub ident: &'static str,
+    /// Discriminant of variant, either explicit (`Variant = 5`) or implicit
+    ///
+    /// Derive accepts only integer literals as explicit discriminants
+    ///
+    /// Stored as `i128` regardless of [`Variants::repr`], so that every backing integer type fits
+    pub discriminator: i128,
     #[allow(missing_docs)]
     pub fields: Fields,
+    /// Key-value pairs attached with `#[reflectix(meta(key = "value"))]`
+    pub meta: &'static [(&'static str, &'static str)],
+    /// Doc comment, captured with `#[reflectix(docs)]` on the enum. Empty if not captured
+    pub docs: &'static str,
+    /// Whether variant is `#[non_exhaustive]`, so more fields may be added to it
+    pub non_exhaustive: bool,
 }
 #[allow(missing_docs)]
 #[derive(Clone, PartialEq, Eq, Debug)]
 pub struct Variants {
     #[allow(missing_docs)]
     pub variants: &'static [Variant],
+    /// Integer type backing discriminants of enum
+    pub repr: DiscriminantRepr,
+    /// Whether discriminants form a range without gaps
+    ///
+    /// Implicit discriminants are always contiguous
+    pub contiguous: bool,
+    /// Handling of names, which don't belong to any variant
+    pub unknown: UnknownVariants,
+}
+
+impl Variants {
+    /// Reflected variants in declaration order
+    pub fn iter(&self) -> std::slice::Iter<'static, Variant> {
+        self.variants.iter()
+    }
+
+    /// Variant, exposed under `name`
+    pub fn find(&self, name: &str) -> Option<&'static Variant> {
+        self.variants.iter().find(|variant| variant.ident == name)
+    }
+
+    /// Variant with discriminant `discriminator`, e.g. to decode tag of wire format
+    pub fn find_by_discriminator(&self, discriminator: i128) -> Option<&'static Variant> {
+        self.variants
+            .iter()
+            .find(|variant| variant.discriminator == discriminator)
+    }
+
+    /// Names of unit variants, which are the only ones that can be built from name alone
+    pub fn unit_names(&self) -> impl Iterator<Item = &'static str> {
+        self.iter()
+            .filter(|variant| variant.is_unit())
+            .map(|variant| variant.ident)
+    }
+}
+
+impl IntoIterator for &Variants {
+    type Item = &'static Variant;
+    type IntoIter = std::slice::Iter<'static, Variant>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Policy of parsing names, which don't belong to any variant of enum
+///
+/// Chosen with `#[reflectix(unknown_variants = "skip")]` or `#[reflectix(fallback_variant = "...")]`,
+/// enums reject unknown names by default. Respected by `serde_variant` module
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum UnknownVariants {
+    /// Unknown name is an error
+    #[default]
+    Error,
+    /// Values with unknown names are dropped from collections they are parsed into
+    Skip,
+    /// Unknown name is replaced with name of this unit variant
+    Fallback(&'static str),
+}
+
+/// Integer type backing enum discriminants, as specified by `#[repr(...)]`
+///
+/// If enum doesn't specify integer repr, it's discriminants are [`DiscriminantRepr::Isize`]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(missing_docs)]
+pub enum DiscriminantRepr {
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+}
+
+impl DiscriminantRepr {
+    /// Whether backing integer type is signed
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 | Self::Isize
+        )
+    }
+
+    /// Size of backing integer type in bytes
+    pub fn size(&self) -> usize {
+        match self {
+            Self::U8 | Self::I8 => 1,
+            Self::U16 | Self::I16 => 2,
+            Self::U32 | Self::I32 => 4,
+            Self::U64 | Self::I64 => 8,
+            Self::U128 | Self::I128 => 16,
+            Self::Usize | Self::Isize => std::mem::size_of::<usize>(),
+        }
+    }
 }
 
 /// Information about type
 ///
 /// if [`TypeInfo`] is implemented, comes as associated constant
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, Debug)]
 pub struct Type {
     /// Type name, exactly as in code (case and underscores are preserved)
     pub ident: &'static str,
     /// Type of data that this type contains
     pub data: Data,
+    /// Key-value pairs attached with `#[reflectix(meta(key = "value"))]`
+    pub meta: &'static [(&'static str, &'static str)],
+    /// Doc comment, captured with `#[reflectix(docs)]`. Empty if not captured
+    pub docs: &'static str,
+    /// Whether type is `#[non_exhaustive]`, so more fields (or variants, for enums) may be added to it
+    pub non_exhaustive: bool,
+    /// Generic parameters of type in order of declaration, together with their arguments
+    ///
+    /// Metadata is produced per instantiation, so `Wrapper<i32>` and `Wrapper<String>` differ here
+    pub generics: &'static [GenericParamInfo],
+    /// [`TypeId`](std::any::TypeId) of described type, which allows to tell whether erased value is of this type
+    ///
+    /// Transparent wrappers share metadata of wrapped type, so this is id of wrapped type for them
+    pub type_id: std::any::TypeId,
+    /// Size of type in bytes, as returned by [`std::mem::size_of`]
+    ///
+    /// Same as with `type_id`, transparent wrappers report layout of wrapped type
+    pub size: usize,
+    /// Alignment of type in bytes, as returned by [`std::mem::align_of`]
+    pub align: usize,
+}
+
+/// Metadata of different types is never equal, types of fields are compared only by their identity
+impl PartialEq for Type {
+    fn eq(&self, other: &Self) -> bool {
+        if std::ptr::eq(self, other) {
+            return true;
+        }
+        self.type_id == other.type_id
+            && self.ident == other.ident
+            && self.size == other.size
+            && self.align == other.align
+            && self.non_exhaustive == other.non_exhaustive
+            && self.docs == other.docs
+            && self.meta == other.meta
+            && self.generics == other.generics
+            && self.data == other.data
+    }
+}
+
+impl Eq for Type {}
+
+impl Type {
+    /// Whether `value` is of type, described by this metadata
+    pub fn describes(&self, value: &dyn Any) -> bool {
+        value.type_id() == self.type_id
+    }
+
+    /// Value of metadata attribute `key`
+    pub fn get_meta(&self, key: &str) -> Option<&'static str> {
+        find_meta(self.meta, key)
+    }
+
+    /// Name of type, followed by arguments of it's generic parameters, such as `Wrapper<i32>` or `Grid<3>`
+    ///
+    /// Unlike [`Type::ident`], tells instantiations of generic type apart. Lifetimes are omitted
+    pub fn full_name(&self) -> String {
+        let arguments = self
+            .generics
+            .iter()
+            .filter_map(|param| match &param.kind {
+                GenericParamKind::Lifetime => None,
+                GenericParamKind::Type(ty) => Some(ty.full_name()),
+                GenericParamKind::Const(value) => Some(value.to_string()),
+            })
+            .collect::<Vec<_>>();
+        match arguments.is_empty() {
+            true => self.ident.to_string(),
+            false => format!("{}<{}>", self.ident, arguments.join(", ")),
+        }
+    }
+
+    /// Tags of type, given with `#[reflectix(tag = "...")]`, in order of declaration
+    pub fn tags(&self) -> impl Iterator<Item = &'static str> {
+        self.meta
+            .iter()
+            .filter(|(key, _)| *key == TAG_META)
+            .map(|(_, tag)| *tag)
+    }
+
+    #[allow(missing_docs)]
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags().any(|candidate| candidate == tag)
+    }
+
+    /// Find field by it's name (or index, if field is tuple-like)
+    ///
+    /// For enums, fields of all variants are searched and first match is returned
+    pub fn find_field(&self, name: &str) -> Option<&'static Field> {
+        self.find_field_with(name, NameMatching::Exact)
+    }
+
+    /// Same as [`Type::find_field`], but names are compared according to `matching`
+    pub fn find_field_with(&self, name: &str, matching: NameMatching) -> Option<&'static Field> {
+        let fields: Box<dyn Iterator<Item = &'static Field>> = match &self.data {
+            Data::Struct(fields) | Data::Tuple(fields) | Data::Union(fields) => {
+                Box::new(fields.as_slice().iter())
+            }
+            Data::Enum(variants) => Box::new(
+                variants
+                    .variants
+                    .iter()
+                    .flat_map(|variant| variant.fields.as_slice()),
+            ),
+            Data::Primitive
+            | Data::Unit
+            | Data::List(_)
+            | Data::Array { .. }
+            | Data::Optional(_)
+            | Data::Map { .. } => return None,
+        };
+
+        fields
+            .into_iter()
+            .find(|field| field.id.matches_with(name, matching))
+    }
+
+    /// Resolve dot-separated path of fields, such as `"a.b.c"`, walking static metadata only
+    ///
+    /// Allows to verify paths before touching any value. Returns metadata of the last field in path
+    pub fn resolve_path(&self, path: &str) -> Option<&'static Field> {
+        let mut segments = path.split('.');
+        let mut field = self.find_field(segments.next()?)?;
+
+        for segment in segments {
+            field = field.ty.find_field(segment)?;
+        }
+        Some(field)
+    }
 }
 
 /// If attempt to borrow field was incorrect
@@ -109,6 +720,101 @@ pub enum FieldAccessError {
     /// If accessing field that is not present in type
     #[error("Field not found")]
     NotFound,
+
+    /// If there were an attempt to mutably access field behind shared reference
+    #[error("Attempt to mutably access field behind shared reference")]
+    ReadOnly,
+
+    /// If accessing non-public field of type, derived with `#[reflectix(public_only)]`
+    #[error("Field is not public")]
+    Private,
+
+    /// If there were an attempt to mutably access field of pinned value
+    #[error("Attempt to mutably access field of pinned value")]
+    Pinned,
+
+    /// If accessing field of type, derived with `#[reflectix(static_only)]`
+    #[error("Type provides metadata only")]
+    StaticOnly,
+
+    /// If accessing field of union, which can't tell active field
+    #[error("Attempt to access field of union")]
+    Union,
+
+    /// If there were an attempt to mutate [`frozen::Frozen`] value
+    #[error("Attempt to mutate frozen value")]
+    Frozen,
+
+    /// If borrowing field of value behind interior mutability without guard, see [`ReflectShared`]
+    #[error("Attempt to borrow field of value behind interior mutability without guard")]
+    Shared,
+
+    /// If `RefCell` is already borrowed in a way, which conflicts with requested borrow
+    #[error("Value is already borrowed")]
+    Borrowed,
+
+    /// If lock around value is poisoned by panic of it's previous holder
+    #[error("Lock is poisoned")]
+    Poisoned,
+
+    /// Domain error of user code, such as validation in manual implementation of [`TypeInfoDynamic`]
+    #[error(transparent)]
+    Custom(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl FieldAccessError {
+    /// Wrap error of user code into [`FieldAccessError::Custom`]
+    pub fn custom(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Self::Custom(error.into())
+    }
+}
+
+/// Failure of downcasting [`Unsizeable`]/[`UnsizeableMut`] to particular type
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("Can't downcast `{actual}` to `{expected}`")]
+pub struct DowncastError {
+    /// Name of requested type, as given by [`std::any::type_name`]
+    pub expected: &'static str,
+    /// Name of actual type, as given by it's [`Type::ident`]
+    pub actual: &'static str,
+}
+
+impl DowncastError {
+    fn new<T>(actual: &dyn TypeInfoDynamic) -> Self {
+        #[cfg(feature = "metrics")]
+        metrics::record(actual.get_dynamic(), metrics::Metric::DowncastFailure);
+        Self {
+            expected: std::any::type_name::<T>(),
+            actual: actual.get_dynamic().ident,
+        }
+    }
+}
+
+/// Name, which doesn't belong to any unit variant of enum
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("Enum `{ty}` has no unit variant `{name}`, expected one of: {}", .expected.join(", "))]
+pub struct UnknownVariantError {
+    #[allow(missing_docs)]
+    pub ty: &'static str,
+    /// Name, which was looked up
+    pub name: String,
+    /// Names of unit variants of enum
+    pub expected: Vec<&'static str>,
+}
+
+impl UnknownVariantError {
+    #[doc(hidden)]
+    pub fn new(ty: &'static Type, name: &str) -> Self {
+        let expected = match &ty.data {
+            Data::Enum(variants) => variants.unit_names().collect(),
+            _ => Vec::new(),
+        };
+        Self {
+            ty: ty.ident,
+            name: name.to_string(),
+            expected,
+        }
+    }
 }
 
 /// Failure of type construction
@@ -119,12 +825,19 @@ pub enum RuntimeConstructError {
     Primitive,
 
     /// Invalid type was passed as argument to constructor
-    #[error("Invalid type at {index} was passed to runtime constructor")]
+    #[error(
+        "Argument {index} doesn't match field `{field}` of type `{expected}`{}",
+        .variant.map(|variant| format!(" in variant `{}`", variant)).unwrap_or_default()
+    )]
     UnexpectedType {
-        #[allow(missing_docs)]
+        /// Position of mismatching argument
         index: usize,
         /// Name of expected type
         expected: &'static str,
+        /// Declared field, which argument was meant for
+        field: FieldId,
+        /// Requested variant, if enum was constructed
+        variant: Option<&'static str>,
     },
 
     /// Related enum doesn't have requested variant
@@ -146,6 +859,63 @@ pub enum RuntimeConstructError {
     #[error("Not enough arguments were passed")]
     #[allow(missing_docs)]
     NotEnoughArgs,
+
+    #[error("Too many arguments were passed")]
+    #[allow(missing_docs)]
+    TooManyArgs,
+
+    /// Attempted to construct reference type
+    #[error("Can't construct reference type")]
+    Reference,
+
+    /// Constructor of wrapped value returned value of another type, than the wrapper holds
+    #[error("Constructor of wrapped value returned `{found}` instead of `{expected}`")]
+    ForeignValue {
+        /// Name of type, held by the wrapper
+        expected: &'static str,
+        /// Name of type, which was actually constructed
+        found: &'static str,
+    },
+
+    /// Called [`TypeInfoDynamic::construct_wrapper`] on type, which doesn't wrap other value
+    #[error("Type doesn't wrap other value")]
+    NotWrapper,
+
+    /// Named argument doesn't belong to any field, see [`named`]
+    #[error("Type has no field `{0}`")]
+    UnknownField(String),
+
+    /// Named arguments skip field, while following fields are given, see [`named`]
+    #[error("Field `{0}` is missing")]
+    MissingField(FieldId),
+
+    /// Named arguments contain the same field more than once, see [`named`]
+    #[error("Field `{0}` is given more than once")]
+    DuplicateField(FieldId),
+
+    /// Type (or variant) is `#[non_exhaustive]` and defined in another crate,
+    /// so it can't be built from it's fields
+    #[error("Can't construct non-exhaustive type of another crate")]
+    NonExhaustive,
+
+    /// Type is derived with `#[reflectix(static_only)]`, so it has no constructors
+    #[error("Type provides metadata only")]
+    StaticOnly,
+
+    /// Attempted to construct union
+    #[error("Can't construct union")]
+    Union,
+
+    /// Domain error of user code, such as constructor, given with `#[reflectix(constructor = "...")]`
+    #[error(transparent)]
+    Custom(Box<dyn std::error::Error + Send + Sync>),
+}
+
+impl RuntimeConstructError {
+    /// Wrap error of user code into [`RuntimeConstructError::Custom`]
+    pub fn custom(error: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Self::Custom(error.into())
+    }
 }
 
 /// Object-safe version of [`TypeInfo`]
@@ -160,16 +930,25 @@ pub trait TypeInfoDynamic: std::any::Any {
 
     /// Constructs this type if it is a struct
     ///
+    /// Constructed value is returned as [`UnsizeableOwned`], so it can be reflected on right away
+    ///
     /// Attempts to downcast passed arguments to type of fields.
     /// Multiple fields can be of same type, just make sure that order is preserved or you might get unexpected results
     ///
-    /// If called on enum type, [`RuntimeConstructError::NotStruct`] will be returned
+    /// If called on enum type, [`RuntimeConstructError::NotStruct`] will be returned.
+    /// Types derived with `#[reflectix(public_only)]` return [`RuntimeConstructError::PrivateFields`], if some of their fields are not public
+    ///
+    /// **Note**: Arguments must be passed in same order as fields are listed in [`Fields`] (which is definition order, unless overridden)
+    ///
+    /// Trailing arguments can be omitted for fields with `#[reflectix(default)]` or `#[reflectix(default = "expr")]`
     ///
-    /// **Note**: Arguments must be passed in same order as definition order of fields inside struct
+    /// Structs derived with `#[reflectix(constructor = "Self::new_checked")]` are built by calling given function
+    /// with fields in the same order, instead of struct literal. Function must return `Result<Self, E>`,
+    /// error of which is returned as [`RuntimeConstructError::Custom`]
     fn construct_struct(
         &self,
         args: Vec<Box<dyn Any>>,
-    ) -> Result<Box<dyn Any>, RuntimeConstructError>;
+    ) -> Result<UnsizeableOwned, RuntimeConstructError>;
 
     /// Constructs `Self` if it is enum
     ///
@@ -178,12 +957,12 @@ pub trait TypeInfoDynamic: std::any::Any {
     ///
     /// If variant is unit, no arguments will be required aside from `variant`
     ///
-    /// **Note**: Arguments must be passed in same order as definition order of fields inside of particular variant
+    /// **Note**: Arguments must be passed in same order as fields of particular variant are listed in [`Fields`]
     fn construct_enum(
         &self,
         variant: &'static str, // some sort of safety gate, because in fully reflective usage one wouldn't be able to construct &'static variant name
         args: Vec<Box<dyn Any>>,
-    ) -> Result<Box<dyn Any>, RuntimeConstructError>;
+    ) -> Result<UnsizeableOwned, RuntimeConstructError>;
 
     /// Borrow immutably field inside this type
     ///
@@ -196,68 +975,602 @@ pub trait TypeInfoDynamic: std::any::Any {
     ///
     /// Same as [`TypeInfo::field`], except that returned "reference" is mutable
     fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError>;
-}
-
-/// Static-type version of [`TypeInfoDynamic`]
-pub trait TypeInfo: TypeInfoDynamic + Sized {
-    #[allow(missing_docs)]
-    const INFO: &'static Type;
-}
-
-/// Immutable reference holder, returned by [`TypeInfoDynamic::field`] method
-///
-/// Can be downcasted to underlying type if underlying type is "nameable"
-pub struct Unsizeable<'a> {
-    ptr: *const (),
-    target_id: std::any::TypeId,
-    _lt: std::marker::PhantomData<&'a ()>,
-}
 
-impl<'a> Unsizeable<'a> {
-    #[doc(hidden)]
-    pub fn new(ptr: *const (), target_id: std::any::TypeId) -> Self {
-        Self {
-            ptr,
-            target_id,
-            _lt: std::marker::PhantomData,
-        }
+    /// Variant which value currently holds, if type is enum
+    ///
+    /// Returns [`Option::None`] for non-enum types and for variants excluded from metadata
+    fn variant(&self) -> Option<&'static Variant> {
+        None
     }
 
-    /// Attempts to downcast field to immutable reference of particular type
+    /// Borrow immutably field by it's position in [`Fields`]
     ///
-    /// You need to be able to name this type in compile-time to succesfully downcast
+    /// For enums, position refers to fields of active variant. Derived types match on position directly,
+    /// which is cheaper than comparing names in [`TypeInfoDynamic::field`]
+    fn field_at<'s>(&'s self, position: usize) -> Result<Unsizeable<'s>, FieldAccessError> {
+        let id = positional_field_id(self, position)?;
+        self.field(id)
+    }
+
+    /// Borrow mutably field by it's position in [`Fields`]
     ///
-    /// If `T` doesn't match actual type, [`Option::None`] will be returned
-    pub fn downcast_ref<T>(&self) -> Option<&'a T>
-    where
-        T: 'static,
-    {
-        if std::any::TypeId::of::<T>() != self.target_id {
-            return None;
-        }
+    /// Same as [`TypeInfoDynamic::field_at`], except that returned "reference" is mutable
+    fn field_at_mut<'s>(
+        &'s mut self,
+        position: usize,
+    ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        let id = positional_field_id(self, position)?;
+        self.field_mut(id)
+    }
 
-        unsafe {
-            let target_ptr = self.ptr as *const T;
+    /// View of this value as list, if it's type is [`Data::List`]
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        None
+    }
+
+    /// Same as [`TypeInfoDynamic::as_list`], except that returned view is mutable
+    fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+        None
+    }
+
+    /// View of this value as map, if it's type is [`Data::Map`]
+    fn as_map(&self) -> Option<&dyn ReflectMap> {
+        None
+    }
+
+    /// Same as [`TypeInfoDynamic::as_map`], except that returned view is mutable
+    fn as_map_mut(&mut self) -> Option<&mut dyn ReflectMap> {
+        None
+    }
+
+    /// Access to value behind interior mutability, such as `RefCell<T>` or `Mutex<T>`
+    fn as_shared(&self) -> Option<&dyn ReflectShared> {
+        None
+    }
+
+    /// Wrapped value, if this is a wrapper (such as `Box<T>` or `Arc<T>`), which shares metadata of wrapped type
+    ///
+    /// Primitive leaves are told apart by their concrete type, so code, which looks for them,
+    /// should look through wrappers first, see [`innermost`](#method.innermost)
+    fn pointee(&self) -> Option<&dyn TypeInfoDynamic> {
+        None
+    }
+
+    /// Same as [`TypeInfoDynamic::pointee`], except that returned value is mutable
+    ///
+    /// Value behind interior mutability (such as `Mutex<T>`) is returned too, since exclusive reference needs no guard.
+    /// Fails with the same error as [`TypeInfoDynamic::field_mut`] of the wrapper, if it doesn't allow
+    /// to mutate wrapped value (because it's shared, pinned or frozen), and with [`FieldAccessError::NotFound`]
+    /// if this value is not a wrapper
+    fn pointee_mut(&mut self) -> Result<UnsizeableMut<'_>, FieldAccessError> {
+        Err(FieldAccessError::NotFound)
+    }
+
+    /// Constructs this wrapper around `inner`, which must be of the type [`TypeInfoDynamic::pointee`] refers to
+    ///
+    /// Wrapped leaves (such as `Box<u32>`) have no constructor of their own, so copies of them are built
+    /// from copy of wrapped value. If this value is not a wrapper, [`RuntimeConstructError::NotWrapper`] will be returned
+    fn construct_wrapper(
+        &self,
+        _inner: Box<dyn Any>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::NotWrapper)
+    }
+}
+
+/// Operations on lists, which don't require to name type of elements
+///
+/// Obtained with [`TypeInfoDynamic::as_list`]
+pub trait ReflectList {
+    /// Number of elements
+    fn len(&self) -> usize;
+
+    #[allow(missing_docs)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Append element to the end of list
+    ///
+    /// If `value` is not of element type, [`FieldAccessError::UnmatchingType`] will be returned
+    fn push(&mut self, value: Box<dyn Any>) -> Result<(), FieldAccessError>;
+
+    /// Remove element at `index`, shifting following elements
+    ///
+    /// If `index` is out of bounds, [`FieldAccessError::NotFound`] will be returned
+    fn remove(&mut self, index: usize) -> Result<UnsizeableOwned, FieldAccessError>;
+}
+
+/// Operations on maps, which don't require to name types of keys and values
+///
+/// Keys are passed boxed. If key is not of map's key type, but is `String` or `&'static str`,
+/// it's parsed into primitive key type (so `"5"` can be used as key of `HashMap<u32, _>`)
+/// or into unit variant of enum key type with [`TypeInfo::from_unit_variant`].
+///
+/// Obtained with [`TypeInfoDynamic::as_map`]
+pub trait ReflectMap {
+    /// Number of entries
+    fn len(&self) -> usize;
+
+    #[allow(missing_docs)]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Borrow value of entry with `key`
+    ///
+    /// If there is no such entry, [`FieldAccessError::NotFound`] will be returned
+    fn get(&self, key: Box<dyn Any>) -> Result<Unsizeable<'_>, FieldAccessError>;
+
+    /// Insert entry, returning previous value with the same key, if there was any
+    ///
+    /// If key or value is not of map's type, [`FieldAccessError::UnmatchingType`] will be returned
+    fn insert(
+        &mut self,
+        key: Box<dyn Any>,
+        value: Box<dyn Any>,
+    ) -> Result<Option<UnsizeableOwned>, FieldAccessError>;
+
+    /// Remove entry with `key`, returning it's value
+    ///
+    /// If there is no such entry, [`FieldAccessError::NotFound`] will be returned
+    fn remove(&mut self, key: Box<dyn Any>) -> Result<UnsizeableOwned, FieldAccessError>;
+
+    /// All entries, in order of underlying map
+    fn iter(&self) -> Box<dyn Iterator<Item = (Unsizeable<'_>, Unsizeable<'_>)> + '_>;
+}
+
+/// Access to value behind interior mutability, which goes through `borrow()`/`lock()` of it's container
+///
+/// Such values share metadata with the value inside, but their fields can't be borrowed from shared reference
+/// without holding a guard, so [`TypeInfoDynamic::field`] fails with [`FieldAccessError::Shared`].
+/// Returned accessors hold the guard and dereference to the value inside.
+///
+/// Obtained with [`TypeInfoDynamic::as_shared`]
+pub trait ReflectShared {
+    /// Borrow value inside immutably, as `RefCell::borrow`, `Mutex::lock` or `RwLock::read` do
+    ///
+    /// Fails with [`FieldAccessError::Borrowed`] if `RefCell` is already mutably borrowed,
+    /// and with [`FieldAccessError::Poisoned`] if lock is poisoned. Locks are waited for
+    fn read(&self) -> Result<SharedRef<'_>, FieldAccessError>;
+
+    /// Borrow value inside mutably, as `RefCell::borrow_mut`, `Mutex::lock` or `RwLock::write` do
+    ///
+    /// Fails the same way as [`ReflectShared::read`]. Value inside `Cell` is only copied out on read,
+    /// so it can't be written through shared reference and [`FieldAccessError::ReadOnly`] is returned
+    fn write(&self) -> Result<SharedMut<'_>, FieldAccessError>;
+}
+
+// guard of interior mutability, erased down to the value it guards
+pub(crate) trait ErasedGuard {
+    fn get(&self) -> &dyn TypeInfoDynamic;
+}
+
+pub(crate) trait ErasedGuardMut: ErasedGuard {
+    fn get_mut(&mut self) -> &mut dyn TypeInfoDynamic;
+}
+
+/// Immutable accessor, returned by [`ReflectShared::read`]
+///
+/// Holds the guard until dropped
+pub struct SharedRef<'a> {
+    guard: Box<dyn ErasedGuard + 'a>,
+}
+
+impl<'a> SharedRef<'a> {
+    pub(crate) fn new(guard: impl ErasedGuard + 'a) -> Self {
+        Self {
+            guard: Box::new(guard),
+        }
+    }
+}
+
+impl std::ops::Deref for SharedRef<'_> {
+    type Target = dyn TypeInfoDynamic;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.get()
+    }
+}
+
+/// Mutable accessor, returned by [`ReflectShared::write`]
+///
+/// Holds the guard until dropped
+pub struct SharedMut<'a> {
+    guard: Box<dyn ErasedGuardMut + 'a>,
+}
+
+impl<'a> SharedMut<'a> {
+    pub(crate) fn new(guard: impl ErasedGuardMut + 'a) -> Self {
+        Self {
+            guard: Box::new(guard),
+        }
+    }
+}
+
+impl std::ops::Deref for SharedMut<'_> {
+    type Target = dyn TypeInfoDynamic;
+
+    fn deref(&self) -> &Self::Target {
+        self.guard.get()
+    }
+}
+
+impl std::ops::DerefMut for SharedMut<'_> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.guard.get_mut()
+    }
+}
+
+fn positional_field_id<T: TypeInfoDynamic + ?Sized>(
+    value: &T,
+    position: usize,
+) -> Result<FieldId, FieldAccessError> {
+    let fields = match &value.get_dynamic().data {
+        Data::Struct(fields) | Data::Tuple(fields) => fields,
+        Data::Enum(_) => &value.variant().ok_or(FieldAccessError::NotFound)?.fields,
+        _ => return Err(FieldAccessError::NotFound),
+    };
+
+    match fields {
+        Fields::Unit => Err(FieldAccessError::Unit),
+        fields => fields
+            .as_slice()
+            .get(position)
+            .map(|field| field.id.clone())
+            .ok_or(FieldAccessError::NotFound),
+    }
+}
+
+impl dyn TypeInfoDynamic {
+    /// Mutate field inside this type in-place with closure
+    ///
+    /// Safe, scoped alternative to [`TypeInfoDynamic::field_mut`]: mutable borrow of field ends when `f` returns,
+    /// so it can't outlive the call or be duplicated
+    ///
+    /// If `T` doesn't match actual type of the field, [`FieldAccessError::UnmatchingType`] will be returned
+    pub fn update_field<T: 'static>(
+        &mut self,
+        id: FieldId,
+        f: impl FnOnce(&mut T),
+    ) -> Result<(), FieldAccessError> {
+        let field = self.field_mut(id)?;
+        let field = field
+            .downcast_mut::<T>()
+            .ok_or(FieldAccessError::UnmatchingType)?;
+
+        f(field);
+        Ok(())
+    }
+
+    /// Replace value of field inside this type
+    ///
+    /// `value` must be of same type as the field, otherwise [`FieldAccessError::UnmatchingType`] will be returned.
+    /// The only coercion is done for optional fields: `Option<T>` field can be set both with `Option<T>` and bare `T`,
+    /// which is wrapped into `Some`. To reset optional field, use [`clear_field`](Self::clear_field)
+    pub fn set_field(&mut self, id: FieldId, value: Box<dyn Any>) -> Result<(), FieldAccessError> {
+        let field = self.field_mut(id)?;
+        let value = match field.as_dynamic().get_dynamic().data {
+            Data::Optional(_) if Any::type_id(&*value) != field.target_id => field
+                .as_dynamic()
+                .construct_enum("Some", vec![value])
+                .map_err(|_| FieldAccessError::UnmatchingType)?
+                .into_any(),
+            _ => value,
+        };
+        field.set(value)
+    }
+
+    /// Append element to list field
+    ///
+    /// If field is not a list, or `value` is not of it's element type, [`FieldAccessError::UnmatchingType`] will be returned
+    pub fn list_push(&mut self, id: FieldId, value: Box<dyn Any>) -> Result<(), FieldAccessError> {
+        self.field_mut(id)?
+            .into_dynamic()
+            .as_list_mut()
+            .ok_or(FieldAccessError::UnmatchingType)?
+            .push(value)
+    }
+
+    /// Remove element at `index` from list field
+    pub fn list_remove(
+        &mut self,
+        id: FieldId,
+        index: usize,
+    ) -> Result<UnsizeableOwned, FieldAccessError> {
+        self.field_mut(id)?
+            .into_dynamic()
+            .as_list_mut()
+            .ok_or(FieldAccessError::UnmatchingType)?
+            .remove(index)
+    }
+
+    /// Number of elements in list field
+    pub fn list_len(&self, id: FieldId) -> Result<usize, FieldAccessError> {
+        self.field(id)?
+            .as_dynamic()
+            .as_list()
+            .map(ReflectList::len)
+            .ok_or(FieldAccessError::UnmatchingType)
+    }
+
+    /// Value behind all wrappers, which share metadata with it (as `T` in `Box<Arc<T>>`), or this value itself
+    pub fn innermost(&self) -> &dyn TypeInfoDynamic {
+        let mut value = self;
+        while let Some(pointee) = value.pointee() {
+            value = pointee;
+        }
+        value
+    }
+
+    /// Whether this optional value is `Some`
+    ///
+    /// If type of value is not [`Data::Optional`], [`FieldAccessError::UnmatchingType`] will be returned
+    pub fn is_some(&self) -> Result<bool, FieldAccessError> {
+        self.as_some().map(|inner| inner.is_some())
+    }
+
+    /// Contained value of this optional value, `None` if it's empty
+    ///
+    /// If type of value is not [`Data::Optional`], [`FieldAccessError::UnmatchingType`] will be returned
+    pub fn as_some(&self) -> Result<Option<Unsizeable<'_>>, FieldAccessError> {
+        let Data::Optional(_) = self.get_dynamic().data else {
+            return Err(FieldAccessError::UnmatchingType);
+        };
+        Ok(self.field(FieldId::Index(0)).ok())
+    }
+
+    /// Same as [`as_some`](Self::as_some), except that returned "reference" is mutable
+    pub fn as_some_mut(&mut self) -> Result<Option<UnsizeableMut<'_>>, FieldAccessError> {
+        let Data::Optional(_) = self.get_dynamic().data else {
+            return Err(FieldAccessError::UnmatchingType);
+        };
+        Ok(self.field_mut(FieldId::Index(0)).ok())
+    }
+
+    /// Insert entry into map field, returning previous value with the same key, if there was any
+    ///
+    /// See [`ReflectMap`] for accepted keys
+    pub fn map_insert(
+        &mut self,
+        id: FieldId,
+        key: Box<dyn Any>,
+        value: Box<dyn Any>,
+    ) -> Result<Option<UnsizeableOwned>, FieldAccessError> {
+        self.field_mut(id)?
+            .into_dynamic()
+            .as_map_mut()
+            .ok_or(FieldAccessError::UnmatchingType)?
+            .insert(key, value)
+    }
+
+    /// Remove entry with `key` from map field, returning it's value
+    pub fn map_remove(
+        &mut self,
+        id: FieldId,
+        key: Box<dyn Any>,
+    ) -> Result<UnsizeableOwned, FieldAccessError> {
+        self.field_mut(id)?
+            .into_dynamic()
+            .as_map_mut()
+            .ok_or(FieldAccessError::UnmatchingType)?
+            .remove(key)
+    }
+
+    /// Borrow value of entry with `key` in map field
+    pub fn map_get(
+        &self,
+        id: FieldId,
+        key: Box<dyn Any>,
+    ) -> Result<Unsizeable<'_>, FieldAccessError> {
+        let map = self.field(id)?.as_dynamic();
+        map.as_map()
+            .ok_or(FieldAccessError::UnmatchingType)?
+            .get(key)
+    }
+
+    /// Reset optional field to `None`
+    ///
+    /// If field is not optional, [`FieldAccessError::UnmatchingType`] will be returned
+    pub fn clear_field(&mut self, id: FieldId) -> Result<(), FieldAccessError> {
+        let field = self.field_mut(id)?;
+        if !matches!(field.as_dynamic().get_dynamic().data, Data::Optional(_)) {
+            return Err(FieldAccessError::UnmatchingType);
+        }
+
+        let none = field
+            .as_dynamic()
+            .construct_enum("None", Vec::new())
+            .map_err(|_| FieldAccessError::UnmatchingType)?;
+        field.set(none.into_any())
+    }
+
+    /// Discriminant of active variant, `None` if value is not an enum
+    pub fn discriminator(&self) -> Option<i128> {
+        self.variant().map(|variant| variant.discriminator)
+    }
+
+    /// Sole field of active variant, if it is single-field tuple variant (such as `Message::Text(String)`)
+    ///
+    /// Allows to grab payload of message-like enums without knowing variant name
+    pub fn payload(&self) -> Option<Unsizeable<'_>> {
+        match self.variant()?.fields {
+            Fields::Indexed([_]) => self.field_at(0).ok(),
+            _ => None,
+        }
+    }
+
+    /// Same as [`payload`](Self::payload), but returned "reference" is mutable
+    pub fn payload_mut(&mut self) -> Option<UnsizeableMut<'_>> {
+        match self.variant()?.fields {
+            Fields::Indexed([_]) => self.field_at_mut(0).ok(),
+            _ => None,
+        }
+    }
+
+    /// Borrow field, which is found by `name` according to `matching`
+    ///
+    /// For enums, only fields of active variant are searched
+    pub fn field_by_name(
+        &self,
+        name: &str,
+        matching: NameMatching,
+    ) -> Result<Unsizeable<'_>, FieldAccessError> {
+        let field = named_field_id(self, name, matching).and_then(|id| self.field(id));
+        #[cfg(feature = "metrics")]
+        let field = metrics::record_lookup(self.get_dynamic(), field);
+        field
+    }
+
+    /// Same as [`field_by_name`](Self::field_by_name), but returned "reference" is mutable
+    pub fn field_by_name_mut(
+        &mut self,
+        name: &str,
+        matching: NameMatching,
+    ) -> Result<UnsizeableMut<'_>, FieldAccessError> {
+        #[cfg(feature = "metrics")]
+        let ty = self.get_dynamic();
+        let field = named_field_id(self, name, matching).and_then(|id| self.field_mut(id));
+        #[cfg(feature = "metrics")]
+        let field = metrics::record_lookup(ty, field);
+        field
+    }
+}
+
+fn named_field_id(
+    value: &dyn TypeInfoDynamic,
+    name: &str,
+    matching: NameMatching,
+) -> Result<FieldId, FieldAccessError> {
+    let fields = match (value.variant(), &value.get_dynamic().data) {
+        (Some(variant), _) => variant.fields.as_slice(),
+        (None, Data::Struct(fields) | Data::Tuple(fields)) => fields.as_slice(),
+        (None, _) => &[],
+    };
+    fields
+        .iter()
+        .find(|field| field.id.matches_with(name, matching))
+        .map(|field| field.id.clone())
+        .ok_or(FieldAccessError::NotFound)
+}
+
+/// Static-type version of [`TypeInfoDynamic`]
+pub trait TypeInfo: TypeInfoDynamic + Sized {
+    #[allow(missing_docs)]
+    const INFO: &'static Type;
+
+    /// Value of unit variant, which is exposed under `name`
+    ///
+    /// Allows to turn names back into values without prototype (e.g. to parse enum keys of maps).
+    /// Returns [`Option::None`] for non-enum types and names of non-unit variants
+    fn from_unit_variant(name: &str) -> Option<Self> {
+        let _ = name;
+        None
+    }
+
+    /// Deep-copy `src` into this value, reusing it's allocations where possible
+    ///
+    /// `src` must be of the same type, see [`apply`] for details
+    fn clone_from_dynamic(&mut self, src: &dyn TypeInfoDynamic) -> Result<(), apply::ApplyError> {
+        apply::clone_into(UnsizeableMut::new(self), src)
+    }
+}
+
+/// Immutable reference holder, returned by [`TypeInfoDynamic::field`] method
+///
+/// Can be downcasted to underlying type if underlying type is "nameable"
+pub struct Unsizeable<'a> {
+    ptr: *const (),
+    target_id: std::any::TypeId,
+    dynamic: &'a dyn TypeInfoDynamic,
+}
+
+impl<'a> Unsizeable<'a> {
+    #[doc(hidden)]
+    pub fn new<T: TypeInfoDynamic>(value: &'a T) -> Self {
+        Self {
+            ptr: (value as *const T) as *const (),
+            target_id: std::any::TypeId::of::<T>(),
+            dynamic: value,
+        }
+    }
+
+    /// Attempts to downcast field to immutable reference of particular type
+    ///
+    /// You need to be able to name this type in compile-time to succesfully downcast
+    ///
+    /// If `T` doesn't match actual type, [`Option::None`] will be returned
+    pub fn downcast_ref<T>(&self) -> Option<&'a T>
+    where
+        T: 'static,
+    {
+        if std::any::TypeId::of::<T>() != self.target_id {
+            return None;
+        }
+
+        unsafe {
+            let target_ptr = self.ptr as *const T;
             target_ptr.as_ref()
         }
     }
+
+    /// Same as [`Unsizeable::downcast_ref`], but reports names of both types on failure
+    pub fn try_downcast_ref<T>(&self) -> Result<&'a T, DowncastError>
+    where
+        T: 'static,
+    {
+        self.downcast_ref()
+            .ok_or_else(|| DowncastError::new::<T>(self.dynamic))
+    }
+
+    /// Erased view of the field
+    ///
+    /// Unlike [`Unsizeable::downcast_ref`], doesn't require to name type of the field,
+    /// which allows to reflect on nested fields
+    pub fn as_dynamic(&self) -> &'a dyn TypeInfoDynamic {
+        self.dynamic
+    }
+
+    /// Projects to nested field of this field, keeping the same lifetime
+    ///
+    /// Allows chained projection without intermediate downcasts to concrete types
+    pub fn field(&self, id: FieldId) -> Result<Unsizeable<'a>, FieldAccessError> {
+        let field = self.dynamic.field(id);
+        #[cfg(feature = "metrics")]
+        let field = metrics::record_lookup(self.dynamic.get_dynamic(), field);
+        field
+    }
 }
 
 /// Mutable reference holder, returned by [`TypeInfoDynamic::field_mut`] method
 ///
 /// Can be downcasted to underlying type if underlying type is "nameable"
-
 pub struct UnsizeableMut<'a> {
-    ptr: *mut (),
+    ptr: *mut dyn TypeInfoDynamic,
     target_id: std::any::TypeId,
-    _lt: std::marker::PhantomData<&'a ()>,
+    assign: unsafe fn(*mut (), Box<dyn Any>) -> Result<(), FieldAccessError>,
+    _lt: std::marker::PhantomData<&'a mut ()>,
 }
 impl<'a> UnsizeableMut<'a> {
     #[doc(hidden)]
-    pub fn new(ptr: *mut (), target_id: std::any::TypeId) -> Self {
+    pub fn new<T: TypeInfoDynamic>(value: &'a mut T) -> Self {
+        // SAFETY: `ptr` must point to valid `T`, which holds because this instantiation
+        // is stored only alongside pointer to `T`
+        unsafe fn assign_erased<T: 'static>(
+            ptr: *mut (),
+            value: Box<dyn Any>,
+        ) -> Result<(), FieldAccessError> {
+            let value = value
+                .downcast::<T>()
+                .map_err(|_| FieldAccessError::UnmatchingType)?;
+            *(ptr as *mut T) = *value;
+            Ok(())
+        }
+
         Self {
-            ptr,
-            target_id,
+            ptr: value as *mut T as *mut dyn TypeInfoDynamic,
+            target_id: std::any::TypeId::of::<T>(),
+            assign: assign_erased::<T>,
             _lt: std::marker::PhantomData,
         }
     }
@@ -279,6 +1592,141 @@ impl<'a> UnsizeableMut<'a> {
             target_ptr.as_mut()
         }
     }
+
+    /// Same as [`UnsizeableMut::downcast_mut`], but reports names of both types on failure
+    pub fn try_downcast_mut<T>(&self) -> Result<&'a mut T, DowncastError>
+    where
+        T: 'static,
+    {
+        self.downcast_mut()
+            .ok_or_else(|| DowncastError::new::<T>(self.as_dynamic()))
+    }
+
+    /// Erased immutable view of the field
+    pub fn as_dynamic(&self) -> &dyn TypeInfoDynamic {
+        unsafe { &*self.ptr }
+    }
+
+    /// Converts holder into erased mutable reference
+    ///
+    /// Allows to reflect on nested fields without naming type of the field
+    pub fn into_dynamic(self) -> &'a mut dyn TypeInfoDynamic {
+        unsafe { &mut *self.ptr }
+    }
+
+    /// Projects to nested field of this field, keeping the same lifetime
+    pub fn field_mut(self, id: FieldId) -> Result<UnsizeableMut<'a>, FieldAccessError> {
+        #[cfg(feature = "metrics")]
+        let ty = self.as_dynamic().get_dynamic();
+        let field = self.into_dynamic().field_mut(id);
+        #[cfg(feature = "metrics")]
+        let field = metrics::record_lookup(ty, field);
+        field
+    }
+
+    /// Replaces value of the field with `value`, dropping previous one
+    ///
+    /// If `value` is not of field type, [`FieldAccessError::UnmatchingType`] will be returned
+    pub fn set(self, value: Box<dyn Any>) -> Result<(), FieldAccessError> {
+        unsafe { (self.assign)(self.ptr as *mut (), value) }
+    }
+
+    /// Deep-copy `src` into the field, see [`TypeInfo::clone_from_dynamic`]
+    pub fn clone_from_dynamic(self, src: &dyn TypeInfoDynamic) -> Result<(), apply::ApplyError> {
+        apply::clone_into(self, src)
+    }
+}
+
+/// Owned type-erased value
+///
+/// Unlike `Box<dyn Any>`, keeps type info of the value, so it still can be reflected on.
+/// Returned by constructors and can be passed back to them as argument with [`UnsizeableOwned::into_any`]
+pub struct UnsizeableOwned {
+    value: Box<dyn TypeInfoDynamic>,
+}
+
+impl UnsizeableOwned {
+    #[allow(missing_docs)]
+    pub fn new<T: TypeInfoDynamic>(value: T) -> Self {
+        Self {
+            value: Box::new(value),
+        }
+    }
+
+    /// Wraps already boxed value
+    pub fn from_boxed(value: Box<dyn TypeInfoDynamic>) -> Self {
+        Self { value }
+    }
+
+    /// [`Type`] information of the value
+    pub fn get_dynamic(&self) -> &'static Type {
+        self.value.get_dynamic()
+    }
+
+    /// [`std::any::TypeId`] of the value
+    pub fn type_id(&self) -> std::any::TypeId {
+        let value: &dyn Any = &*self.value;
+        value.type_id()
+    }
+
+    /// Whether value is of type `T`
+    pub fn is<T: 'static>(&self) -> bool {
+        self.type_id() == std::any::TypeId::of::<T>()
+    }
+
+    #[allow(missing_docs)]
+    pub fn as_dynamic(&self) -> &dyn TypeInfoDynamic {
+        &*self.value
+    }
+
+    #[allow(missing_docs)]
+    pub fn as_dynamic_mut(&mut self) -> &mut dyn TypeInfoDynamic {
+        &mut *self.value
+    }
+
+    /// Attempts to downcast value to reference of particular type
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        let value: &dyn Any = &*self.value;
+        value.downcast_ref()
+    }
+
+    /// Attempts to downcast value to particular type, just like [`Box::downcast`]
+    ///
+    /// On failure, value is returned back untouched
+    pub fn downcast<T: 'static>(self) -> Result<Box<T>, Self> {
+        match self.is::<T>() {
+            true => Ok(self.into_any().downcast().unwrap()),
+            false => {
+                #[cfg(feature = "metrics")]
+                metrics::record(self.get_dynamic(), metrics::Metric::DowncastFailure);
+                Err(self)
+            }
+        }
+    }
+
+    /// Erases type info, so value can be passed to constructors as argument
+    pub fn into_any(self) -> Box<dyn Any> {
+        self.value
+    }
+
+    #[allow(missing_docs)]
+    pub fn into_dynamic(self) -> Box<dyn TypeInfoDynamic> {
+        self.value
+    }
+}
+
+impl From<UnsizeableOwned> for Box<dyn Any> {
+    fn from(value: UnsizeableOwned) -> Self {
+        value.into_any()
+    }
+}
+
+impl std::fmt::Debug for UnsizeableOwned {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnsizeableOwned")
+            .field("ty", &self.get_dynamic().ident)
+            .finish_non_exhaustive()
+    }
 }
 
 use std::any::Any;
@@ -288,11 +1736,16 @@ macro_rules! impl_primitive {
     ($name:ty ) => {
         paste! {
             #[allow(unused)]
-            const  [<$name:upper _INFO>]: Type = Type {
+            static [<$name:upper _INFO>]: Type = Type {
               ident: std::stringify!($name),
               data: Data::Primitive,
-              // size: std::mem::size_of::<$name>(),
-              // alignment: std::mem::align_of::<$name>()
+              meta: &[],
+              docs: "",
+              non_exhaustive: false,
+              generics: &[],
+              type_id: std::any::TypeId::of::<$name>(),
+              size: std::mem::size_of::<$name>(),
+              align: std::mem::align_of::<$name>(),
             };
 
             #[automatically_derived]
@@ -301,7 +1754,7 @@ macro_rules! impl_primitive {
                     &[<$name:upper _INFO>]
 
                 }
-                fn construct_struct(&self, _args: Vec<Box<dyn Any>>) -> Result<Box<dyn Any>, RuntimeConstructError> {
+                fn construct_struct(&self, _args: Vec<Box<dyn Any>>) -> Result<UnsizeableOwned, RuntimeConstructError> {
                      Err(RuntimeConstructError::Primitive)
                 }
 
@@ -309,7 +1762,7 @@ macro_rules! impl_primitive {
                     &self,
                     _variant: &'static str,
                     _args: Vec<Box<dyn Any>>,
-                ) -> Result<Box<dyn Any>, RuntimeConstructError> {
+                ) -> Result<UnsizeableOwned, RuntimeConstructError> {
                          Err(RuntimeConstructError::Primitive)
 
                 }
@@ -331,25 +1784,235 @@ macro_rules! impl_primitive {
     };
 }
 
-impl_primitive!(u8);
-impl_primitive!(u16);
-impl_primitive!(u32);
-impl_primitive!(u64);
-impl_primitive!(u128);
+macro_rules! impl_primitives {
+    ($($name:ty),*) => {
+        $(impl_primitive!($name);)*
+    };
+}
+
+for_each_primitive!(impl_primitives);
+
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+for_each_nonzero!(impl_primitives);
+
+use std::net::IpAddr;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
+for_each_opaque!(impl_primitives);
+
+/// Static references are transparent: they share metadata with referred type
+///
+/// Fields can be accessed only immutably, [`TypeInfoDynamic::field_mut`] returns [`FieldAccessError::ReadOnly`]
+impl<T: TypeInfo> TypeInfoDynamic for &'static T {
+    fn get_dynamic(&self) -> &'static Type {
+        T::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Reference)
+    }
+
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Reference)
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        (**self).field(id)
+    }
+
+    fn field_mut<'s>(&'s mut self, _id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        Err(FieldAccessError::ReadOnly)
+    }
+
+    fn variant(&self) -> Option<&'static Variant> {
+        (**self).variant()
+    }
+
+    fn field_at<'s>(&'s self, position: usize) -> Result<Unsizeable<'s>, FieldAccessError> {
+        (**self).field_at(position)
+    }
+
+    fn field_at_mut<'s>(
+        &'s mut self,
+        _position: usize,
+    ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        Err(FieldAccessError::ReadOnly)
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        (**self).as_list()
+    }
+
+    fn as_map(&self) -> Option<&dyn ReflectMap> {
+        (**self).as_map()
+    }
+
+    fn as_shared(&self) -> Option<&dyn ReflectShared> {
+        (**self).as_shared()
+    }
+
+    fn pointee(&self) -> Option<&dyn TypeInfoDynamic> {
+        Some(&**self)
+    }
+
+    fn pointee_mut(&mut self) -> Result<UnsizeableMut<'_>, FieldAccessError> {
+        Err(FieldAccessError::ReadOnly)
+    }
+
+    fn construct_wrapper(
+        &self,
+        _inner: Box<dyn Any>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Reference)
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for &'static T {
+    const INFO: &'static Type = T::INFO;
+}
+
+static STR_INFO: Type = Type {
+    ident: "str",
+    data: Data::Primitive,
+    meta: &[],
+    docs: "",
+    non_exhaustive: false,
+    generics: &[],
+    type_id: std::any::TypeId::of::<&'static str>(),
+    size: std::mem::size_of::<&'static str>(),
+    align: std::mem::align_of::<&'static str>(),
+};
 
-impl_primitive!(i8);
-impl_primitive!(i16);
-impl_primitive!(i32);
-impl_primitive!(i64);
-impl_primitive!(i128);
+/// String literals are primitives, just as `String`, but they can only be read, since there is no way
+/// to produce `'static` string out of parsed or constructed one
+#[automatically_derived]
+impl TypeInfoDynamic for &'static str {
+    fn get_dynamic(&self) -> &'static Type {
+        &STR_INFO
+    }
 
-impl_primitive!(usize);
-impl_primitive!(isize);
+    fn construct_struct(
+        &self,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Primitive)
+    }
 
-impl_primitive!(String);
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Primitive)
+    }
+
+    fn field<'s>(&'s self, _id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        Err(FieldAccessError::Unit)
+    }
 
-impl_primitive!(f32);
-impl_primitive!(f64);
+    fn field_mut<'s>(&'s mut self, _id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        Err(FieldAccessError::Unit)
+    }
+}
+
+#[automatically_derived]
+impl TypeInfo for &'static str {
+    const INFO: &'static Type = &STR_INFO;
+}
+
+/// Same as `&'static T`, but fields are accessible mutably
+impl<T: TypeInfo> TypeInfoDynamic for &'static mut T {
+    fn get_dynamic(&self) -> &'static Type {
+        T::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Reference)
+    }
+
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Reference)
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        (**self).field(id)
+    }
+
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        (**self).field_mut(id)
+    }
+
+    fn variant(&self) -> Option<&'static Variant> {
+        (**self).variant()
+    }
+
+    fn field_at<'s>(&'s self, position: usize) -> Result<Unsizeable<'s>, FieldAccessError> {
+        (**self).field_at(position)
+    }
+
+    fn field_at_mut<'s>(
+        &'s mut self,
+        position: usize,
+    ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        (**self).field_at_mut(position)
+    }
+
+    fn as_list(&self) -> Option<&dyn ReflectList> {
+        (**self).as_list()
+    }
+
+    fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+        (**self).as_list_mut()
+    }
+
+    fn as_map(&self) -> Option<&dyn ReflectMap> {
+        (**self).as_map()
+    }
+
+    fn as_map_mut(&mut self) -> Option<&mut dyn ReflectMap> {
+        (**self).as_map_mut()
+    }
+
+    fn as_shared(&self) -> Option<&dyn ReflectShared> {
+        (**self).as_shared()
+    }
+
+    fn pointee(&self) -> Option<&dyn TypeInfoDynamic> {
+        Some(&**self)
+    }
+
+    fn pointee_mut(&mut self) -> Result<UnsizeableMut<'_>, FieldAccessError> {
+        Ok(UnsizeableMut::new(&mut **self))
+    }
+
+    fn construct_wrapper(
+        &self,
+        _inner: Box<dyn Any>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::Reference)
+    }
+}
+
+impl<T: TypeInfo> TypeInfo for &'static mut T {
+    const INFO: &'static Type = T::INFO;
+}
 
 mod __object_safety_check {
     use super::TypeInfoDynamic;
@@ -0,0 +1,139 @@
+// Declarative implementation of reflection for types, which can't use derive
+
+/// Implement [`TypeInfo`](crate::TypeInfo) and [`TypeInfoDynamic`](crate::TypeInfoDynamic)
+/// for struct with named fields, listing fields by hand
+///
+/// Meant for types the derive can't be placed on, such as FFI structs or generated code.
+/// Listed fields must be accessible at the place of invocation and every field type must implement `TypeInfo`.
+/// Fields, which are not listed, are not reflected, so struct with such fields can't be constructed reflectively
+/// ([`TypeInfoDynamic::construct_struct`](crate::TypeInfoDynamic::construct_struct) fails with
+/// [`RuntimeConstructError::PrivateFields`](crate::RuntimeConstructError::PrivateFields)):
+///
+/// ```ignore
+/// reflectix::impl_type_info! {
+///     struct timeval {
+///         tv_sec: i64,
+///         tv_usec: i64,
+///     }
+/// }
+/// ```
+///
+/// Add `..` after fields to mark that some fields are left out.
+///
+/// Offsets of listed fields are captured as is, so declare layout of type with `#[repr(C)]`
+/// if it's going to be viewed over bytes, produced elsewhere (see [`view`](crate::view))
+#[macro_export]
+macro_rules! impl_type_info {
+    (struct $ty:ident { $($field:ident : $field_ty:ty),* $(,)? }) => {
+        $crate::impl_type_info!(@impl $ty, true, $($field: $field_ty),*);
+    };
+    (struct $ty:ident { $($field:ident : $field_ty:ty,)* .. }) => {
+        $crate::impl_type_info!(@impl $ty, false, $($field: $field_ty),*);
+    };
+    (@impl $ty:ident, $complete:tt, $($field:ident : $field_ty:ty),*) => {
+        impl $crate::TypeInfoDynamic for $ty {
+            fn get_dynamic(&self) -> &'static $crate::Type {
+                <Self as $crate::TypeInfo>::INFO
+            }
+
+            fn construct_struct(
+                &self,
+                args: Vec<Box<dyn ::std::any::Any>>,
+            ) -> Result<$crate::UnsizeableOwned, $crate::RuntimeConstructError> {
+                $crate::impl_type_info!(@construct $complete, $ty, args, $($field: $field_ty),*)
+            }
+
+            fn construct_enum(
+                &self,
+                _variant: &'static str,
+                _args: Vec<Box<dyn ::std::any::Any>>,
+            ) -> Result<$crate::UnsizeableOwned, $crate::RuntimeConstructError> {
+                Err($crate::RuntimeConstructError::NotEnum)
+            }
+
+            fn field<'s>(
+                &'s self,
+                id: $crate::FieldId,
+            ) -> Result<$crate::Unsizeable<'s>, $crate::FieldAccessError> {
+                match id {
+                    $($crate::FieldId::Named(name) if name == stringify!($field) => {
+                        Ok($crate::Unsizeable::new(&self.$field))
+                    })*
+                    _ => Err($crate::FieldAccessError::NotFound),
+                }
+            }
+
+            fn field_mut<'s>(
+                &'s mut self,
+                id: $crate::FieldId,
+            ) -> Result<$crate::UnsizeableMut<'s>, $crate::FieldAccessError> {
+                match id {
+                    $($crate::FieldId::Named(name) if name == stringify!($field) => {
+                        Ok($crate::UnsizeableMut::new(&mut self.$field))
+                    })*
+                    _ => Err($crate::FieldAccessError::NotFound),
+                }
+            }
+        }
+
+        impl $crate::TypeInfo for $ty {
+            const INFO: &'static $crate::Type = &$crate::Type {
+                ident: stringify!($ty),
+                data: $crate::Data::Struct($crate::impl_type_info!(@fields $ty, $($field: $field_ty),*)),
+                meta: &[],
+                docs: "",
+                non_exhaustive: false,
+                generics: &[],
+                type_id: ::std::any::TypeId::of::<$ty>(),
+                size: ::std::mem::size_of::<$ty>(),
+                align: ::std::mem::align_of::<$ty>(),
+            };
+        }
+    };
+    // struct literal can be written only if every field is listed
+    (@construct false, $ty:ident, $args:ident, $($field:ident : $field_ty:ty),*) => {{
+        let _ = $args;
+        Err($crate::RuntimeConstructError::PrivateFields)
+    }};
+    (@construct true, $ty:ident, $args:ident, $($field:ident : $field_ty:ty),*) => {{
+        let fields_count = [$(stringify!($field)),*].len();
+        if $args.len() < fields_count {
+            return Err($crate::RuntimeConstructError::NotEnoughArgs);
+        }
+        if $args.len() > fields_count {
+            return Err($crate::RuntimeConstructError::TooManyArgs);
+        }
+
+        #[allow(unused_mut, unused_variables)]
+        let mut args = $args.into_iter().enumerate();
+        Ok($crate::UnsizeableOwned::new($ty {$(
+            $field: {
+                let (index, arg) = args.next().unwrap();
+                *arg.downcast::<$field_ty>().map_err(|_| {
+                    $crate::RuntimeConstructError::UnexpectedType {
+                        index,
+                        expected: <$field_ty as $crate::TypeInfo>::INFO.ident,
+                        field: $crate::FieldId::Named(stringify!($field)),
+                        variant: None,
+                    }
+                })?
+            },
+        )*}))
+    }};
+    (@fields $ty:ident,) => {
+        $crate::Fields::Unit
+    };
+    (@fields $ty:ident, $($field:ident : $field_ty:ty),+) => {
+        $crate::Fields::Named(&[$($crate::Field {
+            id: $crate::FieldId::Named(stringify!($field)),
+            ty: $crate::TypeRef::of::<$field_ty>(),
+            type_id: ::std::any::TypeId::of::<$field_ty>(),
+            meta: &[],
+            docs: "",
+            vis: $crate::Visibility::Public,
+            ownership: $crate::Ownership::Owned,
+            offset: ::std::option::Option::Some(::std::mem::offset_of!($ty, $field)),
+            has_default: false,
+        }),+])
+    };
+}
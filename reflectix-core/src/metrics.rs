@@ -0,0 +1,138 @@
+//! Counting of reflective operations per type, to find misuse of dynamic paths
+//!
+//! Erased entry points report every operation to global [`MetricsSink`], installed with [`set_sink`]:
+//!
+//! - [`Metric::FieldLookup`] and [`Metric::LookupFailure`]: projections of [`Unsizeable::field`](crate::Unsizeable::field),
+//!   [`UnsizeableMut::field_mut`](crate::UnsizeableMut::field_mut) and lookups by name
+//!   (`field_by_name` and `field_by_name_mut` of `dyn TypeInfoDynamic`). Recorded against type of value, which is looked into
+//! - [`Metric::DowncastFailure`]: failed `try_downcast_ref`, `try_downcast_mut` and [`UnsizeableOwned::downcast`](crate::UnsizeableOwned::downcast).
+//!   Recorded against actual type of value. Plain `downcast_ref` and `downcast_mut` are commonly used to probe for type,
+//!   so their failures are not recorded
+//! - [`Metric::ConstructError`]: failed construction by [`Registration`](crate::registry::Registration)
+//!   and by [`named`](crate::named) constructors. Recorded against constructed type
+//!
+//! Methods of [`TypeInfoDynamic`](crate::TypeInfoDynamic), called on concrete values, are not instrumented.
+//! Without sink, reporting is a single read of uninstalled sink
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, RwLock};
+
+use crate::Type;
+
+/// Reflective operation, which is counted
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Metric {
+    /// Field was looked up
+    FieldLookup,
+    /// Field was looked up, but it's not found or not accessible
+    LookupFailure,
+    /// Value was requested as type, which it's not of
+    DowncastFailure,
+    /// Constructor returned error
+    ConstructError,
+}
+
+/// Receiver of reflective operations
+pub trait MetricsSink: Send + Sync {
+    /// Called on every operation, `ty` is type the operation was performed on
+    fn record(&self, ty: &'static Type, metric: Metric);
+}
+
+static SINK: RwLock<Option<Arc<dyn MetricsSink>>> = RwLock::new(None);
+
+/// Install global sink, replacing previous one
+pub fn set_sink(sink: Arc<dyn MetricsSink>) {
+    *SINK.write().unwrap_or_else(|error| error.into_inner()) = Some(sink);
+}
+
+/// Uninstall global sink, so operations are no longer reported
+pub fn clear_sink() {
+    *SINK.write().unwrap_or_else(|error| error.into_inner()) = None;
+}
+
+pub(crate) fn record(ty: &'static Type, metric: Metric) {
+    let sink = SINK.read().unwrap_or_else(|error| error.into_inner());
+    if let Some(sink) = &*sink {
+        sink.record(ty, metric);
+    }
+}
+
+// records lookup and it's failure
+pub(crate) fn record_lookup<T, E>(ty: &'static Type, result: Result<T, E>) -> Result<T, E> {
+    record(ty, Metric::FieldLookup);
+    record_err(ty, Metric::LookupFailure, result)
+}
+
+// records failure of `result` as `metric`
+pub(crate) fn record_err<T, E>(
+    ty: &'static Type,
+    metric: Metric,
+    result: Result<T, E>,
+) -> Result<T, E> {
+    if result.is_err() {
+        record(ty, metric);
+    }
+    result
+}
+
+/// Sink, which counts operations per type
+///
+/// Types are told apart by their [`Type::type_id`], so instantiations of generic type are counted separately
+#[derive(Default, Debug)]
+pub struct Counters {
+    counts: Mutex<HashMap<(TypeId, Metric), Count>>,
+}
+
+#[derive(Debug)]
+struct Count {
+    ident: &'static str,
+    value: u64,
+}
+
+impl Counters {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of times `metric` was recorded for `ty`
+    pub fn get(&self, ty: &Type, metric: Metric) -> u64 {
+        self.lock()
+            .get(&(ty.type_id, metric))
+            .map_or(0, |count| count.value)
+    }
+
+    /// All non-zero counts as (ident of type, metric, count), sorted by count in descending order
+    pub fn snapshot(&self) -> Vec<(&'static str, Metric, u64)> {
+        let mut snapshot = self
+            .lock()
+            .iter()
+            .map(|((_, metric), count)| (count.ident, *metric, count.value))
+            .collect::<Vec<_>>();
+        snapshot.sort_by_key(|&(_, _, count)| std::cmp::Reverse(count));
+        snapshot
+    }
+
+    /// Reset all counts to zero
+    pub fn reset(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<(TypeId, Metric), Count>> {
+        self.counts
+            .lock()
+            .unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+impl MetricsSink for Counters {
+    fn record(&self, ty: &'static Type, metric: Metric) {
+        self.lock()
+            .entry((ty.type_id, metric))
+            .or_insert(Count {
+                ident: ty.ident,
+                value: 0,
+            })
+            .value += 1;
+    }
+}
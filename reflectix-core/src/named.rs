@@ -0,0 +1,153 @@
+//! Construction from arguments, identified by field names instead of positions
+//!
+//! [`construct_struct_named`] and [`construct_enum_named`] arrange name-value pairs into positional
+//! arguments of [`TypeInfoDynamic::construct_struct`]/[`TypeInfoDynamic::construct_enum`]. Fields are named
+//! as in metadata, fields of tuple-like types are named by their index (`"0"`, `"1"`).
+//!
+//! Trailing fields may be omitted, in which case constructor fills them with their defaults
+//! (or fails with [`RuntimeConstructError::NotEnoughArgs`]). Omitted field, followed by given ones,
+//! is [`RuntimeConstructError::MissingField`]. Field, given more than once, is [`RuntimeConstructError::DuplicateField`]
+//! rather than silently overwritten, since it's always a bug of caller.
+//!
+//! Names, which don't belong to any field, are handled according to [`UnknownFields`]. Collected names are
+//! returned in [`Constructed::extras`], so data written by newer version of type can be passed through losslessly
+use std::any::Any;
+
+use crate::{Data, Fields, RuntimeConstructError, TypeInfoDynamic, UnsizeableOwned};
+
+/// Policy of handling arguments, which don't belong to any field
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum UnknownFields {
+    /// Fail with [`RuntimeConstructError::UnknownField`]
+    #[default]
+    Error,
+    /// Drop unknown arguments
+    Ignore,
+    /// Keep unknown arguments in [`Constructed::extras`]
+    Collect,
+}
+
+/// Value, constructed from named arguments
+#[derive(Debug)]
+pub struct Constructed {
+    #[allow(missing_docs)]
+    pub value: UnsizeableOwned,
+    /// Arguments, which don't belong to any field, in order they were passed.
+    /// Empty unless [`UnknownFields::Collect`] is used
+    pub extras: Vec<(String, Box<dyn Any>)>,
+}
+
+/// Construct struct of the same type as `prototype` from named arguments
+pub fn construct_struct_named(
+    prototype: &dyn TypeInfoDynamic,
+    args: Vec<(&str, Box<dyn Any>)>,
+    unknown: UnknownFields,
+) -> Result<Constructed, RuntimeConstructError> {
+    let constructed = struct_named(prototype, args, unknown);
+    #[cfg(feature = "metrics")]
+    let constructed = crate::metrics::record_err(
+        prototype.get_dynamic(),
+        crate::metrics::Metric::ConstructError,
+        constructed,
+    );
+    constructed
+}
+
+fn struct_named(
+    prototype: &dyn TypeInfoDynamic,
+    args: Vec<(&str, Box<dyn Any>)>,
+    unknown: UnknownFields,
+) -> Result<Constructed, RuntimeConstructError> {
+    let (Data::Struct(fields) | Data::Tuple(fields)) = &prototype.get_dynamic().data else {
+        return Err(RuntimeConstructError::NotStruct);
+    };
+
+    let (args, extras) = arrange(fields, args, unknown)?;
+    Ok(Constructed {
+        value: prototype.construct_struct(args)?,
+        extras,
+    })
+}
+
+/// Construct `variant` of the same enum as `prototype` from named arguments
+pub fn construct_enum_named(
+    prototype: &dyn TypeInfoDynamic,
+    variant: &'static str,
+    args: Vec<(&str, Box<dyn Any>)>,
+    unknown: UnknownFields,
+) -> Result<Constructed, RuntimeConstructError> {
+    let constructed = enum_named(prototype, variant, args, unknown);
+    #[cfg(feature = "metrics")]
+    let constructed = crate::metrics::record_err(
+        prototype.get_dynamic(),
+        crate::metrics::Metric::ConstructError,
+        constructed,
+    );
+    constructed
+}
+
+fn enum_named(
+    prototype: &dyn TypeInfoDynamic,
+    variant: &'static str,
+    args: Vec<(&str, Box<dyn Any>)>,
+    unknown: UnknownFields,
+) -> Result<Constructed, RuntimeConstructError> {
+    let Data::Enum(variants) = &prototype.get_dynamic().data else {
+        return Err(RuntimeConstructError::NotEnum);
+    };
+    let fields = &variants
+        .find(variant)
+        .ok_or(RuntimeConstructError::InvalidVariant)?
+        .fields;
+
+    let (args, extras) = arrange(fields, args, unknown)?;
+    Ok(Constructed {
+        value: prototype.construct_enum(variant, args)?,
+        extras,
+    })
+}
+
+type Extras = Vec<(String, Box<dyn Any>)>;
+
+// positional arguments up to the last given field, and unknown arguments if they are collected
+fn arrange(
+    fields: &'static Fields,
+    args: Vec<(&str, Box<dyn Any>)>,
+    unknown: UnknownFields,
+) -> Result<(Vec<Box<dyn Any>>, Extras), RuntimeConstructError> {
+    let fields = fields.as_slice();
+    let mut slots: Vec<Option<Box<dyn Any>>> = fields.iter().map(|_| None).collect();
+    let mut extras = Vec::new();
+
+    for (name, value) in args {
+        match fields.iter().position(|field| field.id.matches(name)) {
+            Some(position) if slots[position].is_some() => {
+                return Err(RuntimeConstructError::DuplicateField(
+                    fields[position].id.clone(),
+                ))
+            }
+            Some(position) => slots[position] = Some(value),
+            None => match unknown {
+                UnknownFields::Error => {
+                    return Err(RuntimeConstructError::UnknownField(name.to_string()))
+                }
+                UnknownFields::Ignore => {}
+                UnknownFields::Collect => extras.push((name.to_string(), value)),
+            },
+        }
+    }
+
+    let given = slots
+        .iter()
+        .rposition(Option::is_some)
+        .map_or(0, |last| last + 1);
+    let args = slots
+        .into_iter()
+        .take(given)
+        .zip(fields)
+        .map(|(slot, field)| {
+            slot.ok_or_else(|| RuntimeConstructError::MissingField(field.id.clone()))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok((args, extras))
+}
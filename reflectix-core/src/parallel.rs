@@ -0,0 +1,69 @@
+//! Parallel versions of bulk helpers
+//!
+//! Reflective work on every value is independent of others, so batches of values are split between
+//! threads of global [rayon](::rayon) pool. Results are returned in order of input,
+//! so they are the same as of calling sequential helper on every value in a loop.
+//!
+//! Values must be [`Sync`] to be inspected from several threads, and [`Send`] to be copied into
+use rayon::prelude::*;
+
+use crate::apply::ApplyError;
+use crate::diff::{diff, Difference};
+use crate::hash::{canonical_bytes, content_hash};
+use crate::size::deep_size_of;
+use crate::{FieldAccessError, TypeInfo, TypeInfoDynamic};
+
+/// [`diff`] every pair of values at the same position
+///
+/// Pairs beyond length of shorter slice are not compared
+pub fn diff_pairs<L, R>(left: &[L], right: &[R]) -> Vec<Vec<Difference>>
+where
+    L: TypeInfoDynamic + Sync,
+    R: TypeInfoDynamic + Sync,
+{
+    left.par_iter()
+        .zip(right)
+        .map(|(left, right)| diff(left, right))
+        .collect()
+}
+
+/// Copy every value of `src` into value of `dst` at the same position
+/// with [`TypeInfo::clone_from_dynamic`]
+///
+/// Stops at first failure, although other copies may have already been done by then.
+/// Values beyond length of shorter slice are left untouched
+pub fn clone_from_all<T, S>(dst: &mut [T], src: &[S]) -> Result<(), ApplyError>
+where
+    T: TypeInfo + Send,
+    S: TypeInfoDynamic + Sync,
+{
+    dst.par_iter_mut()
+        .zip(src)
+        .try_for_each(|(dst, src)| dst.clone_from_dynamic(src))
+}
+
+/// [`content_hash`] of every value
+///
+/// Fails if any of values can't be encoded
+pub fn content_hashes<T: TypeInfoDynamic + Sync>(
+    values: &[T],
+) -> Result<Vec<u64>, FieldAccessError> {
+    values.par_iter().map(|value| content_hash(value)).collect()
+}
+
+/// [`canonical_bytes`] of every value
+///
+/// Fails if any of values can't be encoded
+pub fn canonical_bytes_all<T: TypeInfoDynamic + Sync>(
+    values: &[T],
+) -> Result<Vec<Vec<u8>>, FieldAccessError> {
+    values
+        .par_iter()
+        .map(|value| canonical_bytes(value))
+        .collect()
+}
+
+/// Total [`deep_size_of`] of all values
+pub fn deep_size_of_all<T: TypeInfoDynamic + Sync>(values: &[T]) -> usize {
+    values.par_iter().map(|value| deep_size_of(value)).sum()
+}
@@ -0,0 +1,192 @@
+//! Precomputed access plans
+//!
+//! Codecs usually visit every value of the same type in the same way: walk fields in order,
+//! descend into nested structs, and read primitive leaves. [`Plan`] does that walk over metadata once,
+//! flattening nested structs into ordered list of [`Step`]s. Each step knows positions of fields leading to it
+//! (accessed with [`TypeInfoDynamic::field_at`]) and, for primitive leaves, holds reader which turns field into [`Leaf`]
+//! without trying every primitive type in turn.
+//!
+//! Fields, which can't be planned ahead (enums, lists, maps and so on), are still listed as steps
+//! with [`LeafKind::Dynamic`], so it's up to codec to handle them by walking value itself
+use std::any::Any;
+
+use paste::paste;
+
+use crate::{Data, FieldAccessError, Fields, Type, TypeInfoDynamic, Unsizeable};
+
+/// Single field visited by [`Plan`]
+#[derive(Clone, Debug)]
+pub struct Step {
+    /// Dot-separated path of field, relative to planned type
+    pub path: String,
+    /// Metadata of field's type
+    pub ty: &'static Type,
+    #[allow(missing_docs)]
+    pub kind: LeafKind,
+    positions: Vec<usize>,
+    read: Option<Reader>,
+}
+
+impl Step {
+    /// Borrow field, which this step refers to, from value of planned type
+    pub fn access<'v>(
+        &self,
+        value: &'v dyn TypeInfoDynamic,
+    ) -> Result<Unsizeable<'v>, FieldAccessError> {
+        let (first, rest) = self
+            .positions
+            .split_first()
+            .ok_or(FieldAccessError::NotFound)?;
+
+        let mut field = value.field_at(*first)?;
+        for position in rest {
+            field = field.as_dynamic().field_at(*position)?;
+        }
+        Ok(field)
+    }
+
+    /// Read primitive leaf out of field, returned by [`Step::access`]
+    ///
+    /// Returns [`Option::None`] for [`LeafKind::Dynamic`] steps
+    pub fn leaf<'v>(&self, field: &Unsizeable<'v>) -> Option<Leaf<'v>> {
+        let value: &'v dyn Any = field.as_dynamic().innermost();
+        (self.read?)(value)
+    }
+}
+
+/// Ordered list of fields of particular struct type, computed once from it's metadata
+#[derive(Clone, Debug)]
+pub struct Plan {
+    ty: &'static Type,
+    steps: Vec<Step>,
+}
+
+impl Plan {
+    /// Compute plan for struct type
+    ///
+    /// Returns [`Option::None`] if `ty` is not a struct
+    pub fn new(ty: &'static Type) -> Option<Self> {
+        let Data::Struct(fields) = &ty.data else {
+            return None;
+        };
+
+        let mut steps = Vec::new();
+        collect_steps(fields, &mut Vec::new(), "", &mut steps);
+        Some(Self { ty, steps })
+    }
+
+    /// Planned type
+    pub fn ty(&self) -> &'static Type {
+        self.ty
+    }
+
+    /// Steps in order of fields, with nested structs flattened
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Visit every step of the plan on `value`
+    ///
+    /// If `value` is not of planned type, [`FieldAccessError::UnmatchingType`] will be returned
+    pub fn visit<'v>(
+        &self,
+        value: &'v dyn TypeInfoDynamic,
+        mut visitor: impl FnMut(&Step, Unsizeable<'v>),
+    ) -> Result<(), FieldAccessError> {
+        let ty = value.get_dynamic();
+        if !std::ptr::eq(ty, self.ty) && ty != self.ty {
+            return Err(FieldAccessError::UnmatchingType);
+        }
+
+        for step in &self.steps {
+            visitor(step, step.access(value)?);
+        }
+        Ok(())
+    }
+}
+
+fn collect_steps(
+    fields: &'static Fields,
+    positions: &mut Vec<usize>,
+    path: &str,
+    steps: &mut Vec<Step>,
+) {
+    for (position, field) in fields.as_slice().iter().enumerate() {
+        let path = match path {
+            "" => field.id.to_string(),
+            path => format!("{}.{}", path, field.id),
+        };
+        positions.push(position);
+
+        match &field.ty.get().data {
+            Data::Struct(nested @ (Fields::Named(_) | Fields::Indexed(_))) => {
+                collect_steps(nested, positions, &path, steps)
+            }
+            _ => {
+                let (kind, read) = match reader(field.ty.get()) {
+                    Some((kind, read)) => (kind, Some(read)),
+                    None => (LeafKind::Dynamic, None),
+                };
+                steps.push(Step {
+                    path,
+                    ty: field.ty.get(),
+                    kind,
+                    positions: positions.clone(),
+                    read,
+                });
+            }
+        }
+
+        positions.pop();
+    }
+}
+
+macro_rules! plan_leaves {
+    ($($ty:ident),*) => {
+        paste! {
+            /// Borrowed primitive value, read by [`Step::leaf`]
+            #[derive(Clone, Copy, PartialEq, Debug)]
+            #[allow(missing_docs)]
+            pub enum Leaf<'a> {
+                $([<$ty:camel>](&'a $ty),)*
+            }
+
+            /// Kind of value at [`Step`]
+            #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+            #[allow(missing_docs)]
+            pub enum LeafKind {
+                $([<$ty:camel>],)*
+                /// Value can't be planned ahead and must be walked by hand
+                Dynamic,
+            }
+
+            impl Leaf<'_> {
+                #[allow(missing_docs)]
+                pub fn kind(&self) -> LeafKind {
+                    match self {
+                        $(Self::[<$ty:camel>](_) => LeafKind::[<$ty:camel>],)*
+                    }
+                }
+            }
+
+            $(
+                fn [<read_ $ty:snake>](value: &dyn Any) -> Option<Leaf<'_>> {
+                    value.downcast_ref::<$ty>().map(Leaf::[<$ty:camel>])
+                }
+            )*
+
+            type Reader = fn(&dyn Any) -> Option<Leaf<'_>>;
+
+            fn reader(ty: &'static Type) -> Option<(LeafKind, Reader)> {
+                $(
+                    if ty == <$ty as crate::TypeInfo>::INFO {
+                        return Some((LeafKind::[<$ty:camel>], [<read_ $ty:snake>] as Reader));
+                    }
+                )*
+                None
+            }
+        }
+    };
+}
+
+for_each_primitive!(plan_leaves);
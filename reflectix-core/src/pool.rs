@@ -0,0 +1,87 @@
+//! Pooling of values, which are reset reflectively instead of being rebuilt
+//!
+//! [`Recycler`] keeps values, released back to it, and hands them out again in the state of template value.
+//! Reset is done with [`TypeInfo::clone_from_dynamic`], so strings, lists and maps of pooled values
+//! keep their allocations and only contents are copied from template (see [`crate::apply`]).
+//! Meant for game loops and other hot paths, where values of the same shape are created every frame
+use crate::apply::ApplyError;
+use crate::TypeInfo;
+
+/// Pool of values of `T`, which are reset to template value on release
+pub struct Recycler<T: TypeInfo> {
+    template: T,
+    pool: Vec<T>,
+}
+
+impl<T: TypeInfo> Recycler<T> {
+    /// Create empty pool, values of which start as copies of `template`
+    pub fn new(template: T) -> Self {
+        Self {
+            template,
+            pool: Vec::new(),
+        }
+    }
+
+    /// Value, pooled values are reset to
+    pub fn template(&self) -> &T {
+        &self.template
+    }
+
+    /// Take value out of pool, or construct new copy of template if pool is empty
+    pub fn acquire(&mut self) -> Result<T, ApplyError> {
+        match self.pool.pop() {
+            Some(value) => Ok(value),
+            None => self.fresh(),
+        }
+    }
+
+    /// Reset `value` to template and put it back to pool
+    ///
+    /// If value can't be reset, it's dropped instead of being pooled
+    pub fn release(&mut self, mut value: T) -> Result<(), ApplyError> {
+        value.clone_from_dynamic(&self.template)?;
+        self.pool.push(value);
+        Ok(())
+    }
+
+    /// Fill pool with copies of template, until it holds at least `len` values
+    pub fn prefill(&mut self, len: usize) -> Result<(), ApplyError> {
+        self.pool.reserve(len.saturating_sub(self.pool.len()));
+        while self.pool.len() < len {
+            let value = self.fresh()?;
+            self.pool.push(value);
+        }
+        Ok(())
+    }
+
+    /// Number of values, which are waiting in pool
+    pub fn len(&self) -> usize {
+        self.pool.len()
+    }
+
+    #[allow(missing_docs)]
+    pub fn is_empty(&self) -> bool {
+        self.pool.is_empty()
+    }
+
+    /// Drop all pooled values
+    pub fn clear(&mut self) {
+        self.pool.clear();
+    }
+
+    fn fresh(&self) -> Result<T, ApplyError> {
+        let value = crate::apply::dynamic_clone(&self.template)?;
+        Ok(*value
+            .downcast::<T>()
+            .expect("copy is of the same type as template"))
+    }
+}
+
+impl<T: TypeInfo + std::fmt::Debug> std::fmt::Debug for Recycler<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Recycler")
+            .field("template", &self.template)
+            .field("pooled", &self.pool.len())
+            .finish()
+    }
+}
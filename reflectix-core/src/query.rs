@@ -0,0 +1,308 @@
+//! Hydration of structs from flat key-value pairs, such as query strings and urlencoded forms
+//!
+//! Keys are dot-separated paths of fields: `name=Tom&address.city=Oslo` sets field `name` and field `city`
+//! of nested struct `address`. [`from_pairs`] builds value out of already split pairs,
+//! [`from_query_str`] splits and decodes `application/x-www-form-urlencoded` input first.
+//! [`to_query_string`] is the inverse.
+//!
+//! Values are parsed into primitive fields with [`FromStr`](std::str::FromStr), enum fields accept names of unit variants.
+//! Optional fields, which are absent, are `None`, other absent fields are filled with their defaults by constructor.
+//! Nested values are constructed with corresponding fields of prototype
+use std::any::Any;
+
+use crate::named::{construct_struct_named, UnknownFields};
+use crate::{
+    Data, FieldAccessError, FieldId, RuntimeConstructError, Type, TypeInfoDynamic, UnsizeableOwned,
+};
+
+/// Failure of hydration or encoding
+#[derive(thiserror::Error, Debug)]
+pub enum QueryError {
+    /// Type can't be represented by key-value pairs
+    #[error("Type `{0}` can't be represented in query")]
+    Unsupported(&'static str),
+    /// Value can't be parsed as type of it's field
+    #[error("Can't parse `{value}` of `{key}` as `{ty}`")]
+    Parse {
+        #[allow(missing_docs)]
+        key: String,
+        #[allow(missing_docs)]
+        value: String,
+        #[allow(missing_docs)]
+        ty: &'static str,
+    },
+    /// Key is given both as value and as parent of nested keys, or is given more than once
+    #[error("Conflicting values of `{0}`")]
+    Conflict(String),
+    /// Malformed percent-encoding
+    #[error("Invalid percent-encoding in `{0}`")]
+    Encoding(String),
+    #[allow(missing_docs)]
+    #[error(transparent)]
+    Access(#[from] FieldAccessError),
+    #[allow(missing_docs)]
+    #[error(transparent)]
+    Construct(#[from] RuntimeConstructError),
+}
+
+/// Construct struct of the same type as `prototype` from `application/x-www-form-urlencoded` input
+pub fn from_query_str(
+    input: &str,
+    prototype: &dyn TypeInfoDynamic,
+) -> Result<UnsizeableOwned, QueryError> {
+    let pairs = input
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            Ok((decode(key)?, decode(value)?))
+        })
+        .collect::<Result<Vec<_>, QueryError>>()?;
+
+    from_pairs(
+        pairs
+            .iter()
+            .map(|(key, value)| (key.as_str(), value.as_str())),
+        prototype,
+    )
+}
+
+/// Construct struct of the same type as `prototype` from pairs of dotted paths and values
+pub fn from_pairs<'p>(
+    pairs: impl IntoIterator<Item = (&'p str, &'p str)>,
+    prototype: &dyn TypeInfoDynamic,
+) -> Result<UnsizeableOwned, QueryError> {
+    let pairs = pairs
+        .into_iter()
+        .map(|(key, value)| (key.split('.').collect::<Vec<_>>(), value))
+        .collect::<Vec<_>>();
+    let nodes = pairs
+        .iter()
+        .map(|(path, value)| Node { path, value })
+        .collect::<Vec<_>>();
+    hydrate(prototype, "", &nodes)
+}
+
+/// Encode primitive and unit variant fields of `value` as query string with dotted paths of fields as keys
+pub fn to_query_string(value: &dyn TypeInfoDynamic) -> Result<String, QueryError> {
+    let mut pairs = Vec::new();
+    encode_fields(value, "", &mut pairs)?;
+    Ok(pairs
+        .iter()
+        .map(|(key, value)| format!("{}={}", encode(key), encode(value)))
+        .collect::<Vec<_>>()
+        .join("&"))
+}
+
+// pair, path of which is relative to currently hydrated struct
+struct Node<'n> {
+    path: &'n [&'n str],
+    value: &'n str,
+}
+
+fn hydrate(
+    prototype: &dyn TypeInfoDynamic,
+    prefix: &str,
+    nodes: &[Node<'_>],
+) -> Result<UnsizeableOwned, QueryError> {
+    let ty = prototype.get_dynamic();
+    let Data::Struct(fields) = &ty.data else {
+        return Err(QueryError::Unsupported(ty.ident));
+    };
+
+    let mut args: Vec<(String, Box<dyn Any>)> = Vec::new();
+    let mut seen: Vec<&str> = Vec::new();
+    for node in nodes {
+        let name = node.path[0];
+        if seen.contains(&name) {
+            continue;
+        }
+        seen.push(name);
+
+        let path = join(prefix, name);
+        let children = nodes
+            .iter()
+            .filter(|other| other.path[0] == name)
+            .collect::<Vec<_>>();
+        let field = fields
+            .as_slice()
+            .iter()
+            .find(|field| field.id.matches(name));
+        let Some(field) = field else {
+            // passed as is, so constructor reports it as unknown
+            args.push((name.to_string(), Box::new(node.value.to_string())));
+            continue;
+        };
+
+        let arg = match children.as_slice() {
+            [leaf] if leaf.path.len() == 1 => {
+                let field_value = prototype.field(field.id.clone())?;
+                parse(field_value.as_dynamic(), &path, leaf.value)?
+            }
+            _ if children.iter().any(|child| child.path.len() == 1) => {
+                return Err(QueryError::Conflict(path))
+            }
+            _ => {
+                let nested = children
+                    .iter()
+                    .map(|child| Node {
+                        path: &child.path[1..],
+                        value: child.value,
+                    })
+                    .collect::<Vec<_>>();
+                let field_value = prototype.field(field.id.clone())?;
+                hydrate(field_value.as_dynamic(), &path, &nested)?.into_any()
+            }
+        };
+        args.push((name.to_string(), arg));
+    }
+
+    // absent optional fields are `None` rather than missing
+    for field in fields.as_slice() {
+        let is_optional = matches!(field.ty.get().data, Data::Optional(_));
+        if is_optional && !seen.iter().any(|name| field.id.matches(name)) {
+            let field_value = prototype.field(field.id.clone())?;
+            let none = field_value
+                .as_dynamic()
+                .construct_enum("None", Vec::new())?;
+            args.push((field.id.to_string(), none.into_any()));
+        }
+    }
+
+    let (names, args): (Vec<_>, Vec<_>) = args.into_iter().unzip();
+    let args = names.iter().map(String::as_str).zip(args).collect();
+    Ok(construct_struct_named(prototype, args, UnknownFields::Error)?.value)
+}
+
+fn join(prefix: &str, name: &str) -> String {
+    match prefix {
+        "" => name.to_string(),
+        prefix => format!("{}.{}", prefix, name),
+    }
+}
+
+// `prototype` is current value of field, which tells type to parse into
+fn parse(
+    prototype: &dyn TypeInfoDynamic,
+    key: &str,
+    value: &str,
+) -> Result<Box<dyn Any>, QueryError> {
+    let ty = prototype.get_dynamic();
+    let error = |ty: &'static Type| QueryError::Parse {
+        key: key.to_string(),
+        value: value.to_string(),
+        ty: ty.ident,
+    };
+
+    match &ty.data {
+        Data::Primitive => crate::containers::parse_primitive(ty, value).ok_or_else(|| error(ty)),
+        Data::Enum(variants) => {
+            let variant = variants
+                .find(value)
+                .filter(|variant| variant.is_unit())
+                .ok_or_else(|| error(ty))?;
+            Ok(prototype
+                .construct_enum(variant.ident, Vec::new())?
+                .into_any())
+        }
+        Data::Optional(inner) => {
+            let inner = inner.get();
+            let inner = match inner.data {
+                Data::Primitive => {
+                    crate::containers::parse_primitive(inner, value).ok_or_else(|| error(inner))?
+                }
+                _ => return Err(QueryError::Unsupported(ty.ident)),
+            };
+            Ok(prototype.construct_enum("Some", vec![inner])?.into_any())
+        }
+        _ => Err(QueryError::Unsupported(ty.ident)),
+    }
+}
+
+fn encode_fields(
+    value: &dyn TypeInfoDynamic,
+    prefix: &str,
+    pairs: &mut Vec<(String, String)>,
+) -> Result<(), QueryError> {
+    if let Some(shared) = value.as_shared() {
+        return encode_fields(&*shared.read()?, prefix, pairs);
+    }
+    let ty = value.get_dynamic();
+    let Data::Struct(fields) = &ty.data else {
+        return Err(QueryError::Unsupported(ty.ident));
+    };
+
+    for field in fields.as_slice() {
+        let Ok(field_value) = value.field(field.id.clone()) else {
+            continue;
+        };
+        // values behind interior mutability are encoded while guard is held
+        let guard = match field_value.as_dynamic().as_shared() {
+            Some(shared) => Some(shared.read()?),
+            None => None,
+        };
+        let mut field_value = match &guard {
+            Some(inner) => &**inner,
+            None => field_value.as_dynamic(),
+        };
+        let key = join(prefix, &field.id.to_string());
+
+        if let Data::Optional(_) = field_value.get_dynamic().data {
+            match field_value.field(FieldId::Index(0)) {
+                Ok(inner) => field_value = inner.as_dynamic(),
+                Err(_) => continue,
+            }
+        }
+
+        if let Some(text) = crate::diff::display_primitive(field_value) {
+            pairs.push((key, text));
+            continue;
+        }
+        match &field_value.get_dynamic().data {
+            Data::Enum(_) => match field_value.variant() {
+                Some(variant) if variant.is_unit() => pairs.push((key, variant.ident.to_string())),
+                _ => return Err(QueryError::Unsupported(field_value.get_dynamic().ident)),
+            },
+            _ => encode_fields(field_value, &key, pairs)?,
+        }
+    }
+    Ok(())
+}
+
+// `+` stands for space, `%XX` for arbitrary byte
+fn decode(text: &str) -> Result<String, QueryError> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut input = text.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [input.next(), input.next()];
+                let [Some(high), Some(low)] = hex else {
+                    return Err(QueryError::Encoding(text.to_string()));
+                };
+                let hex = std::str::from_utf8(&[high, low])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| QueryError::Encoding(text.to_string()))?;
+                bytes.push(hex);
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| QueryError::Encoding(text.to_string()))
+}
+
+fn encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            byte => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,434 @@
+//! Registry of reflected types
+//!
+//! [`Registry`] maps type names to [`Registration`]s, each of which holds type's metadata and a prototype value.
+//! Types are named by [`Type::full_name`], so instantiations of generic type (`Wrapper<i32>` and `Wrapper<String>`)
+//! are registered separately. Wrappers, which share metadata of wrapped type (such as `Box<T>`), share it's name too,
+//! so `Box<i32>` can't be registered alongside `i32`.
+//! Prototype allows to construct values of the type knowing only it's name, because constructors
+//! of [`TypeInfoDynamic`] are called on existing value.
+//!
+//! Registry is a plain value, not a global: create as many as needed and pass them around
+//!
+//! Entries can be removed and replaced (e.g. when dynamic library with types is reloaded).
+//! Every insertion gets new generation, so [`TypeHandle`]s obtained before replacement
+//! stop resolving instead of pointing at metadata of unloaded type
+//!
+//! Types can be grouped with `#[reflectix(tag = "...")]` (repeatable), and subset of registry with particular tag
+//! is listed by [`Registry::types_with_tag`]
+//!
+//! Field lookups through [`Registry::field`] compare names according to registry's [`NameMatching`],
+//! so registry, which is fed by external system, can accept it's naming convention in one place
+//!
+//! Leaf types of foreign crates, which can't implement [`TypeInfo`], can be described with
+//! [`Registry::register_primitive`], so that values of such types are recognized by [`TypeId`]
+//!
+//! With `serde` feature registry can export schemas of it's types as [`crate::schema::MetadataBundle`]
+//! and import bundle of another process, to check local types against remote ones before exchanging messages
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use crate::schema::{MetadataBundle, SchemaMismatch, TypeSchema};
+use crate::{
+    Data, FieldAccessError, NameMatching, RuntimeConstructError, Type, TypeInfo, TypeInfoDynamic,
+    Unsizeable, UnsizeableMut, UnsizeableOwned,
+};
+
+/// Failure of type registration
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    /// Type is already registered
+    #[error("Type `{0}` is already registered")]
+    AlreadyRegistered(&'static str),
+
+    /// Different type with the same name is already registered
+    #[error("Another type named `{0}` is already registered")]
+    NameConflict(&'static str),
+}
+
+/// Registered type
+pub struct Registration {
+    ty: &'static Type,
+    type_id: TypeId,
+    prototype: Box<dyn TypeInfoDynamic>,
+    generation: u64,
+}
+
+impl Registration {
+    #[allow(missing_docs)]
+    pub fn ty(&self) -> &'static Type {
+        self.ty
+    }
+
+    #[allow(missing_docs)]
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+
+    /// Generation of registry, at which this registration was inserted
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Value type was registered with
+    pub fn prototype(&self) -> &dyn TypeInfoDynamic {
+        &*self.prototype
+    }
+
+    /// Construct new value of registered type, see [`TypeInfoDynamic::construct_struct`]
+    pub fn construct_struct(
+        &self,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let constructed = self.prototype.construct_struct(args);
+        #[cfg(feature = "metrics")]
+        let constructed = crate::metrics::record_err(
+            self.prototype.get_dynamic(),
+            crate::metrics::Metric::ConstructError,
+            constructed,
+        );
+        constructed
+    }
+
+    /// Construct new value of registered type, see [`TypeInfoDynamic::construct_enum`]
+    pub fn construct_enum(
+        &self,
+        variant: &'static str,
+        args: Vec<Box<dyn Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        let constructed = self.prototype.construct_enum(variant, args);
+        #[cfg(feature = "metrics")]
+        let constructed = crate::metrics::record_err(
+            self.prototype.get_dynamic(),
+            crate::metrics::Metric::ConstructError,
+            constructed,
+        );
+        constructed
+    }
+}
+
+impl std::fmt::Debug for Registration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Registration")
+            .field("ty", &self.ty.ident)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Reference to particular registration, which doesn't borrow registry
+///
+/// Owns name of type, so it stays valid to hold even after type's library is unloaded
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct TypeHandle {
+    name: String,
+    generation: u64,
+}
+
+impl TypeHandle {
+    /// Name of referred type
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    #[allow(missing_docs)]
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+/// Set of registered types, addressable by name and by [`TypeId`]
+#[derive(Default, Debug)]
+pub struct Registry {
+    registrations: HashMap<TypeId, Registration>,
+    names: HashMap<String, TypeId>,
+    primitives: HashMap<TypeId, &'static Type>,
+    name_matching: NameMatching,
+    next_generation: u64,
+    #[cfg(feature = "serde")]
+    remote: HashMap<String, TypeSchema>,
+}
+
+impl Registry {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register type of `prototype` under [full name](Type::full_name) from it's metadata
+    ///
+    /// Each type can be registered only once, and names of registered types must be unique
+    pub fn register<T: TypeInfo>(&mut self, prototype: T) -> Result<(), RegistryError> {
+        let registration = Registration {
+            ty: T::INFO,
+            type_id: TypeId::of::<T>(),
+            prototype: Box::new(prototype),
+            generation: 0,
+        };
+
+        self.check_conflict(&registration)?;
+        self.insert(registration);
+        Ok(())
+    }
+
+    /// Register type of `prototype`, replacing registration with the same name or type
+    ///
+    /// Returns replaced registration, handles to which won't resolve anymore
+    pub fn replace<T: TypeInfo>(&mut self, prototype: T) -> Option<Registration> {
+        let replaced = self
+            .unregister(&T::INFO.full_name())
+            .or_else(|| self.unregister_by_id(TypeId::of::<T>()));
+
+        self.insert(Registration {
+            ty: T::INFO,
+            type_id: TypeId::of::<T>(),
+            prototype: Box::new(prototype),
+            generation: 0,
+        });
+        replaced
+    }
+
+    /// Remove registration of type, [full name](Type::full_name) of which is `ident`
+    pub fn unregister(&mut self, ident: &str) -> Option<Registration> {
+        let type_id = self.names.remove(ident)?;
+        self.registrations.remove(&type_id)
+    }
+
+    /// Remove registration of type with given [`TypeId`]
+    pub fn unregister_by_id(&mut self, type_id: TypeId) -> Option<Registration> {
+        let registration = self.registrations.remove(&type_id)?;
+        self.names.remove(&registration.ty.full_name());
+        Some(registration)
+    }
+
+    /// Handle to current registration of type named `ident`
+    pub fn handle(&self, ident: &str) -> Option<TypeHandle> {
+        let registration = self.get(ident)?;
+        Some(TypeHandle {
+            name: registration.ty.full_name(),
+            generation: registration.generation,
+        })
+    }
+
+    /// Registration, which `handle` was obtained for
+    ///
+    /// Returns [`Option::None`] if type was unregistered or replaced since
+    pub fn resolve(&self, handle: &TypeHandle) -> Option<&Registration> {
+        self.get(&handle.name)
+            .filter(|registration| registration.generation == handle.generation)
+    }
+
+    /// Move all registrations of `other` into this registry
+    ///
+    /// Merge is atomic: if any type of `other` conflicts with this registry, nothing is moved
+    pub fn merge(&mut self, other: Registry) -> Result<(), RegistryError> {
+        for registration in other.iter() {
+            self.check_conflict(registration)?;
+        }
+        for (type_id, ty) in &other.primitives {
+            if self.primitives.contains_key(type_id) || self.registrations.contains_key(type_id) {
+                return Err(RegistryError::AlreadyRegistered(ty.ident));
+            }
+            if self.names.contains_key(ty.ident) || self.primitive_by_name(ty.ident).is_some() {
+                return Err(RegistryError::NameConflict(ty.ident));
+            }
+        }
+
+        for (_, registration) in other.registrations {
+            self.insert(registration);
+        }
+        self.primitives.extend(other.primitives);
+        Ok(())
+    }
+
+    /// All registrations, in unspecified order
+    pub fn iter(&self) -> impl Iterator<Item = &Registration> {
+        self.registrations.values()
+    }
+
+    /// Registrations of types, tagged with `tag`, in unspecified order
+    ///
+    /// Allows frameworks to discover only types, meant for them (e.g. components of ECS),
+    /// among everything registered
+    pub fn types_with_tag<'r>(&'r self, tag: &'r str) -> impl Iterator<Item = &'r Registration> {
+        self.iter()
+            .filter(move |registration| registration.ty.has_tag(tag))
+    }
+
+    /// Same as [`Registry::register`], with default value as prototype
+    pub fn register_default<T: TypeInfo + Default>(&mut self) -> Result<(), RegistryError> {
+        self.register(T::default())
+    }
+
+    /// Describe `T` as primitive named `name`
+    ///
+    /// Meant to be called at startup for leaf types, which user doesn't control and thus can't derive [`TypeInfo`] for.
+    /// Metadata is allocated once per registration and lives for the rest of the program
+    pub fn register_primitive<T: Any>(
+        &mut self,
+        name: &'static str,
+    ) -> Result<&'static Type, RegistryError> {
+        let type_id = TypeId::of::<T>();
+        if self.primitives.contains_key(&type_id) || self.registrations.contains_key(&type_id) {
+            return Err(RegistryError::AlreadyRegistered(name));
+        }
+        if self.names.contains_key(name) || self.primitive_by_name(name).is_some() {
+            return Err(RegistryError::NameConflict(name));
+        }
+
+        let ty: &'static Type = Box::leak(Box::new(Type {
+            ident: name,
+            data: Data::Primitive,
+            meta: &[],
+            docs: "",
+            non_exhaustive: false,
+            generics: &[],
+            type_id: std::any::TypeId::of::<T>(),
+            size: std::mem::size_of::<T>(),
+            align: std::mem::align_of::<T>(),
+        }));
+        self.primitives.insert(type_id, ty);
+        Ok(ty)
+    }
+
+    /// Metadata of primitive registered with [`Registry::register_primitive`]
+    pub fn primitive(&self, type_id: TypeId) -> Option<&'static Type> {
+        self.primitives.get(&type_id).copied()
+    }
+
+    /// Metadata of primitive named `ident`, registered with [`Registry::register_primitive`]
+    pub fn primitive_by_name(&self, ident: &str) -> Option<&'static Type> {
+        self.primitives
+            .values()
+            .copied()
+            .find(|ty| ty.ident == ident)
+    }
+
+    /// Registration of type, [full name](Type::full_name) of which is `ident`
+    pub fn get(&self, ident: &str) -> Option<&Registration> {
+        self.get_by_id(*self.names.get(ident)?)
+    }
+
+    #[allow(missing_docs)]
+    pub fn get_by_id(&self, type_id: TypeId) -> Option<&Registration> {
+        self.registrations.get(&type_id)
+    }
+
+    /// Set how [`Registry::field`] and [`Registry::field_mut`] compare names of fields
+    pub fn set_name_matching(&mut self, matching: NameMatching) {
+        self.name_matching = matching;
+    }
+
+    #[allow(missing_docs)]
+    pub fn name_matching(&self) -> NameMatching {
+        self.name_matching
+    }
+
+    /// Borrow field of `value` named `name`, comparing names according to registry's [`NameMatching`]
+    pub fn field<'v>(
+        &self,
+        value: &'v dyn TypeInfoDynamic,
+        name: &str,
+    ) -> Result<Unsizeable<'v>, FieldAccessError> {
+        value.field_by_name(name, self.name_matching)
+    }
+
+    /// Same as [`Registry::field`], but returned "reference" is mutable
+    pub fn field_mut<'v>(
+        &self,
+        value: &'v mut dyn TypeInfoDynamic,
+        name: &str,
+    ) -> Result<UnsizeableMut<'v>, FieldAccessError> {
+        value.field_by_name_mut(name, self.name_matching)
+    }
+
+    #[allow(missing_docs)]
+    pub fn contains<T: 'static>(&self) -> bool {
+        self.registrations.contains_key(&TypeId::of::<T>())
+    }
+
+    #[allow(missing_docs)]
+    pub fn len(&self) -> usize {
+        self.registrations.len()
+    }
+
+    #[allow(missing_docs)]
+    pub fn is_empty(&self) -> bool {
+        self.registrations.is_empty()
+    }
+
+    fn check_conflict(&self, registration: &Registration) -> Result<(), RegistryError> {
+        let ident = registration.ty.ident;
+        if self.registrations.contains_key(&registration.type_id)
+            || self.primitives.contains_key(&registration.type_id)
+        {
+            return Err(RegistryError::AlreadyRegistered(ident));
+        }
+        let name = registration.ty.full_name();
+        if self.names.contains_key(&name) || self.primitive_by_name(&name).is_some() {
+            return Err(RegistryError::NameConflict(ident));
+        }
+        Ok(())
+    }
+
+    fn insert(&mut self, mut registration: Registration) {
+        registration.generation = self.next_generation;
+        self.next_generation += 1;
+
+        self.names
+            .insert(registration.ty.full_name(), registration.type_id);
+        self.registrations
+            .insert(registration.type_id, registration);
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Registry {
+    /// Schemas of all registered types
+    pub fn export_bundle(&self) -> MetadataBundle {
+        let mut types: Vec<TypeSchema> = self
+            .iter()
+            .map(|registration| registration.ty.into())
+            .collect();
+        types.sort_by_key(TypeSchema::full_name);
+        MetadataBundle { types }
+    }
+
+    /// Learn schemas of remote registry, exported with [`Registry::export_bundle`]
+    ///
+    /// Schemas replace previously imported ones with the same [full name](TypeSchema::full_name)
+    pub fn import_bundle(&mut self, bundle: MetadataBundle) {
+        for schema in bundle.types {
+            self.remote.insert(schema.full_name(), schema);
+        }
+    }
+
+    /// Imported schema of type, [full name](TypeSchema::full_name) of which is `ident`
+    pub fn remote_schema(&self, ident: &str) -> Option<&TypeSchema> {
+        self.remote.get(ident)
+    }
+
+    /// Check, that remote side knows type `ty` under the same name and with the same layout
+    ///
+    /// To validate message before sending it, pass metadata of it's value: `value.get_dynamic()`
+    pub fn check_remote(&self, ty: &Type) -> Result<(), SchemaMismatch> {
+        let name = ty.full_name();
+        let remote = self
+            .remote
+            .get(&name)
+            .ok_or_else(|| SchemaMismatch::Unknown(name.clone()))?;
+
+        if *remote != TypeSchema::from(ty) {
+            return Err(SchemaMismatch::Differs(name));
+        }
+        Ok(())
+    }
+}
+
+impl<'r> IntoIterator for &'r Registry {
+    type Item = &'r Registration;
+    type IntoIter = std::collections::hash_map::Values<'r, TypeId, Registration>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.registrations.values()
+    }
+}
@@ -0,0 +1,127 @@
+//! Dispatch of enum messages by their variant
+//!
+//! [`Router`] is built for particular enum type (usually taken from [`crate::registry::Registry`])
+//! and maps names of it's variants to handlers. Dispatched message is never downcasted: handler receives
+//! fields of active variant as [`DynamicStruct`], so message bus can be driven by metadata alone
+use std::collections::HashMap;
+
+use crate::dynamic::DynamicStruct;
+use crate::{Data, Type, TypeInfoDynamic};
+
+/// Failure of building router or dispatching message
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum RouteError {
+    /// Router can be built only for enums
+    #[error("Type `{0}` is not an enum")]
+    NotEnum(&'static str),
+
+    /// Enum doesn't have variant with such name
+    #[error("Enum `{ty}` has no variant `{variant}`")]
+    UnknownVariant {
+        #[allow(missing_docs)]
+        ty: &'static str,
+        #[allow(missing_docs)]
+        variant: String,
+    },
+
+    /// Variant already has handler
+    #[error("Variant `{0}` is already routed")]
+    AlreadyRouted(&'static str),
+
+    /// Dispatched message is not of router's type
+    #[error("Message of type `{actual}` can't be dispatched by router of `{expected}`")]
+    UnmatchingType {
+        #[allow(missing_docs)]
+        expected: &'static str,
+        #[allow(missing_docs)]
+        actual: &'static str,
+    },
+
+    /// Active variant of message has no handler
+    #[error("Variant `{0}` has no handler")]
+    Unrouted(&'static str),
+}
+
+type Handler<'h> = Box<dyn FnMut(&DynamicStruct<'_>) + 'h>;
+
+/// Map from variants of enum type to their handlers
+pub struct Router<'h> {
+    ty: &'static Type,
+    handlers: HashMap<&'static str, Handler<'h>>,
+}
+
+impl<'h> Router<'h> {
+    /// Create router without handlers for enum type `ty`
+    pub fn new(ty: &'static Type) -> Result<Self, RouteError> {
+        if !matches!(ty.data, Data::Enum(_)) {
+            return Err(RouteError::NotEnum(ty.ident));
+        }
+        Ok(Self {
+            ty,
+            handlers: HashMap::new(),
+        })
+    }
+
+    /// Routed enum type
+    pub fn ty(&self) -> &'static Type {
+        self.ty
+    }
+
+    /// Set handler of variant named `variant`
+    pub fn on(
+        &mut self,
+        variant: &str,
+        handler: impl FnMut(&DynamicStruct<'_>) + 'h,
+    ) -> Result<(), RouteError> {
+        let Data::Enum(variants) = &self.ty.data else {
+            unreachable!("router is built for enums only")
+        };
+        let variant = variants
+            .find(variant)
+            .ok_or_else(|| RouteError::UnknownVariant {
+                ty: self.ty.ident,
+                variant: variant.to_string(),
+            })?;
+
+        if self.handlers.contains_key(variant.ident) {
+            return Err(RouteError::AlreadyRouted(variant.ident));
+        }
+        self.handlers.insert(variant.ident, Box::new(handler));
+        Ok(())
+    }
+
+    /// Whether variant named `variant` has handler
+    pub fn is_routed(&self, variant: &str) -> bool {
+        self.handlers.contains_key(variant)
+    }
+
+    /// Call handler of `message`'s active variant with it's fields
+    pub fn dispatch(&mut self, message: &dyn TypeInfoDynamic) -> Result<(), RouteError> {
+        let ty = message.get_dynamic();
+        let variant = match message.variant() {
+            Some(variant) if std::ptr::eq(ty, self.ty) || ty == self.ty => variant,
+            _ => {
+                return Err(RouteError::UnmatchingType {
+                    expected: self.ty.ident,
+                    actual: ty.ident,
+                })
+            }
+        };
+
+        let handler = self
+            .handlers
+            .get_mut(variant.ident)
+            .ok_or(RouteError::Unrouted(variant.ident))?;
+        handler(&DynamicStruct::from_value(message));
+        Ok(())
+    }
+}
+
+impl std::fmt::Debug for Router<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Router")
+            .field("ty", &self.ty.ident)
+            .field("routed", &self.handlers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
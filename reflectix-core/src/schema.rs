@@ -0,0 +1,375 @@
+//! Owned, serializable mirror of [`Type`] metadata
+//!
+//! Metadata itself is built from `&'static` references and can't be deserialized,
+//! so [`Type`], [`Fields`] and [`Variant`] are serialized through owned mirror structs,
+//! which can be freely deserialized, stored and compared
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    Data, DiscriminantRepr, Field, FieldId, Fields, GenericParamInfo, GenericParamKind, Type,
+    Variant, Variants,
+};
+
+/// Owned mirror of [`Type`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct TypeSchema {
+    #[allow(missing_docs)]
+    pub ident: String,
+    #[allow(missing_docs)]
+    pub data: DataSchema,
+    #[allow(missing_docs)]
+    pub meta: Vec<(String, String)>,
+    #[allow(missing_docs)]
+    pub docs: String,
+    /// Missing from bundles, exported before generics were recorded
+    #[serde(default)]
+    pub generics: Vec<GenericParamSchema>,
+}
+
+/// Owned mirror of [`GenericParamInfo`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct GenericParamSchema {
+    #[allow(missing_docs)]
+    pub name: String,
+    #[allow(missing_docs)]
+    pub kind: GenericParamKindSchema,
+}
+
+/// Owned mirror of [`GenericParamKind`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum GenericParamKindSchema {
+    #[allow(missing_docs)]
+    Lifetime,
+    #[allow(missing_docs)]
+    Type(Box<TypeSchema>),
+    #[allow(missing_docs)]
+    Const(i128),
+}
+
+/// Owned mirror of [`Data`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum DataSchema {
+    #[allow(missing_docs)]
+    Primitive,
+    #[allow(missing_docs)]
+    Struct(FieldsSchema),
+    #[allow(missing_docs)]
+    Tuple(FieldsSchema),
+    #[allow(missing_docs)]
+    Enum(VariantsSchema),
+    #[allow(missing_docs)]
+    Union(FieldsSchema),
+    #[allow(missing_docs)]
+    Unit,
+    #[allow(missing_docs)]
+    List(Box<TypeSchema>),
+    #[allow(missing_docs)]
+    Array {
+        #[allow(missing_docs)]
+        element: Box<TypeSchema>,
+        #[allow(missing_docs)]
+        len: usize,
+    },
+    #[allow(missing_docs)]
+    Optional(Box<TypeSchema>),
+    #[allow(missing_docs)]
+    Map {
+        #[allow(missing_docs)]
+        key: Box<TypeSchema>,
+        #[allow(missing_docs)]
+        value: Box<TypeSchema>,
+    },
+    /// Reference back to enclosing type of the same name, which is already being described
+    Recursive,
+}
+
+/// Owned mirror of [`Fields`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum FieldsSchema {
+    #[allow(missing_docs)]
+    Named(Vec<FieldSchema>),
+    #[allow(missing_docs)]
+    Indexed(Vec<FieldSchema>),
+    #[allow(missing_docs)]
+    Unit,
+}
+
+/// Owned mirror of [`FieldId`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum FieldIdSchema {
+    #[allow(missing_docs)]
+    Index(usize),
+    #[allow(missing_docs)]
+    Named(String),
+}
+
+/// Owned mirror of [`Field`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct FieldSchema {
+    #[allow(missing_docs)]
+    pub id: FieldIdSchema,
+    #[allow(missing_docs)]
+    pub ty: TypeSchema,
+    #[allow(missing_docs)]
+    pub meta: Vec<(String, String)>,
+    #[allow(missing_docs)]
+    pub docs: String,
+}
+
+/// Owned mirror of [`Variants`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct VariantsSchema {
+    #[allow(missing_docs)]
+    pub variants: Vec<VariantSchema>,
+    #[allow(missing_docs)]
+    pub repr: DiscriminantRepr,
+    #[allow(missing_docs)]
+    pub contiguous: bool,
+}
+
+/// Owned mirror of [`Variant`]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct VariantSchema {
+    #[allow(missing_docs)]
+    pub ident: String,
+    #[allow(missing_docs)]
+    pub discriminator: i128,
+    #[allow(missing_docs)]
+    pub fields: FieldsSchema,
+    #[allow(missing_docs)]
+    pub meta: Vec<(String, String)>,
+    #[allow(missing_docs)]
+    pub docs: String,
+}
+
+/// Converts metadata into mirrors, remembering types which are being converted,
+/// so references of recursive types back to themselves become [`DataSchema::Recursive`]
+#[derive(Default)]
+struct Mirror {
+    stack: Vec<*const Type>,
+}
+
+impl Mirror {
+    fn ty(&mut self, ty: &Type) -> TypeSchema {
+        let ident = ty.ident.to_string();
+        if self.stack.iter().any(|visited| std::ptr::eq(*visited, ty)) {
+            return TypeSchema {
+                ident,
+                data: DataSchema::Recursive,
+                meta: mirror_meta(ty.meta),
+                docs: ty.docs.to_string(),
+                generics: Vec::new(),
+            };
+        }
+
+        self.stack.push(ty);
+        let data = self.data(&ty.data);
+        let generics = ty
+            .generics
+            .iter()
+            .map(|param| self.generic(param))
+            .collect();
+        self.stack.pop();
+        TypeSchema {
+            ident,
+            data,
+            meta: mirror_meta(ty.meta),
+            docs: ty.docs.to_string(),
+            generics,
+        }
+    }
+
+    fn generic(&mut self, param: &GenericParamInfo) -> GenericParamSchema {
+        GenericParamSchema {
+            name: param.name.to_string(),
+            kind: match &param.kind {
+                GenericParamKind::Lifetime => GenericParamKindSchema::Lifetime,
+                GenericParamKind::Type(argument) => {
+                    GenericParamKindSchema::Type(Box::new(self.ty(argument)))
+                }
+                GenericParamKind::Const(value) => GenericParamKindSchema::Const(*value),
+            },
+        }
+    }
+
+    fn data(&mut self, data: &Data) -> DataSchema {
+        match data {
+            Data::Primitive => DataSchema::Primitive,
+            Data::Struct(fields) => DataSchema::Struct(self.fields(fields)),
+            Data::Tuple(fields) => DataSchema::Tuple(self.fields(fields)),
+            Data::Enum(variants) => DataSchema::Enum(self.variants(variants)),
+            Data::Union(fields) => DataSchema::Union(self.fields(fields)),
+            Data::Unit => DataSchema::Unit,
+            Data::List(element) => DataSchema::List(Box::new(self.ty(element))),
+            Data::Array { element, len } => DataSchema::Array {
+                element: Box::new(self.ty(element)),
+                len: *len,
+            },
+            Data::Optional(inner) => DataSchema::Optional(Box::new(self.ty(inner))),
+            Data::Map { key, value } => DataSchema::Map {
+                key: Box::new(self.ty(key)),
+                value: Box::new(self.ty(value)),
+            },
+        }
+    }
+
+    fn variants(&mut self, variants: &Variants) -> VariantsSchema {
+        VariantsSchema {
+            variants: variants
+                .variants
+                .iter()
+                .map(|variant| self.variant(variant))
+                .collect(),
+            repr: variants.repr,
+            contiguous: variants.contiguous,
+        }
+    }
+
+    fn variant(&mut self, variant: &Variant) -> VariantSchema {
+        VariantSchema {
+            ident: variant.ident.to_string(),
+            discriminator: variant.discriminator,
+            fields: self.fields(&variant.fields),
+            meta: mirror_meta(variant.meta),
+            docs: variant.docs.to_string(),
+        }
+    }
+
+    fn fields(&mut self, fields: &Fields) -> FieldsSchema {
+        match fields {
+            Fields::Named(named) => {
+                FieldsSchema::Named(named.iter().map(|field| self.field(field)).collect())
+            }
+            Fields::Indexed(indexed) => {
+                FieldsSchema::Indexed(indexed.iter().map(|field| self.field(field)).collect())
+            }
+            Fields::Unit => FieldsSchema::Unit,
+        }
+    }
+
+    fn field(&mut self, field: &Field) -> FieldSchema {
+        FieldSchema {
+            id: (&field.id).into(),
+            ty: self.ty(&field.ty),
+            meta: mirror_meta(field.meta),
+            docs: field.docs.to_string(),
+        }
+    }
+}
+
+fn mirror_meta(meta: &[(&str, &str)]) -> Vec<(String, String)> {
+    meta.iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+impl TypeSchema {
+    /// Name of type together with arguments of it's generic parameters, same as [`Type::full_name`]
+    pub fn full_name(&self) -> String {
+        let arguments = self
+            .generics
+            .iter()
+            .filter_map(|param| match &param.kind {
+                GenericParamKindSchema::Lifetime => None,
+                GenericParamKindSchema::Type(ty) => Some(ty.full_name()),
+                GenericParamKindSchema::Const(value) => Some(value.to_string()),
+            })
+            .collect::<Vec<_>>();
+        match arguments.is_empty() {
+            true => self.ident.clone(),
+            false => format!("{}<{}>", self.ident, arguments.join(", ")),
+        }
+    }
+}
+
+impl From<&Type> for TypeSchema {
+    fn from(ty: &Type) -> Self {
+        Mirror::default().ty(ty)
+    }
+}
+
+impl From<&Data> for DataSchema {
+    fn from(data: &Data) -> Self {
+        Mirror::default().data(data)
+    }
+}
+
+impl From<&Variants> for VariantsSchema {
+    fn from(variants: &Variants) -> Self {
+        Mirror::default().variants(variants)
+    }
+}
+
+impl From<&Fields> for FieldsSchema {
+    fn from(fields: &Fields) -> Self {
+        Mirror::default().fields(fields)
+    }
+}
+
+impl From<&FieldId> for FieldIdSchema {
+    fn from(id: &FieldId) -> Self {
+        match id {
+            FieldId::Index(index) => Self::Index(*index),
+            FieldId::Named(name) => Self::Named(name.to_string()),
+        }
+    }
+}
+
+impl From<&Field> for FieldSchema {
+    fn from(field: &Field) -> Self {
+        Mirror::default().field(field)
+    }
+}
+
+impl From<&Variant> for VariantSchema {
+    fn from(variant: &Variant) -> Self {
+        Mirror::default().variant(variant)
+    }
+}
+
+impl Serialize for Type {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        TypeSchema::from(self).serialize(serializer)
+    }
+}
+
+impl Serialize for Fields {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        FieldsSchema::from(self).serialize(serializer)
+    }
+}
+
+impl Serialize for Variant {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        VariantSchema::from(self).serialize(serializer)
+    }
+}
+
+/// Serializable set of type schemas, see [`crate::registry::Registry::export_bundle`]
+///
+/// Bundle allows process to learn reflected types of another process (e.g. worker or plugin host)
+/// and validate messages against them
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Default, Debug)]
+pub struct MetadataBundle {
+    /// Schemas, sorted by name of type
+    pub types: Vec<TypeSchema>,
+}
+
+impl MetadataBundle {
+    /// Schema of type named `ident`
+    pub fn get(&self, ident: &str) -> Option<&TypeSchema> {
+        self.types.iter().find(|schema| schema.ident == ident)
+    }
+}
+
+/// Disagreement between local metadata and imported schema
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum SchemaMismatch {
+    /// Remote side has no type with such name
+    #[error("Type `{0}` is unknown to remote side")]
+    Unknown(String),
+
+    /// Remote side has type with the same name, but different layout
+    #[error("Type `{0}` doesn't match it's remote schema")]
+    Differs(String),
+}
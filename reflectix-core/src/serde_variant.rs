@@ -0,0 +1,94 @@
+//! (De)serialization of enums by names of their unit variants
+//!
+//! Meant to be used as `#[serde(with = "reflectix::serde_variant")]` on fields of reflected enum types,
+//! or as `#[serde(with = "reflectix::serde_variant::seq")]` on `Vec`s of them. Values are written as exposed
+//! names of variants (which respect `rename` and `rename_all`), names which don't belong to any unit variant
+//! are handled according to [`UnknownVariants`] policy of enum:
+//!
+//! - [`UnknownVariants::Error`] fails deserialization, listing names of unit variants
+//! - [`UnknownVariants::Fallback`] replaces unknown name with fallback variant
+//! - [`UnknownVariants::Skip`] drops unknown name from sequence. Single value can't be dropped, so there it is an error
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serializer};
+
+use crate::{Data, TypeInfo, TypeInfoDynamic, UnknownVariantError, UnknownVariants};
+
+/// Serialize `value` as name of it's active variant, which must be unit
+pub fn serialize<T: TypeInfoDynamic, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match value.variant() {
+        Some(variant) if variant.is_unit() => serializer.serialize_str(variant.ident),
+        _ => Err(S::Error::custom(format!(
+            "Value of `{}` is not a unit variant",
+            value.get_dynamic().ident
+        ))),
+    }
+}
+
+/// Deserialize name of unit variant of `T`
+pub fn deserialize<'de, T: TypeInfo, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    let name = String::deserialize(deserializer)?;
+    match parse(&name) {
+        Ok(Some(value)) => Ok(value),
+        Ok(None) => Err(D::Error::custom(UnknownVariantError::new(T::INFO, &name))),
+        Err(error) => Err(D::Error::custom(error)),
+    }
+}
+
+// `None` if value must be skipped
+fn parse<T: TypeInfo>(name: &str) -> Result<Option<T>, UnknownVariantError> {
+    if let Some(value) = T::from_unit_variant(name) {
+        return Ok(Some(value));
+    }
+
+    let unknown = match &T::INFO.data {
+        Data::Enum(variants) => variants.unknown,
+        _ => UnknownVariants::Error,
+    };
+    match unknown {
+        UnknownVariants::Error => Err(UnknownVariantError::new(T::INFO, name)),
+        UnknownVariants::Skip => Ok(None),
+        UnknownVariants::Fallback(fallback) => T::from_unit_variant(fallback)
+            .map(Some)
+            .ok_or_else(|| UnknownVariantError::new(T::INFO, fallback)),
+    }
+}
+
+/// Same as [parent module](super), but for sequences of variants
+pub mod seq {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::{TypeInfo, TypeInfoDynamic};
+
+    struct Name<'a, T>(&'a T);
+
+    impl<T: TypeInfoDynamic> Serialize for Name<'_, T> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::serialize(self.0, serializer)
+        }
+    }
+
+    /// Serialize `values` as sequence of names of their active variants
+    pub fn serialize<T: TypeInfoDynamic, S: Serializer>(
+        values: &[T],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(Name))
+    }
+
+    /// Deserialize sequence of names of unit variants of `T`, skipping unknown ones if `T` allows to
+    pub fn deserialize<'de, T: TypeInfo, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<T>, D::Error> {
+        let mut values = Vec::new();
+        for name in Vec::<String>::deserialize(deserializer)? {
+            if let Some(value) = super::parse(&name).map_err(serde::de::Error::custom)? {
+                values.push(value);
+            }
+        }
+        Ok(values)
+    }
+}
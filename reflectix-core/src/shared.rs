@@ -0,0 +1,278 @@
+//! Reflection of interior mutability containers
+//!
+//! `Cell<T>`, `RefCell<T>`, `Mutex<T>` and `RwLock<T>` share metadata of `T`. Fields are reachable mutably
+//! through exclusive reference (as with `get_mut`), and through shared reference only with guard, see [`ReflectShared`].
+//! For the same reason, [`TypeInfoDynamic::as_list`] and [`TypeInfoDynamic::as_map`] are `None`:
+//! list or map inside is viewed through value, returned by [`ReflectShared::read`]
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, RwLock};
+
+use crate::containers::downcast_constructed;
+use crate::{
+    ErasedGuard, ErasedGuardMut, FieldAccessError, FieldId, ReflectList, ReflectMap, ReflectShared,
+    RuntimeConstructError, SharedMut, SharedRef, Type, TypeInfo, TypeInfoDynamic, Unsizeable,
+    UnsizeableMut, UnsizeableOwned, Variant,
+};
+
+// any guard, which dereferences to reflected value
+struct Guard<G>(G);
+
+impl<G> ErasedGuard for Guard<G>
+where
+    G: Deref,
+    G::Target: TypeInfoDynamic + Sized,
+{
+    fn get(&self) -> &dyn TypeInfoDynamic {
+        &*self.0
+    }
+}
+
+impl<G> ErasedGuardMut for Guard<G>
+where
+    G: DerefMut,
+    G::Target: TypeInfoDynamic + Sized,
+{
+    fn get_mut(&mut self) -> &mut dyn TypeInfoDynamic {
+        &mut *self.0
+    }
+}
+
+// container of `Inner` with interior mutability
+trait Interior: Sized + 'static {
+    type Inner: TypeInfo;
+
+    fn wrap(inner: Self::Inner) -> Self;
+
+    fn inner_mut(&mut self) -> Result<&mut Self::Inner, FieldAccessError>;
+
+    fn read_inner(&self) -> Result<SharedRef<'_>, FieldAccessError>;
+
+    fn write_inner(&self) -> Result<SharedMut<'_>, FieldAccessError>;
+
+    // never waits, so it doesn't deadlock when called while guard is held
+    fn peek_variant(&self) -> Option<&'static Variant>;
+}
+
+impl<T: TypeInfo + Copy> Interior for Cell<T> {
+    type Inner = T;
+
+    fn wrap(inner: T) -> Self {
+        Cell::new(inner)
+    }
+
+    fn inner_mut(&mut self) -> Result<&mut T, FieldAccessError> {
+        Ok(self.get_mut())
+    }
+
+    fn read_inner(&self) -> Result<SharedRef<'_>, FieldAccessError> {
+        Ok(SharedRef::new(Guard(Box::new(self.get()))))
+    }
+
+    fn write_inner(&self) -> Result<SharedMut<'_>, FieldAccessError> {
+        Err(FieldAccessError::ReadOnly)
+    }
+
+    fn peek_variant(&self) -> Option<&'static Variant> {
+        self.get().variant()
+    }
+}
+
+impl<T: TypeInfo> Interior for RefCell<T> {
+    type Inner = T;
+
+    fn wrap(inner: T) -> Self {
+        RefCell::new(inner)
+    }
+
+    fn inner_mut(&mut self) -> Result<&mut T, FieldAccessError> {
+        Ok(self.get_mut())
+    }
+
+    fn read_inner(&self) -> Result<SharedRef<'_>, FieldAccessError> {
+        let guard = self.try_borrow().map_err(|_| FieldAccessError::Borrowed)?;
+        Ok(SharedRef::new(Guard(guard)))
+    }
+
+    fn write_inner(&self) -> Result<SharedMut<'_>, FieldAccessError> {
+        let guard = self
+            .try_borrow_mut()
+            .map_err(|_| FieldAccessError::Borrowed)?;
+        Ok(SharedMut::new(Guard(guard)))
+    }
+
+    fn peek_variant(&self) -> Option<&'static Variant> {
+        self.try_borrow().ok()?.variant()
+    }
+}
+
+impl<T: TypeInfo> Interior for Mutex<T> {
+    type Inner = T;
+
+    fn wrap(inner: T) -> Self {
+        Mutex::new(inner)
+    }
+
+    fn inner_mut(&mut self) -> Result<&mut T, FieldAccessError> {
+        self.get_mut().map_err(|_| FieldAccessError::Poisoned)
+    }
+
+    fn read_inner(&self) -> Result<SharedRef<'_>, FieldAccessError> {
+        let guard = self.lock().map_err(|_| FieldAccessError::Poisoned)?;
+        Ok(SharedRef::new(Guard(guard)))
+    }
+
+    fn write_inner(&self) -> Result<SharedMut<'_>, FieldAccessError> {
+        let guard = self.lock().map_err(|_| FieldAccessError::Poisoned)?;
+        Ok(SharedMut::new(Guard(guard)))
+    }
+
+    fn peek_variant(&self) -> Option<&'static Variant> {
+        self.try_lock().ok()?.variant()
+    }
+}
+
+impl<T: TypeInfo> Interior for RwLock<T> {
+    type Inner = T;
+
+    fn wrap(inner: T) -> Self {
+        RwLock::new(inner)
+    }
+
+    fn inner_mut(&mut self) -> Result<&mut T, FieldAccessError> {
+        self.get_mut().map_err(|_| FieldAccessError::Poisoned)
+    }
+
+    fn read_inner(&self) -> Result<SharedRef<'_>, FieldAccessError> {
+        let guard = self.read().map_err(|_| FieldAccessError::Poisoned)?;
+        Ok(SharedRef::new(Guard(guard)))
+    }
+
+    fn write_inner(&self) -> Result<SharedMut<'_>, FieldAccessError> {
+        let guard = self.write().map_err(|_| FieldAccessError::Poisoned)?;
+        Ok(SharedMut::new(Guard(guard)))
+    }
+
+    fn peek_variant(&self) -> Option<&'static Variant> {
+        self.try_read().ok()?.variant()
+    }
+}
+
+// constructs value inside with value, which is currently inside, as prototype
+fn construct_inner<C: Interior + TypeInfoDynamic>(
+    container: &C,
+    construct: impl FnOnce(&dyn TypeInfoDynamic) -> Result<UnsizeableOwned, RuntimeConstructError>,
+) -> Result<UnsizeableOwned, RuntimeConstructError> {
+    let inner = container
+        .read_inner()
+        .map_err(RuntimeConstructError::custom)?;
+    let constructed = construct(&*inner)?;
+    drop(inner);
+
+    let constructed = downcast_constructed::<C::Inner>(constructed)?;
+    Ok(UnsizeableOwned::new(C::wrap(*constructed)))
+}
+
+macro_rules! impl_interior {
+    ($(<T: $($bound:path),+> $container:ty),* $(,)?) => {
+        $(
+            impl<T: $($bound +)+ 'static> TypeInfoDynamic for $container {
+                fn get_dynamic(&self) -> &'static Type {
+                    T::INFO
+                }
+
+                fn construct_struct(
+                    &self,
+                    args: Vec<Box<dyn Any>>,
+                ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                    construct_inner(self, |inner| inner.construct_struct(args))
+                }
+
+                fn construct_enum(
+                    &self,
+                    variant: &'static str,
+                    args: Vec<Box<dyn Any>>,
+                ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                    construct_inner(self, |inner| inner.construct_enum(variant, args))
+                }
+
+                fn field<'s>(&'s self, _id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+                    Err(FieldAccessError::Shared)
+                }
+
+                fn field_mut<'s>(
+                    &'s mut self,
+                    id: FieldId,
+                ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+                    self.inner_mut()?.field_mut(id)
+                }
+
+                fn variant(&self) -> Option<&'static Variant> {
+                    self.peek_variant()
+                }
+
+                fn field_at<'s>(
+                    &'s self,
+                    _position: usize,
+                ) -> Result<Unsizeable<'s>, FieldAccessError> {
+                    Err(FieldAccessError::Shared)
+                }
+
+                fn field_at_mut<'s>(
+                    &'s mut self,
+                    position: usize,
+                ) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+                    self.inner_mut()?.field_at_mut(position)
+                }
+
+                fn as_list_mut(&mut self) -> Option<&mut dyn ReflectList> {
+                    self.inner_mut().ok()?.as_list_mut()
+                }
+
+                fn as_map_mut(&mut self) -> Option<&mut dyn ReflectMap> {
+                    self.inner_mut().ok()?.as_map_mut()
+                }
+
+                // views, which don't need guard, are available only through exclusive reference,
+                // so `as_list` and `as_map` are reached with `read()`
+                fn pointee_mut(&mut self) -> Result<UnsizeableMut<'_>, FieldAccessError> {
+                    self.inner_mut().map(UnsizeableMut::new)
+                }
+
+                fn construct_wrapper(
+                    &self,
+                    inner: Box<dyn Any>,
+                ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+                    let inner = crate::containers::downcast_arg::<T>(0, inner, None)?;
+                    Ok(UnsizeableOwned::new(<$container as Interior>::wrap(inner)))
+                }
+
+                fn as_shared(&self) -> Option<&dyn ReflectShared> {
+                    Some(self)
+                }
+            }
+
+            impl<T: $($bound +)+ 'static> ReflectShared for $container {
+                fn read(&self) -> Result<SharedRef<'_>, FieldAccessError> {
+                    self.read_inner()
+                }
+
+                fn write(&self) -> Result<SharedMut<'_>, FieldAccessError> {
+                    self.write_inner()
+                }
+            }
+
+            impl<T: $($bound +)+ 'static> TypeInfo for $container {
+                const INFO: &'static Type = T::INFO;
+            }
+        )*
+    };
+}
+
+impl_interior!(
+    <T: TypeInfo, Copy> Cell<T>,
+    <T: TypeInfo> RefCell<T>,
+    <T: TypeInfo> Mutex<T>,
+    <T: TypeInfo> RwLock<T>,
+);
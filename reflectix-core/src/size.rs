@@ -0,0 +1,65 @@
+//! Estimation of memory, used by reflected values
+//!
+//! [`deep_size_of`] walks fields, list elements and map entries of erased value and sums up
+//! their sizes together with heap buffers of strings. Result is an estimate: spare capacity of collections
+//! and allocator overhead are not counted, and boxes are transparent, so their allocations are counted
+//! as if boxed value was stored inline
+use std::any::Any;
+use std::mem::size_of_val;
+
+use crate::{Data, FieldId, Fields, TypeInfoDynamic};
+
+/// Estimated number of bytes, used by `value` both inline and on the heap
+pub fn deep_size_of(value: &dyn TypeInfoDynamic) -> usize {
+    size_of_val(value) + heap_size_of(value)
+}
+
+// bytes, owned by value outside of it's inline representation
+fn heap_size_of(value: &dyn TypeInfoDynamic) -> usize {
+    let any: &dyn Any = value;
+    if let Some(string) = any.downcast_ref::<String>() {
+        return string.capacity();
+    }
+    #[cfg(feature = "json")]
+    if let Some(serde_json::Value::String(string)) = any.downcast_ref::<serde_json::Value>() {
+        return string.capacity();
+    }
+
+    if let Some(list) = value.as_list() {
+        return (0..list.len())
+            .filter_map(|index| value.field(FieldId::Index(index)).ok())
+            .map(|element| deep_size_of(element.as_dynamic()))
+            .sum();
+    }
+    if let Some(map) = value.as_map() {
+        return map
+            .iter()
+            .map(|(key, value)| deep_size_of(key.as_dynamic()) + deep_size_of(value.as_dynamic()))
+            .sum();
+    }
+
+    match &value.get_dynamic().data {
+        Data::Struct(fields) | Data::Tuple(fields) => fields_heap_size(value, fields),
+        Data::Enum(_) => value
+            .variant()
+            .map_or(0, |variant| fields_heap_size(value, &variant.fields)),
+        // elements are stored inline, so only their heap buffers are added
+        Data::Array { len, .. } => (0..*len)
+            .filter_map(|index| value.field(FieldId::Index(index)).ok())
+            .map(|element| heap_size_of(element.as_dynamic()))
+            .sum(),
+        Data::Optional(_) => value
+            .field(FieldId::Index(0))
+            .map_or(0, |inner| heap_size_of(inner.as_dynamic())),
+        _ => 0,
+    }
+}
+
+fn fields_heap_size(value: &dyn TypeInfoDynamic, fields: &Fields) -> usize {
+    fields
+        .as_slice()
+        .iter()
+        .filter_map(|field| value.field(field.id.clone()).ok())
+        .map(|field| heap_size_of(field.as_dynamic()))
+        .sum()
+}
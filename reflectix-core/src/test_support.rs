@@ -0,0 +1,44 @@
+//! Helpers for testing reflective systems
+//!
+//! Each test builds it's own [`Registry`] with exactly the types it needs, so tests running
+//! in parallel don't observe each other's registrations:
+//!
+//! ```ignore
+//! let registry = test_support::registry()
+//!     .with::<Player>()
+//!     .with_value(Team::Red)
+//!     .build();
+//! ```
+use crate::registry::Registry;
+use crate::TypeInfo;
+
+/// Builder of isolated [`Registry`], which panics on registration conflicts instead of returning errors
+#[derive(Default, Debug)]
+pub struct RegistryBuilder {
+    registry: Registry,
+}
+
+impl RegistryBuilder {
+    /// Register `T` with it's default value as prototype
+    pub fn with<T: TypeInfo + Default>(self) -> Self {
+        self.with_value(T::default())
+    }
+
+    /// Register type of `prototype`
+    pub fn with_value<T: TypeInfo>(mut self, prototype: T) -> Self {
+        if let Err(err) = self.registry.register(prototype) {
+            panic!("Can't register test type: {}", err);
+        }
+        self
+    }
+
+    #[allow(missing_docs)]
+    pub fn build(self) -> Registry {
+        self.registry
+    }
+}
+
+/// Start building isolated registry
+pub fn registry() -> RegistryBuilder {
+    RegistryBuilder::default()
+}
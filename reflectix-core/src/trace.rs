@@ -0,0 +1,175 @@
+//! Recording of reflected values as structured [`tracing`](::tracing) fields
+//!
+//! [`record_fields`] walks primitive leaves of value and records each of them into span
+//! under dot-separated path (`address.city`, `items.0.price`), so payloads appear as structured
+//! attributes instead of opaque `Debug` output.
+//!
+//! `tracing` requires every field to be declared when span is created, so span must declare
+//! paths it wants to receive as [`Empty`](::tracing::field::Empty); leaves which are not declared are skipped:
+//!
+//! ```ignore
+//! let span = tracing::info_span!("request", user.name = Empty, user.age = Empty);
+//! trace::record_fields(&span, &user);
+//! ```
+//!
+//! Fields with `#[reflectix(meta(redact = "..."))]` are recorded as [`REDACTED`] without looking inside
+use crate::{Data, FieldId, Fields, TypeInfoDynamic};
+
+/// Value, recorded instead of redacted fields
+pub const REDACTED: &str = "[redacted]";
+
+/// Record primitive leaves of `value` into `span`, see [module documentation](self)
+pub fn record_fields(span: &::tracing::Span, value: &dyn TypeInfoDynamic) {
+    record(span, "", value);
+}
+
+fn record(span: &::tracing::Span, path: &str, value: &dyn TypeInfoDynamic) {
+    let value = value.innermost();
+    if let Some(shared) = value.as_shared() {
+        if let Ok(inner) = shared.read() {
+            record(span, path, &*inner);
+        }
+        return;
+    }
+    if record_primitive(span, path, value) {
+        return;
+    }
+
+    if let Some(list) = value.as_list() {
+        record_elements(span, path, value, list.len());
+        return;
+    }
+    if let Some(map) = value.as_map() {
+        for (key, entry) in map.iter() {
+            if let Some(key) = crate::diff::display_primitive(key.as_dynamic()) {
+                record(span, &join(path, &key), entry.as_dynamic());
+            }
+        }
+        return;
+    }
+
+    match &value.get_dynamic().data {
+        Data::Struct(fields) | Data::Tuple(fields) => record_fields_of(span, path, value, fields),
+        Data::Enum(_) => {
+            if let Some(variant) = value.variant() {
+                record_fields_of(span, path, value, &variant.fields);
+            }
+        }
+        Data::Array { len, .. } => record_elements(span, path, value, *len),
+        // payload is recorded under the path of optional field itself
+        Data::Optional(_) => {
+            if let Ok(inner) = value.field(FieldId::Index(0)) {
+                record(span, path, inner.as_dynamic());
+            }
+        }
+        _ => {}
+    }
+}
+
+fn record_elements(span: &::tracing::Span, path: &str, value: &dyn TypeInfoDynamic, len: usize) {
+    for index in 0..len {
+        if let Ok(element) = value.field(FieldId::Index(index)) {
+            record(span, &join(path, &index.to_string()), element.as_dynamic());
+        }
+    }
+}
+
+fn record_fields_of(
+    span: &::tracing::Span,
+    path: &str,
+    value: &dyn TypeInfoDynamic,
+    fields: &Fields,
+) {
+    for field in fields.as_slice() {
+        let name = match field.id {
+            FieldId::Named(name) => name.to_string(),
+            FieldId::Index(index) => index.to_string(),
+        };
+        let path = join(path, &name);
+
+        if field.get_meta("redact").is_some() {
+            span.record(path.as_str(), REDACTED);
+            continue;
+        }
+        if let Ok(field) = value.field(field.id.clone()) {
+            record(span, &path, field.as_dynamic());
+        }
+    }
+}
+
+fn join(path: &str, segment: &str) -> String {
+    match path {
+        "" => segment.to_string(),
+        path => format!("{}.{}", path, segment),
+    }
+}
+
+// Primitive, which can be recorded as field of span
+trait Leaf {
+    fn record_into(&self, span: &::tracing::Span, path: &str);
+}
+
+macro_rules! value_leaves {
+    ($($ty:ty),*) => {
+        $(impl Leaf for $ty {
+            fn record_into(&self, span: &::tracing::Span, path: &str) {
+                span.record(path, self);
+            }
+        })*
+    };
+}
+
+value_leaves!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    usize,
+    isize,
+    String,
+    f32,
+    f64,
+    bool,
+    &'static str
+);
+
+// `tracing` has no character values, so characters are recorded as strings
+impl Leaf for char {
+    fn record_into(&self, span: &::tracing::Span, path: &str) {
+        span.record(path, self.encode_utf8(&mut [0; 4]) as &str);
+    }
+}
+
+impl Leaf for std::borrow::Cow<'static, str> {
+    fn record_into(&self, span: &::tracing::Span, path: &str) {
+        span.record(path, self.as_ref());
+    }
+}
+
+macro_rules! record_primitives {
+    ($($ty:ty),*) => {
+        // `false` if value is not primitive
+        fn record_primitive(span: &::tracing::Span, path: &str, value: &dyn TypeInfoDynamic) -> bool {
+            let value: &dyn std::any::Any = value;
+            $(
+                if let Some(value) = value.downcast_ref::<$ty>() {
+                    value.record_into(span, path);
+                    return true;
+                }
+            )*
+            false
+        }
+    };
+}
+
+for_each_primitive!(
+    record_primitives,
+    &'static str,
+    std::borrow::Cow<'static, str>
+);
@@ -0,0 +1,115 @@
+//! Heterogeneous store of reflected values
+//!
+//! [`TypeMap`] holds at most one value per type, keyed by it's [`TypeId`]. Values can be accessed
+//! both statically (by naming the type) and reflectively (by [`TypeId`] or name of type in metadata),
+//! which is handy for component-like stores and plugin state
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+use crate::TypeInfoDynamic;
+
+/// Store of values keyed by their type
+#[derive(Default)]
+pub struct TypeMap {
+    entries: HashMap<TypeId, Box<dyn TypeInfoDynamic>>,
+}
+
+impl TypeMap {
+    #[allow(missing_docs)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Store `value`, returning previous value of the same type, if there was any
+    pub fn insert<T: TypeInfoDynamic>(&mut self, value: T) -> Option<T> {
+        self.insert_boxed(Box::new(value))
+            .map(|previous| downcast_box(previous).unwrap())
+    }
+
+    /// Store already erased value, returning previous value of the same type, if there was any
+    pub fn insert_boxed(
+        &mut self,
+        value: Box<dyn TypeInfoDynamic>,
+    ) -> Option<Box<dyn TypeInfoDynamic>> {
+        let type_id = Any::type_id(&*value);
+        self.entries.insert(type_id, value)
+    }
+
+    #[allow(missing_docs)]
+    pub fn get<T: TypeInfoDynamic>(&self) -> Option<&T> {
+        let value: &dyn Any = &**self.entries.get(&TypeId::of::<T>())?;
+        value.downcast_ref()
+    }
+
+    #[allow(missing_docs)]
+    pub fn get_mut<T: TypeInfoDynamic>(&mut self) -> Option<&mut T> {
+        let value: &mut dyn Any = &mut **self.entries.get_mut(&TypeId::of::<T>())?;
+        value.downcast_mut()
+    }
+
+    /// Take value of type `T` out of the store
+    pub fn remove<T: TypeInfoDynamic>(&mut self) -> Option<T> {
+        self.remove_dynamic(TypeId::of::<T>())
+            .map(|value| downcast_box(value).unwrap())
+    }
+
+    #[allow(missing_docs)]
+    pub fn contains<T: TypeInfoDynamic>(&self) -> bool {
+        self.entries.contains_key(&TypeId::of::<T>())
+    }
+
+    /// Erased value of type with given [`TypeId`]
+    pub fn get_dynamic(&self, type_id: TypeId) -> Option<&dyn TypeInfoDynamic> {
+        self.entries.get(&type_id).map(|value| &**value)
+    }
+
+    /// Erased mutable value of type with given [`TypeId`]
+    pub fn get_dynamic_mut(&mut self, type_id: TypeId) -> Option<&mut dyn TypeInfoDynamic> {
+        Some(&mut **self.entries.get_mut(&type_id)?)
+    }
+
+    /// Take erased value of type with given [`TypeId`] out of the store
+    pub fn remove_dynamic(&mut self, type_id: TypeId) -> Option<Box<dyn TypeInfoDynamic>> {
+        self.entries.remove(&type_id)
+    }
+
+    /// Erased value, metadata of which is named `ident`
+    ///
+    /// If several stored types share the name, any of them may be returned
+    pub fn get_by_name(&self, ident: &str) -> Option<&dyn TypeInfoDynamic> {
+        self.iter().find(|value| value.get_dynamic().ident == ident)
+    }
+
+    /// Erased mutable value, metadata of which is named `ident`
+    pub fn get_by_name_mut(&mut self, ident: &str) -> Option<&mut dyn TypeInfoDynamic> {
+        self.iter_mut()
+            .find(|value| value.get_dynamic().ident == ident)
+    }
+
+    /// All stored values, in unspecified order
+    pub fn iter(&self) -> impl Iterator<Item = &dyn TypeInfoDynamic> {
+        self.entries.values().map(|value| &**value)
+    }
+
+    /// All stored values, in unspecified order
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut dyn TypeInfoDynamic> {
+        self.entries
+            .values_mut()
+            .map(|value| &mut **value as &mut dyn TypeInfoDynamic)
+    }
+
+    #[allow(missing_docs)]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[allow(missing_docs)]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+fn downcast_box<T: 'static>(value: Box<dyn TypeInfoDynamic>) -> Option<T> {
+    let value: Box<dyn Any> = value;
+    value.downcast().ok().map(|value| *value)
+}
@@ -0,0 +1,230 @@
+//! Read-only access to `#[repr(C)]` values directly over bytes
+//!
+//! [`view_bytes`] lays metadata of type over byte buffer, such as memory-mapped file or received packet,
+//! without copying or constructing the value. Fields are located by their captured [`Field::offset`], so only
+//! structs with defined layout (and arrays of them) can be viewed. Buffer must be at least as large as the type
+//! and aligned as the type requires.
+//!
+//! Leaves are read with [`ReflectView::read`], which is limited to integers and floats, since any bit pattern
+//! is a valid value of theirs.
+//!
+//! Byte order of wire-format structs is declared with `#[reflectix(endian = "big")]` (or `"little"`, `"native"`)
+//! on fields or on the whole type, recorded as metadata `endian`. Field without declared byte order
+//! inherits it from it's type, and then from enclosing struct. Byte order is native, if it's declared nowhere
+use crate::{Data, Field, FieldId, Ownership, Type, TypeInfo};
+
+/// Failure of viewing bytes as value
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+pub enum ViewError {
+    /// Layout of type is not known
+    #[error("Layout of `{0}` is not defined, only `#[repr(C)]` structs can be viewed")]
+    NoLayout(&'static str),
+    /// Buffer is smaller than type
+    #[error("Buffer of {actual} bytes is too short for value of {expected} bytes")]
+    TooShort {
+        #[allow(missing_docs)]
+        expected: usize,
+        #[allow(missing_docs)]
+        actual: usize,
+    },
+    /// Buffer is not aligned as type requires
+    #[error("Buffer is not aligned to {0} bytes")]
+    Misaligned(usize),
+    #[allow(missing_docs)]
+    #[error("Field `{0}` is not found")]
+    NotFound(FieldId),
+    /// Viewed value is not of requested type
+    #[error("Can't read `{actual}` as `{expected}`")]
+    UnmatchingType {
+        #[allow(missing_docs)]
+        expected: &'static str,
+        #[allow(missing_docs)]
+        actual: &'static str,
+    },
+}
+
+/// Metadata key of declared byte order
+pub const ENDIAN_META: &str = "endian";
+
+/// Byte order of integers and floats
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Endian {
+    #[allow(missing_docs)]
+    Little,
+    #[allow(missing_docs)]
+    Big,
+    /// Byte order of target platform
+    #[default]
+    Native,
+}
+
+impl Endian {
+    /// Parse value of `endian` metadata
+    pub fn from_meta(value: &str) -> Option<Self> {
+        match value {
+            "little" => Some(Self::Little),
+            "big" => Some(Self::Big),
+            "native" => Some(Self::Native),
+            _ => None,
+        }
+    }
+
+    /// Byte order, declared on `field`, if any
+    pub fn of_field(field: &Field) -> Option<Self> {
+        field.get_meta(ENDIAN_META).and_then(Self::from_meta)
+    }
+
+    /// Byte order, declared on `ty`, if any
+    pub fn of_type(ty: &Type) -> Option<Self> {
+        ty.get_meta(ENDIAN_META).and_then(Self::from_meta)
+    }
+}
+
+/// Value of type, laid over bytes
+#[derive(Clone, Copy, Debug)]
+pub struct ReflectView<'b> {
+    bytes: &'b [u8],
+    ty: &'static Type,
+    endian: Endian,
+}
+
+/// View `bytes` as value of type, described by `ty`
+pub fn view_bytes<'b>(bytes: &'b [u8], ty: &'static Type) -> Result<ReflectView<'b>, ViewError> {
+    if !has_layout(ty) {
+        return Err(ViewError::NoLayout(ty.ident));
+    }
+    if bytes.len() < ty.size {
+        return Err(ViewError::TooShort {
+            expected: ty.size,
+            actual: bytes.len(),
+        });
+    }
+    if !(bytes.as_ptr() as usize).is_multiple_of(ty.align) {
+        return Err(ViewError::Misaligned(ty.align));
+    }
+
+    Ok(ReflectView {
+        bytes: &bytes[..ty.size],
+        ty,
+        endian: Endian::of_type(ty).unwrap_or_default(),
+    })
+}
+
+// every byte of value can be located
+fn has_layout(ty: &'static Type) -> bool {
+    match &ty.data {
+        Data::Primitive => true,
+        Data::Struct(fields) => fields.as_slice().iter().all(|field| {
+            field.offset.is_some()
+                && field.ownership == Ownership::Owned
+                // transparent wrappers, such as `Box<T>`, share metadata with wrapped type
+                && field.type_id == field.ty.get().type_id
+        }),
+        Data::Array { element, .. } => has_layout(element.get()),
+        _ => false,
+    }
+}
+
+impl<'b> ReflectView<'b> {
+    /// Type of viewed value
+    pub fn ty(&self) -> &'static Type {
+        self.ty
+    }
+
+    /// Bytes of viewed value
+    pub fn bytes(&self) -> &'b [u8] {
+        self.bytes
+    }
+
+    /// Byte order, [`ReflectView::read`] interprets bytes in
+    pub fn endian(&self) -> Endian {
+        self.endian
+    }
+
+    /// View field of struct, or element of array by it's index
+    pub fn field(&self, id: FieldId) -> Result<ReflectView<'b>, ViewError> {
+        let (offset, ty, endian) = match &self.ty.data {
+            Data::Struct(fields) => {
+                let field: &Field = fields
+                    .as_slice()
+                    .iter()
+                    .find(|field| field.id == id)
+                    .ok_or_else(|| ViewError::NotFound(id.clone()))?;
+                (
+                    field.offset.unwrap(),
+                    field.ty.get(),
+                    Endian::of_field(field),
+                )
+            }
+            Data::Array { element, len } => match id {
+                FieldId::Index(index) if index < *len => {
+                    let element = element.get();
+                    (index * element.size, element, None)
+                }
+                id => return Err(ViewError::NotFound(id)),
+            },
+            _ => return Err(ViewError::NotFound(id)),
+        };
+        if !has_layout(ty) {
+            return Err(ViewError::NoLayout(ty.ident));
+        }
+
+        Ok(ReflectView {
+            bytes: &self.bytes[offset..offset + ty.size],
+            ty,
+            endian: endian
+                .or_else(|| Endian::of_type(ty))
+                .unwrap_or(self.endian),
+        })
+    }
+
+    /// Read viewed value in it's declared byte order, if it's of type `T`
+    pub fn read<T: Plain>(&self) -> Result<T, ViewError> {
+        self.read_with(self.endian)
+    }
+
+    /// Read viewed value in byte order `endian`, if it's of type `T`
+    pub fn read_with<T: Plain>(&self, endian: Endian) -> Result<T, ViewError> {
+        if self.ty.type_id != T::INFO.type_id {
+            return Err(ViewError::UnmatchingType {
+                expected: T::INFO.ident,
+                actual: self.ty.ident,
+            });
+        }
+        Ok(T::decode(self.bytes, endian))
+    }
+}
+
+mod sealed {
+    use super::Endian;
+
+    pub trait Sealed: Sized {
+        // `bytes` are exactly of size of `Self`
+        fn decode(bytes: &[u8], endian: Endian) -> Self;
+    }
+}
+
+/// Types, which are valid for any bit pattern, so they can be read from arbitrary bytes
+///
+/// Sealed, implemented for integers and floats
+pub trait Plain: TypeInfo + Copy + sealed::Sealed {}
+
+macro_rules! impl_plain {
+    ($($ty:ty),*) => {
+        $(
+            impl sealed::Sealed for $ty {
+                fn decode(bytes: &[u8], endian: Endian) -> Self {
+                    let bytes = bytes.try_into().unwrap();
+                    match endian {
+                        Endian::Little => <$ty>::from_le_bytes(bytes),
+                        Endian::Big => <$ty>::from_be_bytes(bytes),
+                        Endian::Native => <$ty>::from_ne_bytes(bytes),
+                    }
+                }
+            }
+            impl Plain for $ty {}
+        )*
+    };
+}
+
+impl_plain!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
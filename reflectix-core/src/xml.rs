@@ -0,0 +1,464 @@
+//! XML encoding of reflected values
+//!
+//! [`to_xml`] writes value as element named after it's type, [`from_xml`] reads it back. Layout follows metadata:
+//!
+//! - primitives are written as text of their element
+//! - named fields are child elements, named as fields. Fields, marked with `#[reflectix(xml_attr)]`
+//!   (recorded as metadata `xml = "attribute"`), are written as attributes instead and must be primitive
+//! - tuple-like fields and elements of tuples are child elements named by their index, prefixed with underscore (`_0`, `_1`)
+//! - enums contain single child element, named after active variant, which holds fields of variant
+//! - lists and arrays contain `item` element per each element, unit types are empty elements
+//! - `None` is written by omitting element (or attribute) altogether
+//! - wrappers (such as `Box<T>`) and values behind interior mutability (such as `Mutex<T>`) are written as value they hold
+//!
+//! Every field must be readable to be written. If some field can't be accessed (e.g. it's private under
+//! `#[reflectix(public_only)]`), writing fails with [`XmlError::Access`] instead of leaving it out
+//!
+//! Maps are not supported, since keys of arbitrary type can't be written as names of elements.
+//!
+//! Reading constructs values reflectively, so it needs prototype of every non-primitive type it meets.
+//! Prototype of root value is given by caller, prototypes of nested values are looked up in [`Registry`]
+//! by their [`TypeId`](std::any::TypeId), falling back to corresponding part of root prototype. Trailing fields,
+//! which are absent from input, are filled by constructor with their defaults, if it has them.
+//! Absent optional fields are `None`
+use std::any::Any;
+use std::fmt::Write;
+
+use quick_xml::events::{BytesStart, Event};
+
+use crate::registry::Registry;
+use crate::{
+    Data, Field, FieldAccessError, FieldId, Fields, RuntimeConstructError, Type, TypeInfoDynamic,
+};
+
+/// Metadata key, which marks field to be written as attribute
+pub const XML_META: &str = "xml";
+
+/// Failure of XML encoding or decoding
+#[derive(thiserror::Error, Debug)]
+pub enum XmlError {
+    /// Input is not a well-formed XML
+    #[error(transparent)]
+    Syntax(#[from] quick_xml::Error),
+    /// Type can't be represented in XML
+    #[error("Type `{0}` can't be represented in XML")]
+    Unsupported(&'static str),
+    /// Field, marked as attribute, is not primitive
+    #[error("Field `{field}` of `{ty}` is marked as attribute, but it is not primitive")]
+    NotAttribute {
+        #[allow(missing_docs)]
+        ty: &'static str,
+        #[allow(missing_docs)]
+        field: FieldId,
+    },
+    /// Element is not the one, expected at it's position
+    #[error("Expected element `{expected}`, found `{found}`")]
+    UnexpectedElement {
+        #[allow(missing_docs)]
+        expected: String,
+        #[allow(missing_docs)]
+        found: String,
+    },
+    /// Element of enum doesn't contain element of variant
+    #[error("Element of enum `{0}` has no variant")]
+    MissingVariant(&'static str),
+    /// Text can't be parsed as primitive type
+    #[error("Can't parse `{value}` as `{ty}`")]
+    Parse {
+        #[allow(missing_docs)]
+        ty: &'static str,
+        #[allow(missing_docs)]
+        value: String,
+    },
+    /// There is no prototype to construct value of type with
+    #[error("No prototype to construct `{0}` with")]
+    NoPrototype(&'static str),
+    #[allow(missing_docs)]
+    #[error(transparent)]
+    Construct(#[from] RuntimeConstructError),
+    /// Field of written value can't be accessed
+    #[error(transparent)]
+    Access(#[from] FieldAccessError),
+}
+
+/// Write `value` as XML element, named after it's type
+pub fn to_xml(value: &dyn TypeInfoDynamic) -> Result<String, XmlError> {
+    let mut out = String::new();
+    write_value(&mut out, value.get_dynamic().ident, value)?;
+    Ok(out)
+}
+
+/// Read value of the same type as `prototype` from XML, see [module documentation](self) for lookup of prototypes
+pub fn from_xml(
+    registry: &Registry,
+    prototype: &dyn TypeInfoDynamic,
+    input: &str,
+) -> Result<Box<dyn Any>, XmlError> {
+    let root = Element::parse(input)?;
+    let ty = prototype.get_dynamic();
+    if root.name != ty.ident {
+        return Err(XmlError::UnexpectedElement {
+            expected: ty.ident.to_string(),
+            found: root.name,
+        });
+    }
+    Decoder { registry }.read(ty, Some(prototype), &root)
+}
+
+fn is_attribute(field: &Field) -> bool {
+    field.get_meta(XML_META) == Some("attribute")
+}
+
+fn element_name(id: &FieldId) -> String {
+    match id {
+        FieldId::Named(name) => name.to_string(),
+        FieldId::Index(index) => format!("_{}", index),
+    }
+}
+
+fn escape(text: &str) -> String {
+    quick_xml::escape::escape(text).into_owned()
+}
+
+fn write_value(out: &mut String, name: &str, value: &dyn TypeInfoDynamic) -> Result<(), XmlError> {
+    // wrappers, such as `Box<T>`, are written as the value they wrap
+    let value = value.innermost();
+    // and values behind interior mutability are written while guard is held
+    if let Some(shared) = value.as_shared() {
+        return write_value(out, name, &*shared.read()?);
+    }
+    let ty = value.get_dynamic();
+    if let Some(text) = crate::diff::display_primitive(value) {
+        write!(out, "<{0}>{1}</{0}>", name, escape(&text)).unwrap();
+        return Ok(());
+    }
+
+    match &ty.data {
+        Data::Struct(fields) | Data::Tuple(fields) => write_fields(out, name, ty, value, fields),
+        Data::Enum(_) => {
+            let variant = value.variant().ok_or(XmlError::Unsupported(ty.ident))?;
+            write!(out, "<{}>", name).unwrap();
+            write_fields(out, variant.ident, ty, value, &variant.fields)?;
+            write!(out, "</{}>", name).unwrap();
+            Ok(())
+        }
+        Data::Optional(_) => match value.as_some()? {
+            Some(inner) => write_value(out, name, inner.as_dynamic()),
+            None => Ok(()),
+        },
+        Data::List(_) | Data::Array { .. } => {
+            write!(out, "<{}>", name).unwrap();
+            let mut index = 0;
+            while let Ok(element) = value.field(FieldId::Index(index)) {
+                write_value(out, "item", element.as_dynamic())?;
+                index += 1;
+            }
+            write!(out, "</{}>", name).unwrap();
+            Ok(())
+        }
+        Data::Unit => {
+            write!(out, "<{}/>", name).unwrap();
+            Ok(())
+        }
+        _ => Err(XmlError::Unsupported(ty.ident)),
+    }
+}
+
+fn write_fields(
+    out: &mut String,
+    name: &str,
+    ty: &'static Type,
+    value: &dyn TypeInfoDynamic,
+    fields: &Fields,
+) -> Result<(), XmlError> {
+    let fields = fields.as_slice();
+    write!(out, "<{}", name).unwrap();
+    for field in fields.iter().filter(|field| is_attribute(field)) {
+        let field_value = value.field(field.id.clone())?;
+        if let Some(text) = attribute_text(ty, field, field_value.as_dynamic())? {
+            write!(out, " {}=\"{}\"", element_name(&field.id), escape(&text)).unwrap();
+        }
+    }
+
+    if fields.iter().all(is_attribute) {
+        out.push_str("/>");
+        return Ok(());
+    }
+    out.push('>');
+    for field in fields.iter().filter(|field| !is_attribute(field)) {
+        let field_value = value.field(field.id.clone())?;
+        write_value(out, &element_name(&field.id), field_value.as_dynamic())?;
+    }
+    write!(out, "</{}>", name).unwrap();
+    Ok(())
+}
+
+// text of attribute, `None` is written by omitting attribute, `Some` by it's contents
+fn attribute_text(
+    ty: &'static Type,
+    field: &Field,
+    value: &dyn TypeInfoDynamic,
+) -> Result<Option<String>, XmlError> {
+    let value = value.innermost();
+    if let Some(shared) = value.as_shared() {
+        return attribute_text(ty, field, &*shared.read()?);
+    }
+    if let Data::Optional(_) = value.get_dynamic().data {
+        return match value.as_some()? {
+            Some(inner) => attribute_text(ty, field, inner.as_dynamic()),
+            None => Ok(None),
+        };
+    }
+
+    match crate::diff::display_primitive(value) {
+        Some(text) => Ok(Some(text)),
+        None => Err(XmlError::NotAttribute {
+            ty: ty.ident,
+            field: field.id.clone(),
+        }),
+    }
+}
+
+// Parsed element of input
+struct Element {
+    name: String,
+    attrs: Vec<(String, String)>,
+    children: Vec<Element>,
+    text: String,
+}
+
+impl Element {
+    fn new(start: &BytesStart) -> Result<Self, XmlError> {
+        let attrs = start
+            .attributes()
+            .map(|attr| {
+                let attr = attr.map_err(quick_xml::Error::from)?;
+                Ok((
+                    String::from_utf8_lossy(attr.key.as_ref()).into_owned(),
+                    attr.unescape_value()?.into_owned(),
+                ))
+            })
+            .collect::<Result<Vec<_>, XmlError>>()?;
+        Ok(Self {
+            name: String::from_utf8_lossy(start.name().as_ref()).into_owned(),
+            attrs,
+            children: Vec::new(),
+            text: String::new(),
+        })
+    }
+
+    fn parse(input: &str) -> Result<Self, XmlError> {
+        let mut reader = quick_xml::Reader::from_str(input);
+        // elements, which are not closed yet
+        let mut open: Vec<Element> = Vec::new();
+
+        loop {
+            let closed = match reader.read_event()? {
+                Event::Start(start) => {
+                    open.push(Element::new(&start)?);
+                    None
+                }
+                Event::Empty(start) => Some(Element::new(&start)?),
+                Event::End(_) => open.pop(),
+                Event::Text(text) => {
+                    if let Some(element) = open.last_mut() {
+                        element.text.push_str(&text.unescape()?);
+                    }
+                    None
+                }
+                Event::CData(data) => {
+                    if let Some(element) = open.last_mut() {
+                        element.text.push_str(&String::from_utf8_lossy(&data));
+                    }
+                    None
+                }
+                Event::Eof => {
+                    return Err(XmlError::Syntax(quick_xml::Error::Syntax(
+                        quick_xml::errors::SyntaxError::UnclosedTag,
+                    )))
+                }
+                _ => None,
+            };
+
+            if let Some(element) = closed {
+                match open.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => return Ok(element),
+                }
+            }
+        }
+    }
+
+    fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|child| child.name == name)
+    }
+
+    fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+struct Decoder<'r> {
+    registry: &'r Registry,
+}
+
+impl Decoder<'_> {
+    // registered prototype of `ty`, or `fallback`
+    fn prototype<'p>(
+        &'p self,
+        ty: &'static Type,
+        fallback: Option<&'p dyn TypeInfoDynamic>,
+    ) -> Option<&'p dyn TypeInfoDynamic> {
+        self.registry
+            .get_by_id(ty.type_id)
+            .map(|registration| registration.prototype())
+            .or(fallback)
+    }
+
+    fn read(
+        &self,
+        ty: &'static Type,
+        prototype: Option<&dyn TypeInfoDynamic>,
+        element: &Element,
+    ) -> Result<Box<dyn Any>, XmlError> {
+        if let Data::Primitive = ty.data {
+            return parse(ty, &element.text);
+        }
+        let prototype = self
+            .prototype(ty, prototype)
+            .ok_or(XmlError::NoPrototype(ty.ident))?;
+
+        let value = match &ty.data {
+            Data::Struct(fields) | Data::Tuple(fields) => {
+                let args = self.read_fields(Some(prototype), fields, element)?;
+                prototype.construct_struct(args)?
+            }
+            Data::Enum(variants) => {
+                let (variant, element) = element
+                    .children
+                    .iter()
+                    .find_map(|child| Some((variants.find(&child.name)?, child)))
+                    .ok_or(XmlError::MissingVariant(ty.ident))?;
+                // fields of inactive variant can't be accessed, so prototype is useful only if variant matches
+                let fields_prototype = prototype
+                    .variant()
+                    .filter(|active| active.ident == variant.ident)
+                    .map(|_| prototype);
+                let args = self.read_fields(fields_prototype, &variant.fields, element)?;
+                prototype.construct_enum(variant.ident, args)?
+            }
+            Data::Optional(inner) => {
+                let inner_prototype = prototype.field(FieldId::Index(0)).ok();
+                let inner = self.read(
+                    inner.get(),
+                    inner_prototype.as_ref().map(|inner| inner.as_dynamic()),
+                    element,
+                )?;
+                prototype.construct_enum("Some", vec![inner])?
+            }
+            Data::List(element_ty)
+            | Data::Array {
+                element: element_ty,
+                ..
+            } => {
+                let element_prototype = prototype.field(FieldId::Index(0)).ok();
+                let items = element
+                    .children
+                    .iter()
+                    .map(|item| {
+                        if item.name != "item" {
+                            return Err(XmlError::UnexpectedElement {
+                                expected: "item".to_string(),
+                                found: item.name.clone(),
+                            });
+                        }
+                        self.read(
+                            element_ty.get(),
+                            element_prototype
+                                .as_ref()
+                                .map(|element| element.as_dynamic()),
+                            item,
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                prototype.construct_struct(items)?
+            }
+            Data::Unit => prototype.construct_struct(Vec::new())?,
+            _ => return Err(XmlError::Unsupported(ty.ident)),
+        };
+        Ok(value.into_any())
+    }
+
+    // positional arguments up to the last field, present in `element`
+    fn read_fields(
+        &self,
+        prototype: Option<&dyn TypeInfoDynamic>,
+        fields: &'static Fields,
+        element: &Element,
+    ) -> Result<Vec<Box<dyn Any>>, XmlError> {
+        let mut args = Vec::new();
+        // first field, which is absent, if any
+        let mut absent: Option<&Field> = None;
+
+        for field in fields.as_slice() {
+            let field_prototype =
+                prototype.and_then(|prototype| prototype.field(field.id.clone()).ok());
+            let field_prototype = field_prototype.as_ref().map(|field| field.as_dynamic());
+            let ty = field.ty.get();
+            let name = element_name(&field.id);
+
+            let arg = if is_attribute(field) {
+                element
+                    .attr(&name)
+                    .map(|text| {
+                        let text = Element {
+                            name: name.clone(),
+                            attrs: Vec::new(),
+                            children: Vec::new(),
+                            text: text.to_string(),
+                        };
+                        self.read(ty, field_prototype, &text)
+                    })
+                    .transpose()?
+            } else {
+                element
+                    .child(&name)
+                    .map(|child| self.read(ty, field_prototype, child))
+                    .transpose()?
+            };
+
+            // omitted optional field is `None`, if there is a prototype to construct it with
+            let arg = match (arg, &ty.data) {
+                (None, Data::Optional(_)) => match self.prototype(ty, field_prototype) {
+                    Some(prototype) => {
+                        Some(prototype.construct_enum("None", Vec::new())?.into_any())
+                    }
+                    None => None,
+                },
+                (arg, _) => arg,
+            };
+
+            match (arg, absent) {
+                (Some(_), Some(absent)) => {
+                    return Err(RuntimeConstructError::MissingField(absent.id.clone()).into())
+                }
+                (Some(arg), None) => args.push(arg),
+                (None, None) => absent = Some(field),
+                (None, Some(_)) => {}
+            }
+        }
+        Ok(args)
+    }
+}
+
+fn parse(ty: &'static Type, text: &str) -> Result<Box<dyn Any>, XmlError> {
+    crate::containers::parse_primitive(ty, text)
+        .or_else(|| crate::containers::parse_primitive(ty, text.trim()))
+        .ok_or_else(|| XmlError::Parse {
+            ty: ty.ident,
+            value: text.to_string(),
+        })
+}
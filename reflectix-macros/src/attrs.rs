@@ -0,0 +1,496 @@
+//! Parsing of `#[reflectix(...)]` helper attributes
+
+/// Attributes placed on the type itself
+#[derive(Default)]
+pub struct ContainerAttrs {
+    /// Type is a single-field wrapper, which reflects exactly as it's inner type
+    pub transparent: bool,
+    /// Also generate inherent `reflect_<field>` getters
+    pub typed_accessors: bool,
+    /// Naming policy of fields (for structs) or variants (for enums)
+    pub rename_all: Option<RenameRule>,
+    /// Deny reflective access to non-`pub` fields and construction of types with such fields
+    pub public_only: bool,
+    /// Capture doc comments of type, fields and variants into metadata
+    pub docs: bool,
+    /// Path to `reflectix` crate, used by generated code
+    pub krate: Option<syn::Path>,
+    /// Require every variant to be reflected and generate inherent `from_variant_name`
+    pub deny_unknown_variants: bool,
+    /// Handling of unknown variant names, `None` if not specified
+    pub unknown_variants: Option<UnknownVariants>,
+    /// Foreign type, which layout is mirrored by this one
+    pub remote: Option<syn::Path>,
+    /// Generate metadata only, without constructors and field access
+    pub static_only: bool,
+    /// Function, which builds struct out of it's fields, instead of struct literal
+    pub constructor: Option<syn::ExprPath>,
+    pub meta: Meta,
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+
+        for attr in reflectix_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("transparent") {
+                    parsed.transparent = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("crate") {
+                    let path: syn::LitStr = meta.value()?.parse()?;
+                    parsed.krate = Some(path.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("remote") {
+                    let path: syn::LitStr = meta.value()?.parse()?;
+                    parsed.remote = Some(path.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("static_only") {
+                    parsed.static_only = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("docs") {
+                    parsed.docs = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("public_only") {
+                    parsed.public_only = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("deny_unknown_variants") {
+                    parsed.deny_unknown_variants = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("unknown_variants") || meta.path.is_ident("fallback_variant")
+                {
+                    if parsed.unknown_variants.is_some() {
+                        return Err(meta.error("policy of unknown variants is already specified"));
+                    }
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    parsed.unknown_variants = Some(if meta.path.is_ident("fallback_variant") {
+                        UnknownVariants::Fallback(value.value())
+                    } else {
+                        UnknownVariants::parse(&value)?
+                    });
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("typed_accessors") {
+                    parsed.typed_accessors = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("rename_all") {
+                    let rule: syn::LitStr = meta.value()?.parse()?;
+                    parsed.rename_all = Some(RenameRule::parse(&rule)?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("meta") {
+                    return parsed.meta.parse(meta);
+                }
+
+                if meta.path.is_ident("endian") {
+                    return parse_endian(&meta, &mut parsed.meta);
+                }
+
+                if meta.path.is_ident("constructor") {
+                    let path: syn::LitStr = meta.value()?.parse()?;
+                    parsed.constructor = Some(path.parse()?);
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("tag") {
+                    let tag: syn::LitStr = meta.value()?.parse()?;
+                    return parsed.meta.insert_tag(&meta, &tag.value());
+                }
+
+                Err(meta.error("unsupported reflectix container attribute"))
+            })?;
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Attributes placed on enum variant
+#[derive(Default)]
+pub struct VariantAttrs {
+    /// Variant is hidden from metadata and constructors
+    pub skip: bool,
+    /// Name, under which variant is exposed
+    pub rename: Option<String>,
+    pub meta: Meta,
+}
+
+impl VariantAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+
+        for attr in reflectix_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    parsed.skip = true;
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("rename") {
+                    let name: syn::LitStr = meta.value()?.parse()?;
+                    parsed.rename = Some(name.value());
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("meta") {
+                    return parsed.meta.parse(meta);
+                }
+
+                Err(meta.error("unsupported reflectix variant attribute"))
+            })?;
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// `endian = "big"`, recorded as metadata, which is read by byte views
+fn parse_endian(meta: &syn::meta::ParseNestedMeta, into: &mut Meta) -> syn::Result<()> {
+    let endian: syn::LitStr = meta.value()?.parse()?;
+    match endian.value().as_str() {
+        value @ ("big" | "little" | "native") => into.insert(meta, "endian", value),
+        _ => Err(syn::Error::new_spanned(
+            endian,
+            "expected one of \"big\", \"little\" or \"native\"",
+        )),
+    }
+}
+
+/// Key-value pairs of `meta(key = "value", ...)`, in order of declaration
+#[derive(Default, Clone)]
+pub struct Meta(pub Vec<(String, String)>);
+
+impl Meta {
+    fn parse(&mut self, meta: syn::meta::ParseNestedMeta) -> syn::Result<()> {
+        meta.parse_nested_meta(|entry| {
+            let key = entry
+                .path
+                .get_ident()
+                .ok_or_else(|| entry.error("meta key must be an identifier"))?
+                .to_string();
+            let value: syn::LitStr = entry.value()?.parse()?;
+            self.insert(&entry, &key, &value.value())
+        })
+    }
+
+    fn insert(
+        &mut self,
+        meta: &syn::meta::ParseNestedMeta,
+        key: &str,
+        value: &str,
+    ) -> syn::Result<()> {
+        if self.0.iter().any(|(existing, _)| existing == key) {
+            return Err(meta.error(format!("meta key `{}` is already set", key)));
+        }
+        self.0.push((key.to_string(), value.to_string()));
+        Ok(())
+    }
+
+    /// `tag = "..."` may be repeated, so every tag is separate `tag` entry
+    fn insert_tag(&mut self, meta: &syn::meta::ParseNestedMeta, tag: &str) -> syn::Result<()> {
+        if self
+            .0
+            .iter()
+            .any(|(key, value)| key == "tag" && value == tag)
+        {
+            return Err(meta.error(format!("tag `{}` is already set", tag)));
+        }
+        self.0.push(("tag".to_string(), tag.to_string()));
+        Ok(())
+    }
+}
+
+impl quote::ToTokens for Meta {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let entries = self
+            .0
+            .iter()
+            .map(|(key, value)| quote::quote! {(#key, #value)});
+        tokens.extend(quote::quote! {&[#(#entries),*]});
+    }
+}
+
+/// Value of attribute argument together with it's location
+pub struct Spanned<T> {
+    pub value: T,
+    pub span: proc_macro2::Span,
+}
+
+/// Attributes placed on field of struct or variant
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// Position of field in metadata
+    pub order: Option<Spanned<usize>>,
+    /// Name, under which field is exposed
+    pub rename: Option<String>,
+    /// Value of field, when constructor didn't receive argument for it
+    pub default: Option<FieldDefault>,
+    pub meta: Meta,
+}
+
+/// Source of value for omitted constructor argument
+pub enum FieldDefault {
+    /// `#[reflectix(default)]`: `Default::default()` of field type
+    Trait,
+    /// `#[reflectix(default = "expr")]`
+    Expr(syn::Expr),
+}
+
+impl FieldAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut parsed = Self::default();
+
+        for attr in reflectix_attrs(attrs) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("order") {
+                    let order: syn::LitInt = meta.value()?.parse()?;
+                    parsed.order = Some(Spanned {
+                        value: order.base10_parse()?,
+                        span: order.span(),
+                    });
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("rename") {
+                    let name: syn::LitStr = meta.value()?.parse()?;
+                    parsed.rename = Some(name.value());
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("default") {
+                    parsed.default = Some(if meta.input.peek(syn::Token![=]) {
+                        let expr: syn::LitStr = meta.value()?.parse()?;
+                        FieldDefault::Expr(expr.parse()?)
+                    } else {
+                        FieldDefault::Trait
+                    });
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("meta") {
+                    return parsed.meta.parse(meta);
+                }
+
+                // recorded as metadata, which is what XML bridge looks for
+                if meta.path.is_ident("xml_attr") {
+                    return parsed.meta.insert(&meta, "xml", "attribute");
+                }
+
+                if meta.path.is_ident("endian") {
+                    return parse_endian(&meta, &mut parsed.meta);
+                }
+
+                Err(meta.error("unsupported reflectix field attribute"))
+            })?;
+        }
+
+        Ok(parsed)
+    }
+}
+
+/// Policy of `#[reflectix(unknown_variants = "...")]` and `#[reflectix(fallback_variant = "...")]`
+pub enum UnknownVariants {
+    Error,
+    Skip,
+    /// Exposed name of unit variant
+    Fallback(String),
+}
+
+impl UnknownVariants {
+    fn parse(policy: &syn::LitStr) -> syn::Result<Self> {
+        Ok(match policy.value().as_str() {
+            "error" => Self::Error,
+            "skip" => Self::Skip,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    policy,
+                    "unknown policy, expected `error` or `skip`",
+                ))
+            }
+        })
+    }
+}
+
+/// Case convention of `#[reflectix(rename_all = "...")]`, named as in serde
+#[derive(Clone, Copy)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(rule: &syn::LitStr) -> syn::Result<Self> {
+        Ok(match rule.value().as_str() {
+            "lowercase" => Self::Lower,
+            "UPPERCASE" => Self::Upper,
+            "PascalCase" => Self::Pascal,
+            "camelCase" => Self::Camel,
+            "snake_case" => Self::Snake,
+            "SCREAMING_SNAKE_CASE" => Self::ScreamingSnake,
+            "kebab-case" => Self::Kebab,
+            "SCREAMING-KEBAB-CASE" => Self::ScreamingKebab,
+            _ => return Err(syn::Error::new_spanned(rule, "unknown rename rule")),
+        })
+    }
+
+    /// Convert either `snake_case` field name or `PascalCase` variant name
+    pub fn apply(&self, name: &str) -> String {
+        // words are separated by underscores and by lowercase-to-uppercase boundaries
+        let mut words: Vec<String> = Vec::new();
+        let mut previous_lower = false;
+        for ch in name.chars() {
+            if ch == '_' {
+                words.push(String::new());
+                previous_lower = false;
+                continue;
+            }
+            if words.is_empty() || (ch.is_uppercase() && previous_lower) {
+                words.push(String::new());
+            }
+            previous_lower = ch.is_lowercase() || ch.is_ascii_digit();
+            words.last_mut().unwrap().extend(ch.to_lowercase());
+        }
+        let words = words.into_iter().filter(|word| !word.is_empty());
+
+        let capitalize = |word: String| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        };
+
+        match self {
+            Self::Lower => words.collect::<Vec<_>>().join(""),
+            Self::Upper => words.collect::<Vec<_>>().join("").to_uppercase(),
+            Self::Pascal => words.map(capitalize).collect(),
+            Self::Camel => words
+                .enumerate()
+                .map(|(index, word)| if index == 0 { word } else { capitalize(word) })
+                .collect(),
+            Self::Snake => words.collect::<Vec<_>>().join("_"),
+            Self::ScreamingSnake => words.collect::<Vec<_>>().join("_").to_uppercase(),
+            Self::Kebab => words.collect::<Vec<_>>().join("-"),
+            Self::ScreamingKebab => words.collect::<Vec<_>>().join("-").to_uppercase(),
+        }
+    }
+}
+
+/// Text of doc comments (`///` and `#[doc = "..."]`), one line per attribute
+///
+/// Single space, which follows `///`, is stripped
+pub fn parse_docs(attrs: &[syn::Attribute]) -> String {
+    let lines = attrs.iter().filter_map(|attr| match &attr.meta {
+        syn::Meta::NameValue(syn::MetaNameValue {
+            path,
+            value:
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(text),
+                    ..
+                }),
+            ..
+        }) if path.is_ident("doc") => Some(text.value()),
+        _ => None,
+    });
+
+    lines
+        .map(|line| line.strip_prefix(' ').map(str::to_string).unwrap_or(line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// `#[non_exhaustive]` is built-in attribute, not a `reflectix` one
+pub fn is_non_exhaustive(attrs: &[syn::Attribute]) -> bool {
+    attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("non_exhaustive"))
+}
+
+/// Whether type is `#[repr(C)]`, so it's layout is defined
+pub fn is_repr_c(attrs: &[syn::Attribute]) -> syn::Result<bool> {
+    let mut repr_c = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            repr_c |= meta.path.is_ident("C");
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<proc_macro2::TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr_c)
+}
+
+/// Finds integer type in `#[repr(...)]`, returning matching `DiscriminantRepr` variant
+///
+/// Without explicit integer repr, discriminants are `isize`
+pub fn parse_repr(attrs: &[syn::Attribute]) -> syn::Result<syn::Ident> {
+    const INTEGERS: &[(&str, &str)] = &[
+        ("u8", "U8"),
+        ("u16", "U16"),
+        ("u32", "U32"),
+        ("u64", "U64"),
+        ("u128", "U128"),
+        ("usize", "Usize"),
+        ("i8", "I8"),
+        ("i16", "I16"),
+        ("i32", "I32"),
+        ("i64", "I64"),
+        ("i128", "I128"),
+        ("isize", "Isize"),
+    ];
+
+    let mut repr = syn::Ident::new("Isize", proc_macro2::Span::call_site());
+
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            let found = INTEGERS
+                .iter()
+                .find(|(integer, _)| meta.path.is_ident(integer));
+            if let Some((_, variant)) = found {
+                repr = syn::Ident::new(variant, proc_macro2::Span::call_site());
+            }
+            // `align(N)` and `packed(N)` carry arguments
+            if meta.input.peek(syn::token::Paren) {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<proc_macro2::TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(repr)
+}
+
+fn reflectix_attrs(attrs: &[syn::Attribute]) -> impl Iterator<Item = &syn::Attribute> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("reflectix"))
+}
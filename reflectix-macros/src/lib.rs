@@ -1,5 +1,8 @@
 use proc_macro::TokenStream;
 use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+
+mod attrs;
 
 #[derive(std::hash::Hash, Clone, PartialEq, Eq)]
 enum FieldId {
@@ -24,7 +27,19 @@ impl FieldId {
 
 struct Field {
     id: FieldId,
-    ty_ident: syn::Ident,
+    /// Name of named field, exposed in metadata and matched by `field()`
+    exposed_name: String,
+    meta: attrs::Meta,
+    ty: syn::Type,
+    vis: syn::Visibility,
+    /// Captured doc comment, empty unless `#[reflectix(docs)]` is set
+    docs: String,
+    /// Expression, which fills omitted constructor argument
+    default: Option<proc_macro2::TokenStream>,
+}
+
+fn is_public(field: &Field) -> bool {
+    matches!(field.vis, syn::Visibility::Public(_))
 }
 
 enum Fields {
@@ -82,22 +97,95 @@ impl Fields {
 
 struct Variant {
     name: syn::Ident,
-    discriminator: syn::LitInt,
+    /// Name of variant, exposed in metadata and accepted by constructor
+    exposed_name: String,
+    discriminator: i128,
+    meta: attrs::Meta,
+    docs: String,
     fields: Fields,
+    /// `PhantomData` fields, which are omitted from metadata
+    phantoms: Vec<syn::Member>,
+    non_exhaustive: bool,
 }
 
 struct Variants {
     variants: Vec<Variant>,
+    /// Name of `DiscriminantRepr` variant
+    repr: syn::Ident,
+    contiguous: bool,
 }
 
 enum Data {
     Struct(Fields),
     Enum(Variants),
+    /// Fields of union are recorded, but can't be accessed
+    Union(Fields),
+}
+
+// `PhantomData<T>` carries no data, so such fields are not reflected
+fn is_phantom(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
 }
 
-fn create_meta_fields<'a, I: Iterator<Item = &'a syn::Field>>(fields: I) -> Fields {
+/*
+Fields are kept in declaration order, unless some of them have `#[reflectix(order = N)]`:
+such fields are placed exactly at position N and the rest fill remaining positions in declaration order
+
+`PhantomData` fields are returned separately, so constructors can initialize them
+*/
+fn create_meta_fields<'a, I: Iterator<Item = &'a syn::Field>>(
+    fields: I,
+    rename_all: Option<attrs::RenameRule>,
+    capture_docs: bool,
+) -> syn::Result<(Fields, Vec<syn::Member>)> {
     let mut new_fields = Vec::new();
+    let mut phantoms = Vec::new();
+    let mut explicit_orders = Vec::new();
+    let mut exposed_names = std::collections::HashSet::new();
     for (index, field) in fields.enumerate() {
+        if is_phantom(&field.ty) {
+            phantoms.push(match &field.ident {
+                Some(ident) => syn::Member::Named(ident.clone()),
+                None => syn::Member::Unnamed(index.into()),
+            });
+            continue;
+        }
+
+        let attrs = attrs::FieldAttrs::parse(&field.attrs)?;
+        if let Some(order) = attrs.order {
+            explicit_orders.push((order, new_fields.len()));
+        }
+
+        // explicit `rename` takes precedence over container-wide `rename_all`,
+        // raw identifiers (`r#type`) are exposed without prefix
+        let exposed_name = match (&field.ident, attrs.rename) {
+            (Some(_), Some(rename)) => rename,
+            (Some(ident), None) => match rename_all {
+                Some(rule) => rule.apply(&ident.unraw().to_string()),
+                None => ident.unraw().to_string(),
+            },
+            (None, Some(_)) => {
+                return Err(syn::Error::new_spanned(
+                    field,
+                    "only named fields can be renamed",
+                ))
+            }
+            (None, None) => index.to_string(),
+        };
+        if !exposed_names.insert(exposed_name.clone()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                format!("field name `{}` is already taken", exposed_name),
+            ));
+        }
+
         let field_id = match field.ident.as_ref() {
             Some(str_id) => FieldId::Named(str_id.clone()),
             None => FieldId::Index(syn::LitInt::new(
@@ -106,80 +194,469 @@ fn create_meta_fields<'a, I: Iterator<Item = &'a syn::Field>>(fields: I) -> Fiel
             )),
         };
 
-        let syn::Type::Path(ref type_ident) = field.ty else {
-            panic!("Unsupported field type used in ",)
-        };
+        // any path is accepted (`String`, `std::string::String`, `<T as Trait>::Assoc`),
+        // as well as arrays (`[u8; 16]`), tuples (`(i32, String)`) and static references (`&'static T`),
+        // it's up to the type to implement `TypeInfo`
+        match &field.ty {
+            syn::Type::Path(_) | syn::Type::Array(_) | syn::Type::Tuple(_) => {}
+            syn::Type::Reference(reference)
+                if reference
+                    .lifetime
+                    .as_ref()
+                    .is_some_and(|lifetime| lifetime.ident == "static") => {}
+            syn::Type::Reference(reference) => {
+                return Err(syn::Error::new_spanned(
+                    reference,
+                    "only `'static` references can be reflected, because reflected types must be 'static",
+                ))
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    &field.ty,
+                    "unsupported field type, only path, array, tuple and reference types can be reflected",
+                ))
+            }
+        }
 
-        let Some(type_ident) = type_ident.path.get_ident() else {
-            todo!();
-        };
+        let field_ty = &field.ty;
+        let default = attrs.default.map(|default| match default {
+            attrs::FieldDefault::Trait => quote! {<#field_ty as Default>::default()},
+            attrs::FieldDefault::Expr(expr) => quote! {#expr},
+        });
 
         new_fields.push(Field {
             id: field_id,
-            ty_ident: type_ident.clone(),
+            exposed_name,
+            meta: attrs.meta,
+            ty: field.ty.clone(),
+            vis: field.vis.clone(),
+            docs: if capture_docs {
+                attrs::parse_docs(&field.attrs)
+            } else {
+                String::new()
+            },
+            default,
         });
     }
 
-    match new_fields.first() {
+    let mut positions: Vec<Option<Field>> = (0..new_fields.len()).map(|_| None).collect();
+    let mut declared = new_fields.into_iter().map(Some).collect::<Vec<_>>();
+    for (order, index) in explicit_orders {
+        let field = declared[index].take().unwrap();
+        match positions.get_mut(order.value) {
+            Some(slot @ None) => *slot = Some(field),
+            Some(Some(_)) => {
+                return Err(syn::Error::new(order.span, "field order is already taken"))
+            }
+            None => {
+                return Err(syn::Error::new(
+                    order.span,
+                    "field order must be less than number of fields",
+                ))
+            }
+        }
+    }
+    let mut rest = declared.into_iter().flatten();
+    let new_fields = positions
+        .into_iter()
+        .map(|slot| slot.or_else(|| rest.next()).unwrap())
+        .collect::<Vec<_>>();
+
+    let fields = match new_fields.first() {
         Some(field) => match field.id {
             FieldId::Named(_) => Fields::Named(new_fields),
             FieldId::Index(_) => Fields::Indexed(new_fields),
         },
         None => Fields::Unit,
+    };
+    Ok((fields, phantoms))
+}
+
+// evaluates integer literal discriminants, such as `5` or `-1`
+fn literal_discriminant(expr: &syn::Expr) -> syn::Result<i128> {
+    let out_of_range = || {
+        syn::Error::new_spanned(
+            expr,
+            "discriminant is out of range of `i128`, which reflected discriminants are stored as",
+        )
+    };
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(int),
+            ..
+        }) => int.base10_digits().parse().map_err(|_| out_of_range()),
+        // literal is negated as a whole, since magnitude of `i128::MIN` doesn't fit into `i128`
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr: inner,
+            ..
+        }) => match &**inner {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Int(int),
+                ..
+            }) => format!("-{}", int.base10_digits())
+                .parse()
+                .map_err(|_| out_of_range()),
+            inner => literal_discriminant(inner)?
+                .checked_neg()
+                .ok_or_else(out_of_range),
+        },
+        syn::Expr::Paren(syn::ExprParen { expr, .. })
+        | syn::Expr::Group(syn::ExprGroup { expr, .. }) => literal_discriminant(expr),
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "unsupported discriminant, only integer literals can be reflected",
+        )),
     }
 }
 
-fn create_meta_variants<'a, I: Iterator<Item = &'a syn::Variant>>(variants: I) -> Variants {
+fn create_meta_variants<'a, I: Iterator<Item = &'a syn::Variant>>(
+    variants: I,
+    type_attrs: &[syn::Attribute],
+    rename_all: Option<attrs::RenameRule>,
+    capture_docs: bool,
+) -> syn::Result<Variants> {
     let mut new_variants = Vec::new();
+    let mut exposed_names = std::collections::HashSet::new();
+
+    // implicit discriminant is previous one plus one, starting from zero.
+    // `None` after `i128::MAX`, which can't be followed by implicit discriminant
+    let mut next_discriminant = Some(0i128);
+    let mut contiguous = true;
 
     for (index, variant) in variants.enumerate() {
+        let discriminant = match &variant.discriminant {
+            Some((_, expr)) => literal_discriminant(expr)?,
+            None => next_discriminant
+                .ok_or_else(|| syn::Error::new_spanned(variant, "enum discriminant overflowed"))?,
+        };
+        // first discriminant may start anywhere, others must follow without gaps
+        contiguous &= index == 0 || Some(discriminant) == next_discriminant;
+        next_discriminant = discriminant.checked_add(1);
+
+        let attrs = attrs::VariantAttrs::parse(&variant.attrs)?;
+        if attrs.skip {
+            continue;
+        }
+
         let variant_name = variant.ident.clone();
-        let fields = create_meta_fields(variant.fields.iter());
+        let (fields, phantoms) = create_meta_fields(variant.fields.iter(), None, capture_docs)?;
 
+        let exposed_name = match (attrs.rename, rename_all) {
+            (Some(rename), _) => rename,
+            (None, Some(rule)) => rule.apply(&variant_name.unraw().to_string()),
+            (None, None) => variant_name.unraw().to_string(),
+        };
+        if !exposed_names.insert(exposed_name.clone()) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                format!("variant name `{}` is already taken", exposed_name),
+            ));
+        }
         new_variants.push(Variant {
-            discriminator: syn::LitInt::new(&index.to_string(), variant_name.span()),
+            exposed_name,
+            meta: attrs.meta,
+            docs: if capture_docs {
+                attrs::parse_docs(&variant.attrs)
+            } else {
+                String::new()
+            },
             name: variant_name,
+            discriminator: discriminant,
             fields,
+            phantoms,
+            non_exhaustive: attrs::is_non_exhaustive(&variant.attrs),
         })
     }
 
-    Variants {
+    Ok(Variants {
         variants: new_variants,
-    }
+        repr: attrs::parse_repr(type_attrs)?,
+        contiguous,
+    })
 }
 
 struct MetaType {
     ident: syn::Ident,
     info_ident: syn::Ident,
+    /// Generics of type, with `TypeInfo` bound added to every type parameter
+    generics: syn::Generics,
     data: Data,
+    /// `PhantomData` fields of struct, which are omitted from metadata
+    phantoms: Vec<syn::Member>,
+    non_exhaustive: bool,
+    /// Whether type is `#[repr(C)]`, so offsets of it's fields are captured
+    repr_c: bool,
+    attrs: attrs::ContainerAttrs,
+    /// Captured doc comment of type itself
+    docs: String,
+}
+
+// every type parameter must be reflectable itself, because it may be used as type of field
+fn bound_generics(generics: &syn::Generics) -> syn::Result<syn::Generics> {
+    let mut generics = generics.clone();
+
+    if let Some(lifetime) = generics.lifetimes().next() {
+        return Err(syn::Error::new_spanned(
+            lifetime,
+            "types with lifetime parameters can't be reflected, because reflected types must be 'static",
+        ));
+    }
+
+    let type_params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let where_clause = generics.make_where_clause();
+    for param in type_params {
+        where_clause
+            .predicates
+            .push(syn::parse_quote!(#param: _reflectix::TypeInfo));
+    }
+
+    Ok(generics)
+}
+
+// Rejects skipped variants, when unknown names must be denied, and fallback to non-existent variant
+fn check_unknown_variants(
+    input: &syn::DeriveInput,
+    attrs: &attrs::ContainerAttrs,
+    variants: &Variants,
+) -> syn::Result<()> {
+    let fallback = match &attrs.unknown_variants {
+        Some(attrs::UnknownVariants::Fallback(fallback)) => Some(fallback),
+        _ => None,
+    };
+
+    if attrs.deny_unknown_variants {
+        if matches!(
+            attrs.unknown_variants,
+            Some(attrs::UnknownVariants::Skip | attrs::UnknownVariants::Fallback(_))
+        ) {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`deny_unknown_variants` conflicts with other policy of unknown variants",
+            ));
+        }
+
+        // value of skipped variant has no name, so it would be unknown to everyone reading it
+        let syn::Data::Enum(enum_data) = &input.data else {
+            unreachable!()
+        };
+        for variant in &enum_data.variants {
+            if attrs::VariantAttrs::parse(&variant.attrs)?.skip {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "`deny_unknown_variants` requires every variant to be reflected",
+                ));
+            }
+        }
+    }
+
+    if let Some(fallback) = fallback {
+        let exists = variants.variants.iter().any(|variant| {
+            variant.exposed_name == *fallback && matches!(variant.fields, Fields::Unit)
+        });
+        if !exists {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                format!("fallback variant `{}` is not a unit variant", fallback),
+            ));
+        }
+    }
+    Ok(())
+}
+
+// Wrappers, which store their contents inline, so they don't break recursion of type
+const INLINE_WRAPPERS: &[&str] = &[
+    "Option",
+    "Result",
+    "Cell",
+    "RefCell",
+    "UnsafeCell",
+    "ManuallyDrop",
+    "MaybeUninit",
+    "Mutex",
+    "RwLock",
+];
+
+// Whether `ty` contains type itself without any pointer in between.
+// Such type would have infinite size, which is better reported here, than by overflow of layout computation
+fn contains_inline(ty: &syn::Type, ident: &syn::Ident) -> bool {
+    match ty {
+        syn::Type::Path(path) if path.qself.is_none() => {
+            let Some(last) = path.path.segments.last() else {
+                return false;
+            };
+            // types of the same name from other modules are qualified, so only bare name refers to type itself
+            if path.path.is_ident("Self") || path.path.is_ident(ident) {
+                return true;
+            }
+            if !INLINE_WRAPPERS.iter().any(|wrapper| last.ident == wrapper) {
+                return false;
+            }
+            let syn::PathArguments::AngleBracketed(args) = &last.arguments else {
+                return false;
+            };
+            args.args.iter().any(|arg| match arg {
+                syn::GenericArgument::Type(ty) => contains_inline(ty, ident),
+                _ => false,
+            })
+        }
+        syn::Type::Array(array) => contains_inline(&array.elem, ident),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().any(|ty| contains_inline(ty, ident)),
+        syn::Type::Paren(paren) => contains_inline(&paren.elem, ident),
+        syn::Type::Group(group) => contains_inline(&group.elem, ident),
+        _ => false,
+    }
+}
+
+// Rejects fields, which contain type itself without indirection
+fn check_cycles(input: &syn::DeriveInput) -> syn::Result<()> {
+    // fields together with their positions in struct or variant
+    let fields: Vec<(usize, &syn::Field)> = match &input.data {
+        syn::Data::Struct(data) => data.fields.iter().enumerate().collect(),
+        syn::Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter().enumerate())
+            .collect(),
+        syn::Data::Union(data) => data.fields.named.iter().enumerate().collect(),
+    };
+
+    for (index, field) in fields {
+        if contains_inline(&field.ty, &input.ident) {
+            let name = match &field.ident {
+                Some(name) => name.to_string(),
+                None => index.to_string(),
+            };
+            return Err(syn::Error::new_spanned(
+                &field.ty,
+                format!(
+                    "field `{}` contains `{}` without indirection, so `{}` would have infinite size; \
+                     wrap it into `Box`, `Vec` or other pointer type",
+                    name, input.ident, input.ident
+                ),
+            ));
+        }
+    }
+    Ok(())
 }
 
 impl MetaType {
-    pub fn new(input: &syn::DeriveInput) -> Self {
+    pub fn new(input: &syn::DeriveInput) -> syn::Result<Self> {
         let ident = input.ident.clone();
+        let attrs = attrs::ContainerAttrs::parse(&input.attrs)?;
+        check_cycles(input)?;
 
+        let mut phantoms = Vec::new();
         let meta_data = match &input.data {
             syn::Data::Struct(syn::DataStruct { fields, .. }) => {
                 let fields_iter = match fields {
-                    syn::Fields::Named(named) => create_meta_fields(named.named.iter()),
-                    syn::Fields::Unnamed(unnamed) => create_meta_fields(unnamed.unnamed.iter()),
-                    syn::Fields::Unit => Fields::Unit,
+                    syn::Fields::Named(named) => {
+                        create_meta_fields(named.named.iter(), attrs.rename_all, attrs.docs)?
+                    }
+                    syn::Fields::Unnamed(unnamed) => {
+                        create_meta_fields(unnamed.unnamed.iter(), attrs.rename_all, attrs.docs)?
+                    }
+                    syn::Fields::Unit => (Fields::Unit, Vec::new()),
                 };
-                Data::Struct(fields_iter)
+                phantoms = fields_iter.1;
+                Data::Struct(fields_iter.0)
             }
-            syn::Data::Enum(enum_data) => {
-                Data::Enum(create_meta_variants(enum_data.variants.iter()))
+            syn::Data::Enum(enum_data) => Data::Enum(create_meta_variants(
+                enum_data.variants.iter(),
+                &input.attrs,
+                attrs.rename_all,
+                attrs.docs,
+            )?),
+            syn::Data::Union(union_data) => {
+                let (fields, _) = create_meta_fields(
+                    union_data.fields.named.iter(),
+                    attrs.rename_all,
+                    attrs.docs,
+                )?;
+                Data::Union(fields)
             }
-            syn::Data::Union(_) => panic!("Unions are not supported"),
         };
 
-        let info_ident = format_ident!("{}_TYPE_INFO", ident.to_string().to_ascii_uppercase());
+        if let Some(constructor) = &attrs.constructor {
+            if !matches!(&meta_data, Data::Struct(_)) {
+                return Err(syn::Error::new_spanned(
+                    constructor,
+                    "`#[reflectix(constructor)]` is supported only for structs",
+                ));
+            }
+        }
+
+        if attrs.typed_accessors && !matches!(&meta_data, Data::Struct(_)) {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`#[reflectix(typed_accessors)]` is supported only for structs",
+            ));
+        }
+
+        let is_enum = matches!(&meta_data, Data::Enum(_));
+        if (attrs.deny_unknown_variants || attrs.unknown_variants.is_some()) && !is_enum {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "policy of unknown variants is supported only for enums",
+            ));
+        }
+        if let Data::Enum(variants) = &meta_data {
+            check_unknown_variants(input, &attrs, variants)?;
+        }
+
+        if attrs.remote.is_some() && matches!(&meta_data, Data::Union(_)) {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`#[reflectix(remote)]` is not supported for unions",
+            ));
+        }
+
+        if attrs.transparent && attrs.static_only {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`#[reflectix(static_only)]` can't be combined with `#[reflectix(transparent)]`",
+            ));
+        }
 
-        Self {
+        if attrs.transparent && attrs.remote.is_some() {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`#[reflectix(remote)]` can't be combined with `#[reflectix(transparent)]`",
+            ));
+        }
+
+        if attrs.transparent
+            && !matches!(&meta_data, Data::Struct(fields) if fields.iter().len() == 1)
+        {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "`#[reflectix(transparent)]` requires struct with exactly one field",
+            ));
+        }
+
+        let info_ident = format_ident!(
+            "{}_TYPE_INFO",
+            ident.unraw().to_string().to_ascii_uppercase()
+        );
+
+        Ok(Self {
             ident,
             data: meta_data,
+            phantoms,
+            non_exhaustive: attrs::is_non_exhaustive(&input.attrs),
+            repr_c: attrs::is_repr_c(&input.attrs)?,
             info_ident,
-        }
+            generics: bound_generics(&input.generics)?,
+            docs: if attrs.docs {
+                attrs::parse_docs(&input.attrs)
+            } else {
+                String::new()
+            },
+            attrs,
+        })
     }
 }
 
@@ -189,36 +666,80 @@ mod gen {
     use quote::quote;
     use quote::quote_spanned;
     use quote::ToTokens;
+    use syn::ext::IdentExt;
 
+    use super::is_public;
     use super::FieldId;
     use crate::Variants;
 
     use super::Fields;
     use super::MetaType;
 
-    use std::collections::HashMap;
+    // fields of enum variants are always public, whatever their syntactic visibility is
+    fn visibility_to_tokens(field: &crate::Field, in_variant: bool) -> proc_macro2::TokenStream {
+        match &field.vis {
+            _ if in_variant => quote! {_reflectix::Visibility::Public},
+            syn::Visibility::Public(_) => quote! {_reflectix::Visibility::Public},
+            syn::Visibility::Restricted(restricted) if !restricted.path.is_ident("self") => {
+                quote! {_reflectix::Visibility::Restricted}
+            }
+            _ => quote! {_reflectix::Visibility::Private},
+        }
+    }
+
+    // references share metadata with their referents, so only the way field holds value is recorded
+    fn ownership_to_tokens(field: &crate::Field) -> proc_macro2::TokenStream {
+        match &field.ty {
+            syn::Type::Reference(reference) if reference.mutability.is_some() => {
+                quote! {_reflectix::Ownership::Mutable}
+            }
+            syn::Type::Reference(_) => quote! {_reflectix::Ownership::Shared},
+            _ => quote! {_reflectix::Ownership::Owned},
+        }
+    }
+
+    /// `container` is the struct, offsets of fields are captured within
+    fn collect_fields(
+        fields: &Fields,
+        in_variant: bool,
+        container: Option<&proc_macro2::TokenStream>,
+    ) -> proc_macro2::TokenStream {
+        let offset = |member: proc_macro2::TokenStream| match container {
+            Some(container) => quote! {Some(::std::mem::offset_of!(#container, #member))},
+            None => quote! {None},
+        };
 
-    fn collect_fields(fields: &Fields) -> proc_macro2::TokenStream {
         match fields {
             Fields::Named(named) => {
                 let mut fields_definition = Vec::new();
                 for field in named.iter() {
-                    let crate::FieldId::Named(ref ident) = field.id else {
-                        unreachable!()
-                    };
-                    let name = ident.to_string();
-                    let type_ident = field.ty_ident.clone();
+                    let name = &field.exposed_name;
+                    let type_ident = field.ty.clone();
+                    let meta = &field.meta;
+                    let vis = visibility_to_tokens(field, in_variant);
+                    let ownership = ownership_to_tokens(field);
+                    let has_default = field.default.is_some();
+                    let docs = &field.docs;
+                    let member = field.id.as_named();
+                    let offset = offset(quote! {#member});
 
                     fields_definition.push(quote! {
-                        reflectix_core::Field {
-                            id: reflectix_core::FieldId::Named(#name),
-                            ty: <#type_ident as reflectix_core::TypeInfo>::INFO,
+                        _reflectix::Field {
+                            id: _reflectix::FieldId::Named(#name),
+                            ty: _reflectix::TypeRef::of::<#type_ident>(),
+                            type_id: std::any::TypeId::of::<#type_ident>(),
+                            meta: #meta,
+                            vis: #vis,
+                            ownership: #ownership,
+                            docs: #docs,
+                            offset: #offset,
+                            has_default: #has_default,
                         }
                     });
                 }
 
                 quote! {
-                    reflectix_core::Fields::Named(&[#(#fields_definition),*])
+                    _reflectix::Fields::Named(&[#(#fields_definition),*])
 
                 }
             }
@@ -228,84 +749,191 @@ mod gen {
                     let FieldId::Index(ref ident) = field.id else {
                         unreachable!()
                     };
-                    let type_ident = field.ty_ident.clone();
+                    let type_ident = field.ty.clone();
+                    let meta = &field.meta;
+                    let vis = visibility_to_tokens(field, in_variant);
+                    let ownership = ownership_to_tokens(field);
+                    let has_default = field.default.is_some();
+                    let docs = &field.docs;
+                    let offset = offset(quote! {#ident});
 
                     fields_definition.push(quote! {
-                        reflectix_core::Field {
-                            id: reflectix_core::FieldId::Indexed(#ident),
-                            ty: <#type_ident as reflectix_core::TypeInfo>::INFO,
+                        _reflectix::Field {
+                            id: _reflectix::FieldId::Index(#ident),
+                            ty: _reflectix::TypeRef::of::<#type_ident>(),
+                            type_id: std::any::TypeId::of::<#type_ident>(),
+                            meta: #meta,
+                            vis: #vis,
+                            ownership: #ownership,
+                            docs: #docs,
+                            offset: #offset,
+                            has_default: #has_default,
                         }
                     });
                 }
 
                 quote! {
-                    reflectix_core::Fields::Indexed(&[#(#fields_definition),*])
+                    _reflectix::Fields::Indexed(&[#(#fields_definition),*])
 
                 }
             }
-            Fields::Unit => quote! {reflectix_core::Fields::Unit},
+            Fields::Unit => quote! {_reflectix::Fields::Unit},
         }
     }
 
-    fn collect_variants(variants: &Variants) -> proc_macro2::TokenStream {
+    fn collect_variants(
+        variants: &Variants,
+        unknown: Option<&crate::attrs::UnknownVariants>,
+    ) -> proc_macro2::TokenStream {
         let mut variants_list = Vec::new();
+        let repr = &variants.repr;
+        let contiguous = variants.contiguous;
 
-        for (discriminator, variant) in variants.variants.iter().enumerate() {
-            let variant_name = variant.name.to_string();
-            let fields_stmt = collect_fields(&variant.fields);
+        for variant in variants.variants.iter() {
+            let variant_name = &variant.exposed_name;
+            let fields_stmt = collect_fields(&variant.fields, true, None);
+            // evaluated value of discriminant, not position of variant, see `Variant::discriminator`
+            let discriminator = &variant.discriminator;
+            let meta = &variant.meta;
+            let docs = &variant.docs;
+            let non_exhaustive = variant.non_exhaustive;
 
             variants_list.push(quote! {
-                reflectix_core::Variant {
+                _reflectix::Variant {
                     ident: #variant_name,
+                    discriminator: #discriminator,
                     fields: #fields_stmt,
-                    discriminator: #discriminator
+                    meta: #meta,
+                    docs: #docs,
+                    non_exhaustive: #non_exhaustive,
                 }
             });
         }
 
+        let unknown = match unknown {
+            None | Some(crate::attrs::UnknownVariants::Error) => quote! {Error},
+            Some(crate::attrs::UnknownVariants::Skip) => quote! {Skip},
+            Some(crate::attrs::UnknownVariants::Fallback(name)) => quote! {Fallback(#name)},
+        };
+
         quote! {
-            reflectix_core::Variants{variants: &[#(#variants_list),*]}
+            _reflectix::Variants{
+                variants: &[#(#variants_list),*],
+                repr: _reflectix::DiscriminantRepr::#repr,
+                contiguous: #contiguous,
+                unknown: _reflectix::UnknownVariants::#unknown,
+            }
         }
     }
 
-    pub fn create_const_definition(meta: &MetaType) -> proc_macro2::TokenStream {
+    /*
+    Metadata of non-generic types is stored in static, so that all references to it have the same address.
+    Generic types can't have statics, so their metadata is evaluated (and interned) per monomorphization
+    right inside of associated `INFO` constant
+
+    Returns definition of static, if there is one, and expression of metadata reference
+    */
+    pub fn create_info_definition(
+        meta: &MetaType,
+    ) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let type_definition = create_type_definition(meta);
+
+        if !meta.generics.params.is_empty() {
+            return (quote! {}, quote! {&#type_definition});
+        }
+
+        let const_ident = &meta.info_ident;
+        let const_type_info_stmt = quote_spanned! {proc_macro2::Span::mixed_site()=>
+          static #const_ident: _reflectix::Type = #type_definition;
+        };
+        (const_type_info_stmt, quote! {&#const_ident})
+    }
+
+    fn create_type_definition(meta: &MetaType) -> proc_macro2::TokenStream {
         let data_definition = match &meta.data {
             crate::Data::Struct(fields) => {
-                let fields = collect_fields(&fields);
+                let rust_ident = &meta.ident;
+                let (_, ty_generics, _) = meta.generics.split_for_impl();
+                let container = quote! {#rust_ident #ty_generics};
+                let fields = collect_fields(fields, false, meta.repr_c.then_some(&container));
                 quote! {
-                    reflectix_core::Data::Struct(#fields)
+                    _reflectix::Data::Struct(#fields)
                 }
             }
             crate::Data::Enum(variants) => {
-                let variants = collect_variants(&variants);
+                let variants = collect_variants(variants, meta.attrs.unknown_variants.as_ref());
+                quote! {
+                    _reflectix::Data::Enum(#variants)
+                }
+            }
+            crate::Data::Union(fields) => {
+                let fields = collect_fields(fields, false, None);
                 quote! {
-                    reflectix_core::Data::Enum(#variants)
+                    _reflectix::Data::Union(#fields)
                 }
             }
         };
 
-        let const_ident = &meta.info_ident;
-        let ty_ident = meta.ident.to_string();
-
-        let const_type_info_stmt = quote_spanned! {proc_macro2::Span::mixed_site()=>
-          const #const_ident: reflectix_core::Type = reflectix_core::Type {
-              ident: #ty_ident,
-              data: #data_definition,
-          };
+        // mirror of foreign type is exposed under name of the type it mirrors
+        let ty_ident = match &meta.attrs.remote {
+            Some(remote) => remote.segments.last().unwrap().ident.unraw().to_string(),
+            None => meta.ident.unraw().to_string(),
         };
-        const_type_info_stmt
+        let type_meta = &meta.attrs.meta;
+        let docs = &meta.docs;
+        let non_exhaustive = meta.non_exhaustive;
+        let rust_ident = &meta.ident;
+        let (_, ty_generics, _) = meta.generics.split_for_impl();
+        let generics = meta.generics.params.iter().map(|param| match param {
+            syn::GenericParam::Type(param) => {
+                let ident = &param.ident;
+                let name = ident.unraw().to_string();
+                quote! {
+                    _reflectix::GenericParamInfo {
+                        name: #name,
+                        kind: _reflectix::GenericParamKind::Type(_reflectix::TypeRef::of::<#ident>()),
+                    }
+                }
+            }
+            syn::GenericParam::Const(param) => {
+                let ident = &param.ident;
+                let name = ident.unraw().to_string();
+                quote! {
+                    _reflectix::GenericParamInfo {
+                        name: #name,
+                        kind: _reflectix::GenericParamKind::Const(#ident as i128),
+                    }
+                }
+            }
+            // derived types are 'static, so `bound_generics` rejects lifetime parameters up front
+            syn::GenericParam::Lifetime(_) => unreachable!("lifetime parameters are rejected"),
+        });
+
+        quote! {
+            _reflectix::Type {
+                ident: #ty_ident,
+                data: #data_definition,
+                meta: #type_meta,
+                docs: #docs,
+                non_exhaustive: #non_exhaustive,
+                generics: &[#(#generics),*],
+                type_id: ::std::any::TypeId::of::<#rust_ident #ty_generics>(),
+                size: ::std::mem::size_of::<#rust_ident #ty_generics>(),
+                align: ::std::mem::align_of::<#rust_ident #ty_generics>(),
+            }
+        }
     }
 
-    fn field_id_to_tokens(id: &FieldId) -> proc_macro2::TokenStream {
-        match id {
-            FieldId::Named(ident) => {
-                let as_str = ident.to_string();
+    fn field_id_to_tokens(field: &crate::Field) -> proc_macro2::TokenStream {
+        match &field.id {
+            FieldId::Named(_) => {
+                let as_str = &field.exposed_name;
                 quote! {
-                    reflectix_core::FieldId::Named(#as_str)
+                    _reflectix::FieldId::Named(#as_str)
                 }
             }
             FieldId::Index(index) => quote! {
-                reflectix_core::FieldId::Index(#index)
+                _reflectix::FieldId::Index(#index)
             },
         }
     }
@@ -316,11 +944,12 @@ mod gen {
         fields: &Fields,
         is_mut_ref: bool,
         is_accessing_tuple_enum_variant: bool,
+        public_only: bool,
     ) -> proc_macro2::TokenStream {
-        let ref_producer = |ident: &syn::Ident| {
+        let ref_producer = |ident: &proc_macro2::TokenStream| {
             let field_ident = match self_ident {
                 Some(self_ident) => quote! {#self_ident.#ident},
-                None => ident.to_token_stream(),
+                None => ident.clone(),
             };
 
             // match is_mut_ref {
@@ -338,20 +967,15 @@ mod gen {
         let mut arms = Vec::new();
 
         for field in fields.iter() {
-            let field_id_as_tokens = field_id_to_tokens(&field.id);
+            let field_id_as_tokens = field_id_to_tokens(field);
 
             let attr_access_name = match &field.id {
-                FieldId::Named(ident) => ident.clone(),
-                FieldId::Index(index) => {
-                    // tuple-emum field names are prefixed with _ to make them valid idents
-                    let prefix = if is_accessing_tuple_enum_variant {
-                        "_"
-                    } else {
-                        ""
-                    }
-                    .to_string();
-                    syn::Ident::new(&(prefix + &index.clone().to_string()), index.span())
+                FieldId::Named(ident) => ident.to_token_stream(),
+                // tuple-emum field names are prefixed with _ to make them valid idents
+                FieldId::Index(index) if is_accessing_tuple_enum_variant => {
+                    format_ident!("_{}", index.to_string(), span = index.span()).to_token_stream()
                 }
+                FieldId::Index(index) => index.to_token_stream(),
             };
 
             let pattern = quote! {
@@ -359,7 +983,7 @@ mod gen {
             };
             let mut field_ref = ref_producer(&attr_access_name);
 
-            let field_ty_ident = &field.ty_ident;
+            let field_ty = &field.ty;
 
             // need to extend lifetime
             //
@@ -376,17 +1000,14 @@ mod gen {
             }
 
             let caster_block = match is_mut_ref {
+                _ if public_only && !is_public(field) => quote! {
+                    return Err(_reflectix::FieldAccessError::Private);
+                },
                 true => quote! {
-                    let field_ref = (#field_ref as *mut #field_ty_ident) as *mut ();
-                    let target_id = std::any::TypeId::of::<#field_ty_ident>();
-
-                    return Ok(reflectix_core::UnsizeableMut::new(field_ref, target_id));
+                    return Ok(_reflectix::UnsizeableMut::new::<#field_ty>(#field_ref));
                 },
                 false => quote! {
-                    let field_ref = (#field_ref as *const #field_ty_ident) as *const ();
-                    let target_id = std::any::TypeId::of::<#field_ty_ident>();
-
-                    return Ok(reflectix_core::Unsizeable::new(field_ref, target_id));
+                    return Ok(_reflectix::Unsizeable::new::<#field_ty>(#field_ref));
                 },
             };
 
@@ -400,7 +1021,7 @@ mod gen {
             match #input_id_ident {
                 #(#patterns => {#arms})*
                 _ => {
-                    return Err(reflectix_core::FieldAccessError::NotFound);
+                    return Err(_reflectix::FieldAccessError::NotFound);
                 }
             }
         }
@@ -431,7 +1052,7 @@ mod gen {
                         .collect::<Vec<_>>();
 
                     quote! {
-                        Self::#variant_name{#(#inplace_ref_type #all_fields_idents),*}
+                        Self::#variant_name{#(#inplace_ref_type #all_fields_idents,)* ..}
                     }
                 }
                 Fields::Indexed(indexed) => {
@@ -440,36 +1061,34 @@ mod gen {
                         // prefixing enum fields indexes with underscore to make them valid idents
                         .map(|x| {
                             syn::Ident::new(
-                                &format!("_{}", x.id.as_indexed().to_string()),
-                                x.ty_ident.span(),
+                                &format!("_{}", x.id.as_indexed()),
+                                x.id.as_indexed().span(),
                             )
                         })
                         .collect::<Vec<_>>();
+                    let indexes = indexed.iter().map(|x| x.id.as_indexed());
 
-                    match is_mut_ref {
-                        true => quote! {
-                            Self::#variant_name(#(ref mut #all_fields_idents),*)
-                        },
-                        false => quote! {
-                            Self::#variant_name(#(ref #all_fields_idents),*)
-                        },
+                    // braced pattern skips `PhantomData` fields, wherever they are
+                    quote! {
+                        Self::#variant_name{#(#indexes: #inplace_ref_type #all_fields_idents,)* ..}
                     }
                 }
-                Fields::Unit => quote! {Self::#variant_name},
+                Fields::Unit => quote! {Self::#variant_name{..}},
             };
 
             let arm = match &variant.fields {
                 iterable_fields @ (Fields::Named(_) | Fields::Indexed(_)) => {
                     create_dyn_field_access_match(
                         None,
-                        &input_id_ident,
+                        input_id_ident,
                         iterable_fields,
                         is_mut_ref,
                         true,
+                        false,
                     )
                 }
                 Fields::Unit => quote! {
-                    return Err(reflectix_core::FieldAccessError::Unit);
+                    return Err(_reflectix::FieldAccessError::Unit);
                 },
             };
 
@@ -477,11 +1096,13 @@ mod gen {
             arms.push(arm);
         }
 
+        // wildcard arm catches skipped variants
         quote! {
+            #[allow(unreachable_patterns)]
             match #self_ident {
                 #(#patterns => {#arms})*
                 _ => {
-                    return Err(reflectix_core::FieldAccessError::UnmatchingDiscriminator);
+                    return Err(_reflectix::FieldAccessError::NotFound);
                 }
             }
         }
@@ -495,16 +1116,22 @@ mod gen {
         let id_ident = syn::Ident::new("id", proc_macro2::Span::call_site());
         let self_ident = syn::Ident::new("self", proc_macro2::Span::call_site());
 
-        let body = match meta.data {
-            crate::Data::Struct(ref fields) => {
-                create_dyn_field_access_match(Some(&self_ident), &id_ident, fields, is_mut, false)
-            }
+        match meta.data {
+            crate::Data::Struct(ref fields) => create_dyn_field_access_match(
+                Some(&self_ident),
+                &id_ident,
+                fields,
+                is_mut,
+                false,
+                meta.attrs.public_only,
+            ),
             crate::Data::Enum(ref variants) => {
                 create_dyn_variant_access_match(&self_ident, &id_ident, variants, is_mut)
             }
-        };
-
-        body
+            crate::Data::Union(_) => quote! {
+                return Err(_reflectix::FieldAccessError::Union);
+            },
+        }
     }
 
     /*
@@ -522,75 +1149,106 @@ mod gen {
         type_ident: &proc_macro2::TokenStream,
         args_ident: &syn::Ident,
         fields: &Fields,
+        phantoms: &[syn::Member],
+        variant: Option<&str>,
+        constructor: Option<&syn::ExprPath>,
     ) -> proc_macro2::TokenStream {
+        // user's constructor receives fields in order of metadata, and it's error is passed through
+        let custom = |values: &[syn::Ident]| {
+            constructor.map(|constructor| {
+                quote! {
+                    #constructor(#(#values),*).map_err(_reflectix::RuntimeConstructError::custom)?
+                }
+            })
+        };
+        let variant = match variant {
+            Some(variant) => quote! {Some(#variant)},
+            None => quote! {None},
+        };
+
         match fields {
             fields @ (Fields::Named(..) | Fields::Indexed(..)) => {
+                let fields_count = fields.iter().len();
+                // trailing fields with defaults may be omitted
+                let required_count = fields
+                    .iter()
+                    .rposition(|field| field.default.is_none())
+                    .map_or(0, |position| position + 1);
                 let mut field_downcast_stmts = Vec::new();
-                let mut field_identifiers = HashMap::new();
+                let mut field_values = Vec::new();
                 for (index, field) in fields.iter().enumerate().rev() {
                     let curr_box_ident = format_ident!("boxed_{}", { index });
 
-                    let current_type = field.ty_ident.clone();
-                    let current_type_str = format!("{}", current_type);
+                    let current_type = &field.ty;
+                    let current_field_id = field_id_to_tokens(field);
 
-                    let downcast_stmt = quote! {
-                        let #curr_box_ident = #args_ident.pop().ok_or(reflectix_core::RuntimeConstructError::NotEnoughArgs)?;
-                        let #curr_box_ident = #curr_box_ident.downcast::<#current_type>().map_err(|_| reflectix_core::RuntimeConstructError::UnexpectedType{index: #index, expected: #current_type_str})?;
-
-                        let #curr_box_ident = unsafe {
-                            let as_raw = Box::into_raw(#curr_box_ident);
-                            let new_item = std::ptr::read(as_raw);
-                            std::mem::drop(Box::from_raw(as_raw));
-                            new_item
-                        };
+                    let downcast = quote! {
+                        *#args_ident.pop().ok_or(_reflectix::RuntimeConstructError::NotEnoughArgs)?.downcast::<#current_type>().map_err(|_| _reflectix::RuntimeConstructError::UnexpectedType{
+                            index: #index,
+                            expected: <#current_type as _reflectix::TypeInfo>::INFO.ident,
+                            field: #current_field_id,
+                            variant: #variant,
+                        })?
+                    };
+                    let downcast_stmt = match &field.default {
+                        Some(default) if index >= required_count => quote! {
+                            let #curr_box_ident: #current_type = if #index < provided_count {
+                                #downcast
+                            } else {
+                                #default
+                            };
+                        },
+                        _ => quote! {
+                            let #curr_box_ident = #downcast;
+                        },
                     };
 
                     field_downcast_stmts.push(downcast_stmt);
-                    field_identifiers.insert(field.id.clone(), curr_box_ident);
+                    field_values.push(curr_box_ident);
                 }
-
-                let is_indexed = fields
-                    .iter()
-                    .all(|x| matches!(x.id, crate::FieldId::Index(_)));
-
-                match is_indexed {
-                    true => {
-                        let keys = field_identifiers
-                            .keys()
-                            .map(FieldId::as_indexed)
-                            .collect::<Vec<_>>();
-                        quote! {
-                            #(#field_downcast_stmts)*
-
-                            return Ok(Box::new(#type_ident(#(#keys),*)));
+                field_values.reverse();
+
+                // braced initializer works for tuple-like types too (`Self { 0: a, 1: b }`),
+                // so order of fields in metadata may differ from declaration order
+                let keys = fields.iter().map(|x| match &x.id {
+                    FieldId::Named(ident) => ident.to_token_stream(),
+                    FieldId::Index(index) => index.to_token_stream(),
+                });
+                let value = custom(&field_values).unwrap_or_else(|| {
+                    quote! {
+                        #type_ident{
+                            #(#keys: #field_values,)*
+                            #(#phantoms: ::core::marker::PhantomData,)*
                         }
                     }
-                    false => {
-                        let mut keys = Vec::new();
-                        let mut values = Vec::new();
-
-                        for (key, value) in field_identifiers.drain() {
-                            values.push(value);
-
-                            let crate::FieldId::Named(key) = key else {
-                                unreachable!()
-                            };
-                            let key =
-                                syn::Ident::new(&key.to_string(), proc_macro2::Span::call_site());
-                            keys.push(key);
-                        }
+                });
 
-                        quote! {
-                            #(#field_downcast_stmts)*
+                // args are popped from the end, so count must be checked upfront,
+                // otherwise missing or extra args would shift the rest
+                quote! {
+                    let provided_count = #args_ident.len();
+                    if provided_count < #required_count {
+                        return Err(_reflectix::RuntimeConstructError::NotEnoughArgs);
+                    }
+                    if provided_count > #fields_count {
+                        return Err(_reflectix::RuntimeConstructError::TooManyArgs);
+                    }
+                    #(#field_downcast_stmts)*
 
-                            return Ok(Box::new(#type_ident{#(#keys: #values),*}));
-                        }
+                    return Ok(_reflectix::UnsizeableOwned::new(#value));
+                }
+            }
+            Fields::Unit => {
+                let value = custom(&[]).unwrap_or_else(|| {
+                    quote! {#type_ident{#(#phantoms: ::core::marker::PhantomData),*}}
+                });
+                quote! {
+                    if !#args_ident.is_empty() {
+                        return Err(_reflectix::RuntimeConstructError::TooManyArgs);
                     }
+                    return Ok(_reflectix::UnsizeableOwned::new(#value));
                 }
             }
-            Fields::Unit => quote! {
-                return Ok(Box::new(#type_ident));
-            },
         }
     }
 
@@ -598,7 +1256,7 @@ mod gen {
     //         &self,
     //         variant: &'static str,
     //         args: Vec<Box<dyn Any>>,
-    //     ) -> Result<Box<dyn Any>, RuntimeConstructError>;
+    //     ) -> Result<UnsizeableOwned, RuntimeConstructError>;
     pub fn create_dyn_enum_ctor(meta: &MetaType) -> proc_macro2::TokenStream {
         let args_ident = syn::Ident::new("args", proc_macro2::Span::call_site());
         let requested_variant_ident = syn::Ident::new("variant", proc_macro2::Span::call_site());
@@ -606,23 +1264,32 @@ mod gen {
 
         let body = match &meta.data {
             crate::Data::Struct(_) => quote! {
-                return Err(reflectix_core::RuntimeConstructError::NotEnum);
+                return Err(_reflectix::RuntimeConstructError::NotEnum);
+            },
+            crate::Data::Union(_) => quote! {
+                return Err(_reflectix::RuntimeConstructError::Union);
             },
             crate::Data::Enum(variants) => {
                 let mut patterns = Vec::new();
                 let mut bodies = Vec::new();
                 for variant in variants.variants.iter() {
                     let variant_name_ident = &variant.name;
-                    let ctor_body = match &variant.fields {
-                        fields @ (Fields::Named(_) | Fields::Indexed(_)) => {
-                            let variant_ty_ident = quote! {#self_ty_ident::#variant_name_ident};
-                            create_dyn_fields_ctor_body(&variant_ty_ident, &args_ident, &fields)
+                    let variant_ty_ident = quote! {#self_ty_ident::#variant_name_ident};
+                    let ctor_body = if meta.attrs.remote.is_some() && variant.non_exhaustive {
+                        quote! {
+                            return Err(_reflectix::RuntimeConstructError::NonExhaustive);
                         }
-                        Fields::Unit => quote! {
-                            return Ok(Box::new(#self_ty_ident::#variant_name_ident));
-                        },
+                    } else {
+                        create_dyn_fields_ctor_body(
+                            &variant_ty_ident,
+                            &args_ident,
+                            &variant.fields,
+                            &variant.phantoms,
+                            Some(&variant.exposed_name),
+                            None,
+                        )
                     };
-                    let variant_name_str = variant.name.to_string();
+                    let variant_name_str = &variant.exposed_name;
                     let pattern = quote! {
                          #variant_name_str
                     };
@@ -637,7 +1304,7 @@ mod gen {
                         })*
 
                         _ => {
-                            return Err(reflectix_core::RuntimeConstructError::InvalidVariant);
+                            return Err(_reflectix::RuntimeConstructError::InvalidVariant);
                         }
                     }
                 };
@@ -651,7 +1318,7 @@ mod gen {
                 &self,
                 #requested_variant_ident: &'static str,
                 mut #args_ident: Vec<Box<dyn std::any::Any>>,
-            ) -> Result<Box<dyn std::any::Any>, reflectix_core::RuntimeConstructError> {
+            ) -> Result<_reflectix::UnsizeableOwned, _reflectix::RuntimeConstructError> {
                 #body
             }
 
@@ -661,79 +1328,605 @@ mod gen {
     // fn construct_struct(
     //     &self,
     //     args: Vec<Box<dyn Any>>,
-    // ) -> Result<Box<dyn Any>, RuntimeConstructError>;
+    // ) -> Result<UnsizeableOwned, RuntimeConstructError>;
     pub fn create_dyn_struct_ctor(meta: &MetaType) -> proc_macro2::TokenStream {
         let args_ident = syn::Ident::new("args", proc_macro2::Span::call_site());
         let self_ty_ident = syn::Ident::new("Self", proc_macro2::Span::call_site());
 
         let body = match &meta.data {
-            crate::Data::Struct(fields) => {
-                create_dyn_fields_ctor_body(&self_ty_ident.to_token_stream(), &args_ident, &fields)
+            crate::Data::Struct(fields)
+                if meta.attrs.public_only && !fields.iter().all(is_public) =>
+            {
+                quote! {
+                    return Err(_reflectix::RuntimeConstructError::PrivateFields);
+                }
             }
+            // mirror could be built, but it couldn't be converted into foreign type
+            crate::Data::Struct(_) if meta.attrs.remote.is_some() && meta.non_exhaustive => {
+                quote! {
+                    return Err(_reflectix::RuntimeConstructError::NonExhaustive);
+                }
+            }
+            crate::Data::Struct(fields) => create_dyn_fields_ctor_body(
+                &self_ty_ident.to_token_stream(),
+                &args_ident,
+                fields,
+                &meta.phantoms,
+                None,
+                meta.attrs.constructor.as_ref(),
+            ),
             crate::Data::Enum(_) => {
                 quote! {
-                    return Err(reflectix_core::RuntimeConstructError::NotStruct);
+                    return Err(_reflectix::RuntimeConstructError::NotStruct);
                 }
             }
+            crate::Data::Union(_) => quote! {
+                return Err(_reflectix::RuntimeConstructError::Union);
+            },
         };
 
         quote! {
             fn construct_struct(
                 &self,
                 mut #args_ident: Vec<Box<dyn std::any::Any>>,
-            ) -> Result<Box<dyn std::any::Any>, reflectix_core::RuntimeConstructError> {
+            ) -> Result<_reflectix::UnsizeableOwned, _reflectix::RuntimeConstructError> {
                 #body
             }
 
         }
     }
+
+    // fn variant(&self) -> Option<&'static Variant>
+    //
+    // Only enums override default implementation. Index of variant in match
+    // is the same as in metadata, because both are generated from the same list
+    pub fn create_variant_method(meta: &MetaType) -> proc_macro2::TokenStream {
+        let crate::Data::Enum(variants) = &meta.data else {
+            return quote! {};
+        };
+
+        let patterns = variants.variants.iter().map(|variant| {
+            let variant_name = &variant.name;
+            quote! {Self::#variant_name{..}}
+        });
+        let indexes = 0..variants.variants.len();
+
+        quote! {
+            fn variant(&self) -> Option<&'static _reflectix::Variant> {
+                let _reflectix::Data::Enum(variants) = &<Self as _reflectix::TypeInfo>::INFO.data else {
+                    unreachable!()
+                };
+
+                // wildcard arm catches skipped variants
+                #[allow(unreachable_patterns)]
+                let index = match self {
+                    #(#patterns => #indexes,)*
+                    _ => return None,
+                };
+                Some(&variants.variants[index])
+            }
+        }
+    }
+
+    // fn from_unit_variant(name: &str) -> Option<Self>
+    //
+    // Matches exposed names of unit variants only, because other variants can't be built without arguments
+    pub fn create_unit_variant_ctor(meta: &MetaType) -> proc_macro2::TokenStream {
+        let crate::Data::Enum(variants) = &meta.data else {
+            return quote! {};
+        };
+
+        let (names, values): (Vec<_>, Vec<_>) = variants
+            .variants
+            .iter()
+            .filter(|variant| matches!(variant.fields, Fields::Unit))
+            .map(|variant| {
+                let ident = &variant.name;
+                let phantoms = &variant.phantoms;
+                (
+                    &variant.exposed_name,
+                    quote! {Self::#ident{#(#phantoms: ::core::marker::PhantomData),*}},
+                )
+            })
+            .unzip();
+        if names.is_empty() {
+            return quote! {};
+        }
+
+        quote! {
+            fn from_unit_variant(name: &str) -> Option<Self> {
+                match name {
+                    #(#names => Some(#values),)*
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    // fn field_at<'s>(&'s self, position: usize) -> Result<Unsizeable<'s>, FieldAccessError>
+    //
+    // Only structs override default implementation, which looks up `FieldId` in metadata.
+    // Fields are listed in the same order as in metadata, so position of arm is position of field
+    pub fn create_positional_access_methods(meta: &MetaType) -> proc_macro2::TokenStream {
+        let crate::Data::Struct(fields) = &meta.data else {
+            return quote! {};
+        };
+        if matches!(fields, Fields::Unit) {
+            return quote! {};
+        }
+
+        let positions = 0..fields.iter().len();
+        let mut arms = Vec::new();
+        let mut mut_arms = Vec::new();
+        for field in fields.iter() {
+            let access = match &field.id {
+                FieldId::Named(ident) => ident.to_token_stream(),
+                FieldId::Index(index) => index.to_token_stream(),
+            };
+            let field_ty = &field.ty;
+            if meta.attrs.public_only && !is_public(field) {
+                arms.push(quote! {Err(_reflectix::FieldAccessError::Private)});
+                mut_arms.push(quote! {Err(_reflectix::FieldAccessError::Private)});
+            } else {
+                arms.push(quote! {Ok(_reflectix::Unsizeable::new::<#field_ty>(&self.#access))});
+                mut_arms.push(
+                    quote! {Ok(_reflectix::UnsizeableMut::new::<#field_ty>(&mut self.#access))},
+                );
+            }
+        }
+        let mut_positions = positions.clone();
+
+        quote! {
+            fn field_at<'s>(&'s self, position: usize) -> Result<_reflectix::Unsizeable<'s>, _reflectix::FieldAccessError> {
+                match position {
+                    #(#positions => #arms,)*
+                    _ => Err(_reflectix::FieldAccessError::NotFound),
+                }
+            }
+            fn field_at_mut<'s>(&'s mut self, position: usize) -> Result<_reflectix::UnsizeableMut<'s>, _reflectix::FieldAccessError> {
+                match position {
+                    #(#mut_positions => #mut_arms,)*
+                    _ => Err(_reflectix::FieldAccessError::NotFound),
+                }
+            }
+        }
+    }
+
+    /*
+    Generates inherent getters for `#[reflectix(typed_accessors)]`
+
+    Getters are generated from the same list of fields as metadata, so every reflected field
+    has a getter and vice versa. Getter is as visible as the field itself
+    */
+    pub fn create_typed_accessors(meta: &MetaType) -> proc_macro2::TokenStream {
+        let crate::Data::Struct(fields) = &meta.data else {
+            return quote! {};
+        };
+        if !meta.attrs.typed_accessors {
+            return quote! {};
+        }
+
+        let getters = fields.iter().map(|field| {
+            let vis = &field.vis;
+            let ty = &field.ty;
+            let (access, getter) = match &field.id {
+                FieldId::Named(ident) => (
+                    ident.to_token_stream(),
+                    format_ident!("reflect_{}", ident.unraw()),
+                ),
+                FieldId::Index(index) => (
+                    index.to_token_stream(),
+                    format_ident!("reflect_{}", index.to_string()),
+                ),
+            };
+            let doc = format!("Typed view of reflected field `{}`", access);
+
+            quote! {
+                #[doc = #doc]
+                #vis fn #getter(&self) -> &#ty {
+                    &self.#access
+                }
+            }
+        });
+
+        let ty_ident = &meta.ident;
+        let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+        quote! {
+            impl #impl_generics #ty_ident #ty_generics #where_clause {
+                #(#getters)*
+            }
+        }
+    }
+
+    // pub fn from_variant_name(name: &str) -> Result<Self, UnknownVariantError>
+    //
+    // Strict counterpart of `from_unit_variant`, generated for `#[reflectix(deny_unknown_variants)]`
+    pub fn create_variant_name_ctor(meta: &MetaType) -> proc_macro2::TokenStream {
+        if !meta.attrs.deny_unknown_variants {
+            return quote! {};
+        }
+
+        let ty_ident = &meta.ident;
+        let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+        quote! {
+            impl #impl_generics #ty_ident #ty_generics #where_clause {
+                /// Value of unit variant, which is exposed under `name`
+                ///
+                /// Fails with list of names of unit variants, if there is no such variant
+                pub fn from_variant_name(name: &str) -> Result<Self, _reflectix::UnknownVariantError> {
+                    <Self as _reflectix::TypeInfo>::from_unit_variant(name).ok_or_else(|| {
+                        _reflectix::UnknownVariantError::new(<Self as _reflectix::TypeInfo>::INFO, name)
+                    })
+                }
+            }
+        }
+    }
+
+    /*
+    Generates conversions between `#[reflectix(remote = "...")]` mirror and foreign type it mirrors
+
+    Foreign type can't implement `TypeInfo` outside of it's crate, so values are converted
+    into mirror for reflection and back once done. Every field of mirror must be public field of foreign type.
+    Conversion into foreign type is omitted if it's `#[non_exhaustive]` (or has such variants),
+    conversion from foreign enum is omitted if it's `#[non_exhaustive]`, because it can't be matched exhaustively
+    */
+    pub fn create_remote_conversions(
+        meta: &MetaType,
+        input: &syn::DeriveInput,
+    ) -> proc_macro2::TokenStream {
+        let Some(remote) = &meta.attrs.remote else {
+            return quote! {};
+        };
+        // generic arguments are inferred, so path can be used in patterns and expressions
+        let mut remote_ctor = remote.clone();
+        remote_ctor.segments.last_mut().unwrap().arguments = syn::PathArguments::None;
+
+        let ty_ident = &meta.ident;
+        let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+
+        // braced initializers and patterns work for every kind of fields
+        let members = |fields: &syn::Fields| {
+            let members = fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| match &field.ident {
+                    Some(ident) => syn::Member::Named(ident.clone()),
+                    None => syn::Member::Unnamed(index.into()),
+                })
+                .collect::<Vec<_>>();
+            let bindings = (0..members.len())
+                .map(|index| format_ident!("__field{}", index))
+                .collect::<Vec<_>>();
+            (members, bindings)
+        };
+
+        let (from_remote, into_remote) = match &input.data {
+            syn::Data::Struct(data) => {
+                let (members, _) = members(&data.fields);
+                let from_remote = quote! {
+                    Self{#(#members: value.#members),*}
+                };
+                let into_remote = (!meta.non_exhaustive).then(|| {
+                    quote! {
+                        Self{#(#members: value.#members),*}
+                    }
+                });
+                (Some(from_remote), into_remote)
+            }
+            syn::Data::Enum(data) => {
+                let mut from_arms = Vec::new();
+                let mut into_arms = Vec::new();
+                for variant in data.variants.iter() {
+                    let variant_ident = &variant.ident;
+                    let (members, bindings) = members(&variant.fields);
+                    from_arms.push(quote! {
+                        #remote_ctor::#variant_ident{#(#members: #bindings,)* ..} => Self::#variant_ident{#(#members: #bindings),*}
+                    });
+                    into_arms.push(quote! {
+                        #ty_ident::#variant_ident{#(#members: #bindings),*} => Self::#variant_ident{#(#members: #bindings),*}
+                    });
+                }
+
+                let from_remote = (!meta.non_exhaustive).then(|| {
+                    quote! {
+                        match value {
+                            #(#from_arms,)*
+                        }
+                    }
+                });
+                let exhaustive_variants = data
+                    .variants
+                    .iter()
+                    .all(|variant| !crate::attrs::is_non_exhaustive(&variant.attrs));
+                let into_remote = exhaustive_variants.then(|| {
+                    quote! {
+                        match value {
+                            #(#into_arms,)*
+                        }
+                    }
+                });
+                (from_remote, into_remote)
+            }
+            syn::Data::Union(_) => unreachable!(),
+        };
+
+        let from_remote = from_remote.map(|body| {
+            quote! {
+                impl #impl_generics ::core::convert::From<#remote> for #ty_ident #ty_generics #where_clause {
+                    fn from(value: #remote) -> Self {
+                        #body
+                    }
+                }
+            }
+        });
+        let into_remote = into_remote.map(|body| {
+            quote! {
+                impl #impl_generics ::core::convert::From<#ty_ident #ty_generics> for #remote #where_clause {
+                    fn from(value: #ty_ident #ty_generics) -> Self {
+                        #body
+                    }
+                }
+            }
+        });
+        quote! {
+            #from_remote
+            #into_remote
+        }
+    }
+
+    /*
+    Generates impls for `#[reflectix(transparent)]` wrappers
+
+    Everything is delegated to the only field: metadata is that of inner type,
+    field access is forwarded and constructed inner value is wrapped back into `Self`
+    */
+    pub fn create_transparent_impl(meta: &MetaType) -> proc_macro2::TokenStream {
+        let crate::Data::Struct(fields) = &meta.data else {
+            unreachable!()
+        };
+        let inner = fields.iter().next().unwrap();
+        let inner_ty = &inner.ty;
+        let ty_ident = &meta.ident;
+
+        let phantoms = &meta.phantoms;
+        let inner_member = match &inner.id {
+            FieldId::Named(ident) => ident.to_token_stream(),
+            FieldId::Index(index) => index.to_token_stream(),
+        };
+        let inner_access = quote! {self.#inner_member};
+        let wrap =
+            quote! {Self{#inner_member: *inner, #(#phantoms: ::core::marker::PhantomData),*}};
+        let wrap_unboxed =
+            quote! {Self{#inner_member: inner, #(#phantoms: ::core::marker::PhantomData),*}};
+
+        let rewrap = quote! {
+            let inner = constructed?.downcast::<#inner_ty>().map_err(|constructed| {
+                _reflectix::RuntimeConstructError::ForeignValue {
+                    expected: <#inner_ty as _reflectix::TypeInfo>::INFO.ident,
+                    found: constructed.get_dynamic().ident,
+                }
+            })?;
+            Ok(_reflectix::UnsizeableOwned::new(#wrap))
+        };
+
+        let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics _reflectix::TypeInfoDynamic for #ty_ident #ty_generics #where_clause {
+                fn get_dynamic(&self) -> &'static _reflectix::Type {
+                    <#inner_ty as _reflectix::TypeInfo>::INFO
+                }
+
+                fn construct_struct(
+                    &self,
+                    args: Vec<Box<dyn std::any::Any>>,
+                ) -> Result<_reflectix::UnsizeableOwned, _reflectix::RuntimeConstructError> {
+                    let constructed = _reflectix::TypeInfoDynamic::construct_struct(&#inner_access, args);
+                    #rewrap
+                }
+
+                fn construct_enum(
+                    &self,
+                    variant: &'static str,
+                    args: Vec<Box<dyn std::any::Any>>,
+                ) -> Result<_reflectix::UnsizeableOwned, _reflectix::RuntimeConstructError> {
+                    let constructed = _reflectix::TypeInfoDynamic::construct_enum(&#inner_access, variant, args);
+                    #rewrap
+                }
+
+                fn field<'s>(&'s self, id: _reflectix::FieldId) -> Result<_reflectix::Unsizeable<'s>, _reflectix::FieldAccessError> {
+                    _reflectix::TypeInfoDynamic::field(&#inner_access, id)
+                }
+                fn field_mut<'s>(&'s mut self, id: _reflectix::FieldId) -> Result<_reflectix::UnsizeableMut<'s>, _reflectix::FieldAccessError> {
+                    _reflectix::TypeInfoDynamic::field_mut(&mut #inner_access, id)
+                }
+                fn variant(&self) -> Option<&'static _reflectix::Variant> {
+                    _reflectix::TypeInfoDynamic::variant(&#inner_access)
+                }
+                fn field_at<'s>(&'s self, position: usize) -> Result<_reflectix::Unsizeable<'s>, _reflectix::FieldAccessError> {
+                    _reflectix::TypeInfoDynamic::field_at(&#inner_access, position)
+                }
+                fn field_at_mut<'s>(&'s mut self, position: usize) -> Result<_reflectix::UnsizeableMut<'s>, _reflectix::FieldAccessError> {
+                    _reflectix::TypeInfoDynamic::field_at_mut(&mut #inner_access, position)
+                }
+                fn as_list(&self) -> Option<&dyn _reflectix::ReflectList> {
+                    _reflectix::TypeInfoDynamic::as_list(&#inner_access)
+                }
+                fn as_list_mut(&mut self) -> Option<&mut dyn _reflectix::ReflectList> {
+                    _reflectix::TypeInfoDynamic::as_list_mut(&mut #inner_access)
+                }
+                fn as_map(&self) -> Option<&dyn _reflectix::ReflectMap> {
+                    _reflectix::TypeInfoDynamic::as_map(&#inner_access)
+                }
+                fn as_map_mut(&mut self) -> Option<&mut dyn _reflectix::ReflectMap> {
+                    _reflectix::TypeInfoDynamic::as_map_mut(&mut #inner_access)
+                }
+                fn as_shared(&self) -> Option<&dyn _reflectix::ReflectShared> {
+                    _reflectix::TypeInfoDynamic::as_shared(&#inner_access)
+                }
+                fn pointee(&self) -> Option<&dyn _reflectix::TypeInfoDynamic> {
+                    Some(&#inner_access)
+                }
+                fn pointee_mut(&mut self) -> Result<_reflectix::UnsizeableMut<'_>, _reflectix::FieldAccessError> {
+                    Ok(_reflectix::UnsizeableMut::new::<#inner_ty>(&mut #inner_access))
+                }
+                fn construct_wrapper(
+                    &self,
+                    inner: Box<dyn std::any::Any>,
+                ) -> Result<_reflectix::UnsizeableOwned, _reflectix::RuntimeConstructError> {
+                    let inner = inner.downcast::<#inner_ty>().map_err(|_| _reflectix::RuntimeConstructError::UnexpectedType {
+                        index: 0,
+                        expected: <#inner_ty as _reflectix::TypeInfo>::INFO.ident,
+                        field: _reflectix::FieldId::Index(0),
+                        variant: None,
+                    })?;
+                    Ok(_reflectix::UnsizeableOwned::new(#wrap))
+                }
+            }
+
+            impl #impl_generics _reflectix::TypeInfo for #ty_ident #ty_generics #where_clause {
+                const INFO: &'static _reflectix::Type = <#inner_ty as _reflectix::TypeInfo>::INFO;
+
+                fn from_unit_variant(name: &str) -> Option<Self> {
+                    <#inner_ty as _reflectix::TypeInfo>::from_unit_variant(name).map(|inner| #wrap_unboxed)
+                }
+            }
+        }
+    }
 }
 
-#[proc_macro_derive(TypeInfo)]
+#[proc_macro_derive(TypeInfo, attributes(reflectix))]
 pub fn type_info_derive(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
 
-    if !ast.generics.params.is_empty() {
-        panic!("Type info for generic struct is currently not supported");
+    let meta = match MetaType::new(&ast) {
+        Ok(meta) => meta,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    // generated code refers to the crate through local alias, so that it works
+    // both with facade crate and with renamed or re-exported one
+    let krate = match &meta.attrs.krate {
+        Some(path) => quote! {#path},
+        None => quote! {::reflectix},
+    };
+    let expanded = expand(&meta, &ast);
+    quote! {
+        const _: () = {
+            use #krate as _reflectix;
+            #expanded
+        };
     }
+    .into()
+}
 
-    let meta = MetaType::new(&ast);
+fn expand(meta: &MetaType, input: &syn::DeriveInput) -> proc_macro2::TokenStream {
+    let typed_accessors = gen::create_typed_accessors(meta);
+    let variant_name_ctor = gen::create_variant_name_ctor(meta);
+    let remote_conversions = gen::create_remote_conversions(meta, input);
 
-    let const_definition = gen::create_const_definition(&meta);
+    if meta.attrs.transparent {
+        let transparent_impl = gen::create_transparent_impl(meta);
+        return quote! {
+            #transparent_impl
+            #typed_accessors
+        };
+    }
+
+    let (info_definition, info_expr) = gen::create_info_definition(meta);
 
-    let const_def_ident = meta.info_ident.clone();
     let ty_ident = meta.ident.clone();
+    let (impl_generics, ty_generics, where_clause) = meta.generics.split_for_impl();
+
+    // matching variants by value or name doesn't touch fields, so it stays available for static-only enums,
+    // which keeps `deny_unknown_variants`, `unknown_variants` and `fallback_variant` meaningful for them
+    let variant_method = gen::create_variant_method(meta);
+    let unit_variant_ctor = gen::create_unit_variant_ctor(meta);
+
+    // `TypeInfoDynamic` is supertrait of `TypeInfo`, so it's still implemented, but every operation fails
+    if meta.attrs.static_only {
+        return quote! {
+            #info_definition
+
+            impl #impl_generics _reflectix::TypeInfoDynamic for #ty_ident #ty_generics #where_clause {
+                fn get_dynamic(&self) -> &'static _reflectix::Type {
+                    <Self as _reflectix::TypeInfo>::INFO
+                }
+
+                fn construct_struct(
+                    &self,
+                    _args: Vec<Box<dyn std::any::Any>>,
+                ) -> Result<_reflectix::UnsizeableOwned, _reflectix::RuntimeConstructError> {
+                    Err(_reflectix::RuntimeConstructError::StaticOnly)
+                }
+
+                fn construct_enum(
+                    &self,
+                    _variant: &'static str,
+                    _args: Vec<Box<dyn std::any::Any>>,
+                ) -> Result<_reflectix::UnsizeableOwned, _reflectix::RuntimeConstructError> {
+                    Err(_reflectix::RuntimeConstructError::StaticOnly)
+                }
+
+                fn field<'s>(&'s self, _id: _reflectix::FieldId) -> Result<_reflectix::Unsizeable<'s>, _reflectix::FieldAccessError> {
+                    Err(_reflectix::FieldAccessError::StaticOnly)
+                }
+                fn field_mut<'s>(&'s mut self, _id: _reflectix::FieldId) -> Result<_reflectix::UnsizeableMut<'s>, _reflectix::FieldAccessError> {
+                    Err(_reflectix::FieldAccessError::StaticOnly)
+                }
+
+                #variant_method
+            }
 
-    let struct_ctor = gen::create_dyn_struct_ctor(&meta);
-    let enum_ctor = gen::create_dyn_enum_ctor(&meta);
+            impl #impl_generics _reflectix::TypeInfo for #ty_ident #ty_generics #where_clause {
+                const INFO: &'static _reflectix::Type = #info_expr;
 
-    let mut_field_access_body = gen::create_get_dyn_field_method_body(&meta, true);
-    let field_access_body = gen::create_get_dyn_field_method_body(&meta, false);
-    let tokens = quote! {
-        #const_definition
+                #unit_variant_ctor
+            }
 
-        impl reflectix_core::TypeInfoDynamic for #ty_ident {
-             fn get_dynamic(&self) -> &'static reflectix_core::Type {
-                 &#const_def_ident
+            #typed_accessors
+            #variant_name_ctor
+            #remote_conversions
+        };
+    }
+
+    let struct_ctor = gen::create_dyn_struct_ctor(meta);
+    let enum_ctor = gen::create_dyn_enum_ctor(meta);
+
+    let mut_field_access_body = gen::create_get_dyn_field_method_body(meta, true);
+    let field_access_body = gen::create_get_dyn_field_method_body(meta, false);
+    let positional_access_methods = gen::create_positional_access_methods(meta);
+
+    quote! {
+        #info_definition
+
+        impl #impl_generics _reflectix::TypeInfoDynamic for #ty_ident #ty_generics #where_clause {
+             fn get_dynamic(&self) -> &'static _reflectix::Type {
+                 <Self as _reflectix::TypeInfo>::INFO
              }
 
              #struct_ctor
              #enum_ctor
 
-            fn field<'s>(&'s self, id: reflectix_core::FieldId) -> Result<reflectix_core::Unsizeable<'s>, reflectix_core::FieldAccessError> {
+            fn field<'s>(&'s self, id: _reflectix::FieldId) -> Result<_reflectix::Unsizeable<'s>, _reflectix::FieldAccessError> {
                 #field_access_body
             }
-            fn field_mut<'s>(&'s mut self, id: reflectix_core::FieldId) -> Result<reflectix_core::UnsizeableMut<'s>, reflectix_core::FieldAccessError> {
+            fn field_mut<'s>(&'s mut self, id: _reflectix::FieldId) -> Result<_reflectix::UnsizeableMut<'s>, _reflectix::FieldAccessError> {
                 #mut_field_access_body
             }
 
+            #variant_method
+            #positional_access_methods
         }
 
-        impl reflectix_core::TypeInfo for #ty_ident {
-            const INFO: &'static reflectix_core::Type = &#const_def_ident;
+        impl #impl_generics _reflectix::TypeInfo for #ty_ident #ty_generics #where_clause {
+            const INFO: &'static _reflectix::Type = #info_expr;
+
+            #unit_variant_ctor
         }
 
+        #typed_accessors
+        #variant_name_ctor
+        #remote_conversions
     }
-    .into();
-    tokens
 }
@@ -36,4 +36,7 @@ pub use ::reflectix_core::*;
 ///
 /// *Note*: That if any field type is compound (non-primitive), then you
 /// must derive  [`TypeInfo`] for those types too
+///
+/// Generated code refers to this crate as `::reflectix`. If it is renamed or re-exported,
+/// specify path to it with `#[reflectix(crate = "path::to::reflectix")]`
 pub use reflectix_macros::TypeInfo;
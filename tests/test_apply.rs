@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use reflectix::apply::ApplyError;
+use reflectix::*;
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug)]
+pub enum State {
+    Idle,
+    Moving { speed: f32 },
+}
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug)]
+pub struct Entity {
+    pub name: String,
+    pub path: Vec<u32>,
+    pub target: Option<String>,
+    pub state: State,
+    pub tags: HashMap<String, u8>,
+}
+
+fn entity() -> Entity {
+    Entity {
+        name: "runner".to_string(),
+        path: vec![1, 2, 3],
+        target: Some("home".to_string()),
+        state: State::Moving { speed: 2.5 },
+        tags: HashMap::from([("team".to_string(), 1)]),
+    }
+}
+
+#[test]
+fn test_clone_from_dynamic() {
+    let src = entity();
+    let mut dst = Entity {
+        name: String::with_capacity(64),
+        path: vec![9; 5],
+        target: None,
+        state: State::Idle,
+        tags: HashMap::from([("stale".to_string(), 0)]),
+    };
+    let name_buffer = dst.name.as_ptr();
+
+    dst.clone_from_dynamic(&src).unwrap();
+    assert_eq!(dst, src);
+    // string buffer was reused instead of being reallocated
+    assert_eq!(dst.name.as_ptr(), name_buffer);
+
+    let mut shorter = src.clone();
+    shorter.path.truncate(1);
+    shorter.target = None;
+    dst.clone_from_dynamic(&shorter).unwrap();
+    assert_eq!(dst, shorter);
+}
+
+#[test]
+fn test_clone_from_field() {
+    let src = State::Moving { speed: 1.0 };
+    let mut dst = entity();
+
+    let erased: &mut dyn TypeInfoDynamic = &mut dst;
+    erased
+        .field_mut("state".into())
+        .unwrap()
+        .clone_from_dynamic(&src)
+        .unwrap();
+    assert_eq!(dst.state, src);
+
+    assert!(matches!(
+        dst.clone_from_dynamic(&src),
+        Err(ApplyError::UnmatchingType {
+            expected: "Entity",
+            actual: "State"
+        })
+    ));
+}
+
+#[test]
+fn test_recycler() {
+    use reflectix::pool::Recycler;
+
+    let template = Entity {
+        name: String::new(),
+        path: Vec::new(),
+        target: None,
+        state: State::Idle,
+        tags: HashMap::new(),
+    };
+    let mut recycler = Recycler::new(template.clone());
+    recycler.prefill(2).unwrap();
+    assert_eq!(recycler.len(), 2);
+
+    let mut value = recycler.acquire().unwrap();
+    assert_eq!(value, template);
+    value.name.push_str("a rather long name of pooled entity");
+    value.path.extend([4, 5, 6]);
+    let capacity = value.name.capacity();
+    recycler.release(value).unwrap();
+
+    let reused = recycler.acquire().unwrap();
+    assert_eq!(reused, template);
+    assert_eq!(reused.name.capacity(), capacity);
+
+    recycler.acquire().unwrap();
+    assert!(recycler.is_empty());
+    assert_eq!(recycler.acquire().unwrap(), template);
+}
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug)]
+pub struct Boxed {
+    pub values: Vec<Box<u32>>,
+    pub limit: Option<Box<u32>>,
+}
+
+#[test]
+fn test_clone_wrapped_leaves() {
+    let src = Boxed {
+        values: vec![Box::new(1), Box::new(2), Box::new(3)],
+        limit: Some(Box::new(10)),
+    };
+    // longer list and `Some` in place of `None` need new wrapped values
+    let mut dst = Boxed {
+        values: vec![Box::new(7)],
+        limit: None,
+    };
+    dst.clone_from_dynamic(&src).unwrap();
+    assert_eq!(dst, src);
+
+    let mut recycler = reflectix::pool::Recycler::new(src.clone());
+    assert_eq!(recycler.acquire().unwrap(), src);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Guarded {
+    pub states: Vec<std::sync::Mutex<State>>,
+}
+
+#[test]
+fn test_clone_interior_mutability() {
+    let src = Guarded {
+        states: vec![
+            std::sync::Mutex::new(State::Idle),
+            std::sync::Mutex::new(State::Moving { speed: 3.0 }),
+        ],
+    };
+    // second element is copied while lock of source is held
+    let mut dst = Guarded::default();
+    dst.clone_from_dynamic(&src).unwrap();
+
+    let states = dst
+        .states
+        .iter()
+        .map(|state| state.lock().unwrap().clone())
+        .collect::<Vec<_>>();
+    assert_eq!(states, [State::Idle, State::Moving { speed: 3.0 }]);
+}
@@ -0,0 +1,578 @@
+use reflectix::*;
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Inner {
+    pub value: u64,
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+#[reflectix(transparent)]
+pub struct Wrapper(pub Inner);
+
+#[test]
+pub fn test_transparent() {
+    assert_eq!(Wrapper::INFO, Inner::INFO);
+
+    let mut wrapper = Wrapper::default();
+    let erased: &mut dyn TypeInfoDynamic = &mut wrapper;
+
+    assert_eq!(erased.get_dynamic().ident, "Inner");
+    erased
+        .update_field("value".into(), |value: &mut u64| *value = 7)
+        .unwrap();
+
+    let constructed = erased.construct_struct(vec![Box::new(3u64)]).unwrap();
+    assert_eq!(constructed.downcast::<Wrapper>().unwrap().0.value, 3);
+    assert_eq!(wrapper.0.value, 7);
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub enum Message {
+    #[reflectix(rename = "ping")]
+    Ping,
+    Text(String),
+    #[reflectix(skip)]
+    Internal {
+        secret: u64,
+    },
+}
+
+#[test]
+pub fn test_variant_attributes() {
+    let Data::Enum(variants) = &Message::INFO.data else {
+        panic!("Message is not an enum");
+    };
+    let idents = variants
+        .variants
+        .iter()
+        .map(|x| x.ident)
+        .collect::<Vec<_>>();
+    assert_eq!(idents, ["ping", "Text"]);
+
+    let prototype = Message::Ping;
+    let ping = prototype.construct_enum("ping", vec![]).unwrap();
+    assert_eq!(*ping.downcast::<Message>().unwrap(), Message::Ping);
+
+    assert!(matches!(
+        prototype.construct_enum("Internal", vec![Box::new(1u64)]),
+        Err(RuntimeConstructError::InvalidVariant)
+    ));
+    assert!(matches!(
+        Message::Internal { secret: 1 }.field("secret".into()),
+        Err(FieldAccessError::NotFound)
+    ));
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Reordered {
+    pub a: u8,
+    pub b: u16,
+    #[reflectix(order = 0)]
+    pub c: u32,
+}
+
+#[test]
+pub fn test_field_order() {
+    let Data::Struct(Fields::Named(fields)) = &Reordered::INFO.data else {
+        panic!("Reordered is not a named struct");
+    };
+    let ids = fields.iter().map(|x| x.id.clone()).collect::<Vec<_>>();
+    assert_eq!(ids, ["c".into(), "a".into(), "b".into()]);
+
+    let prototype = Reordered { a: 0, b: 0, c: 0 };
+    let constructed = prototype
+        .construct_struct(vec![Box::new(3u32), Box::new(1u8), Box::new(2u16)])
+        .unwrap();
+    assert_eq!(
+        *constructed.downcast::<Reordered>().unwrap(),
+        Reordered { a: 1, b: 2, c: 3 }
+    );
+}
+
+#[derive(reflectix::TypeInfo)]
+#[reflectix(typed_accessors)]
+pub struct Account {
+    pub id: u64,
+    pub owner: String,
+}
+
+#[derive(reflectix::TypeInfo)]
+#[reflectix(typed_accessors)]
+pub struct Balance(pub i64);
+
+#[test]
+pub fn test_typed_accessors() {
+    let account = Account {
+        id: 3,
+        owner: "ferris".to_string(),
+    };
+    assert_eq!(*account.reflect_id(), 3);
+    assert_eq!(account.reflect_owner(), "ferris");
+    assert_eq!(
+        account
+            .field("owner".into())
+            .unwrap()
+            .downcast_ref::<String>(),
+        Some(account.reflect_owner())
+    );
+
+    assert_eq!(*Balance(-5).reflect_0(), -5);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+#[reflectix(rename_all = "camelCase")]
+pub struct Request {
+    pub user_id: u64,
+    #[reflectix(rename = "X-Trace")]
+    pub trace_id: String,
+}
+
+#[derive(reflectix::TypeInfo)]
+#[reflectix(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Level {
+    WarnOnce,
+    #[reflectix(rename = "err")]
+    Error {
+        error_code: u32,
+    },
+}
+
+#[test]
+pub fn test_rename() {
+    let Data::Struct(Fields::Named(fields)) = &Request::INFO.data else {
+        unreachable!()
+    };
+    let names: Vec<_> = fields.iter().map(|field| field.id.to_string()).collect();
+    assert_eq!(names, ["userId", "X-Trace"]);
+
+    let request = Request {
+        user_id: 7,
+        trace_id: String::new(),
+    };
+    assert_eq!(
+        request
+            .field("userId".into())
+            .unwrap()
+            .downcast_ref::<u64>(),
+        Some(&7)
+    );
+    assert!(request.field("user_id".into()).is_err());
+
+    let Data::Enum(variants) = &Level::INFO.data else {
+        unreachable!()
+    };
+    let names: Vec<_> = variants
+        .variants
+        .iter()
+        .map(|variant| variant.ident)
+        .collect();
+    assert_eq!(names, ["WARN_ONCE", "err"]);
+    assert!(Level::WarnOnce.construct_enum("WARN_ONCE", vec![]).is_ok());
+    assert!(Level::Error { error_code: 1 }
+        .field("error_code".into())
+        .is_ok());
+}
+
+#[derive(reflectix::TypeInfo)]
+#[reflectix(meta(table = "users", version = "2"))]
+pub struct User {
+    #[reflectix(meta(label = "E-mail", widget = "text"))]
+    pub email: String,
+    pub age: u8,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub enum Role {
+    #[reflectix(meta(color = "red"))]
+    Admin,
+    Guest,
+}
+
+#[test]
+pub fn test_meta() {
+    assert_eq!(User::INFO.get_meta("table"), Some("users"));
+    assert_eq!(User::INFO.meta, [("table", "users"), ("version", "2")]);
+
+    let email = User::INFO.find_field("email").unwrap();
+    assert_eq!(email.get_meta("label"), Some("E-mail"));
+    assert!(User::INFO.find_field("age").unwrap().meta.is_empty());
+
+    let Data::Enum(variants) = &Role::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(variants.variants[0].get_meta("color"), Some("red"));
+    assert_eq!(variants.variants[1].get_meta("color"), None);
+}
+
+/// Not captured
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Connection {
+    pub host: String,
+    #[reflectix(default = "8080")]
+    pub port: u16,
+    #[reflectix(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub enum Shape {
+    Circle {
+        radius: f32,
+        #[reflectix(default = "1.0")]
+        scale: f32,
+    },
+}
+
+#[test]
+pub fn test_default() {
+    let prototype = Connection {
+        host: String::new(),
+        port: 0,
+        tags: Vec::new(),
+    };
+
+    let partial = prototype
+        .construct_struct(vec![Box::new("localhost".to_string())])
+        .unwrap();
+    assert_eq!(
+        *partial.downcast::<Connection>().unwrap(),
+        Connection {
+            host: "localhost".to_string(),
+            port: 8080,
+            tags: Vec::new(),
+        }
+    );
+
+    let with_port = prototype
+        .construct_struct(vec![Box::new("localhost".to_string()), Box::new(22u16)])
+        .unwrap();
+    assert_eq!(with_port.downcast::<Connection>().unwrap().port, 22);
+
+    assert!(matches!(
+        prototype.construct_struct(vec![]),
+        Err(RuntimeConstructError::NotEnoughArgs)
+    ));
+
+    let circle = Shape::Circle {
+        radius: 0.0,
+        scale: 0.0,
+    }
+    .construct_enum("Circle", vec![Box::new(2.0f32)])
+    .unwrap();
+    assert_eq!(
+        *circle.downcast::<Shape>().unwrap(),
+        Shape::Circle {
+            radius: 2.0,
+            scale: 1.0
+        }
+    );
+}
+
+mod account {
+    #[derive(reflectix::TypeInfo)]
+    #[reflectix(public_only)]
+    #[allow(dead_code)]
+    pub struct Account {
+        pub login: String,
+        pub(crate) quota: u32,
+        password: String,
+    }
+
+    impl Account {
+        pub fn new(login: &str) -> Self {
+            Self {
+                login: login.to_string(),
+                quota: 0,
+                password: String::new(),
+            }
+        }
+    }
+}
+
+#[test]
+pub fn test_visibility() {
+    let account = account::Account::new("ferris");
+    let Data::Struct(fields) = &account::Account::INFO.data else {
+        panic!("Account is not a struct");
+    };
+    let visibilities = fields
+        .as_slice()
+        .iter()
+        .map(|field| field.vis)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        visibilities,
+        [
+            Visibility::Public,
+            Visibility::Restricted,
+            Visibility::Private
+        ]
+    );
+
+    let erased: &dyn TypeInfoDynamic = &account;
+    assert_eq!(
+        erased
+            .field("login".into())
+            .unwrap()
+            .downcast_ref::<String>()
+            .unwrap(),
+        "ferris"
+    );
+    assert!(matches!(
+        erased.field("password".into()),
+        Err(FieldAccessError::Private)
+    ));
+    assert!(matches!(
+        erased.construct_struct(vec![]),
+        Err(RuntimeConstructError::PrivateFields)
+    ));
+
+    // positional access is restricted the same way
+    assert!(erased.field_at(0).is_ok());
+    assert!(matches!(erased.field_at(2), Err(FieldAccessError::Private)));
+    let mut account = account;
+    assert!(matches!(
+        account.field_at_mut(2),
+        Err(FieldAccessError::Private)
+    ));
+    let handle = account::Account::INFO.handle("password").unwrap();
+    assert!(matches!(
+        handle.get(&account),
+        Err(FieldAccessError::Private)
+    ));
+    assert!(matches!(
+        handle.get_mut(&mut account),
+        Err(FieldAccessError::Private)
+    ));
+
+    // variant fields are public
+    let Data::Enum(variants) = &Shape::INFO.data else {
+        panic!("Shape is not an enum");
+    };
+    assert!(variants.variants[0].fields.as_slice()[0].is_public());
+}
+
+/// Point on a plane
+///
+/// Coordinates are in pixels
+#[derive(reflectix::TypeInfo)]
+#[reflectix(docs)]
+pub struct Point {
+    /// Horizontal offset
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Direction of movement
+#[derive(reflectix::TypeInfo)]
+#[reflectix(docs)]
+pub enum Direction {
+    /// Towards the top
+    Up,
+    Down,
+}
+
+#[test]
+pub fn test_docs() {
+    assert_eq!(
+        Point::INFO.docs,
+        "Point on a plane\n\nCoordinates are in pixels"
+    );
+    assert_eq!(
+        Point::INFO.find_field("x").unwrap().docs,
+        "Horizontal offset"
+    );
+    assert_eq!(Point::INFO.find_field("y").unwrap().docs, "");
+
+    let Data::Enum(variants) = &Direction::INFO.data else {
+        panic!("Direction is not an enum");
+    };
+    assert_eq!(variants.variants[0].docs, "Towards the top");
+
+    // docs are captured only on request
+    assert_eq!(Connection::INFO.docs, "");
+}
+
+mod facade {
+    pub use reflectix as reflect;
+}
+
+#[derive(reflectix::TypeInfo)]
+#[reflectix(crate = "crate::facade::reflect")]
+pub struct Reexported {
+    pub value: u8,
+}
+
+#[test]
+pub fn test_crate_path() {
+    assert_eq!(Reexported::INFO.ident, "Reexported");
+    assert_eq!(
+        Reexported { value: 3 }
+            .field("value".into())
+            .unwrap()
+            .downcast_ref::<u8>(),
+        Some(&3)
+    );
+}
+
+#[derive(reflectix::TypeInfo)]
+#[reflectix(typed_accessors)]
+pub struct Token {
+    pub r#type: String,
+    pub r#fn: u32,
+}
+
+#[derive(reflectix::TypeInfo, Debug, PartialEq)]
+#[allow(non_camel_case_types)]
+pub enum Keyword {
+    Loop,
+    r#match,
+}
+
+#[test]
+pub fn test_raw_identifiers() {
+    let token = Token {
+        r#type: "ident".to_string(),
+        r#fn: 7,
+    };
+    assert!(Token::INFO.find_field("type").is_some());
+    assert!(Token::INFO.find_field("r#type").is_none());
+    assert_eq!(
+        token.field("fn".into()).unwrap().downcast_ref::<u32>(),
+        Some(&7)
+    );
+    assert_eq!(token.reflect_type(), "ident");
+
+    let constructed = token
+        .construct_struct(vec![Box::new("literal".to_string()), Box::new(1u32)])
+        .unwrap()
+        .downcast::<Token>()
+        .unwrap();
+    assert_eq!(constructed.r#type, "literal");
+
+    assert_eq!(Keyword::from_unit_variant("match"), Some(Keyword::r#match));
+    assert_eq!(Keyword::r#match.variant().unwrap().ident, "match");
+}
+
+#[derive(reflectix::TypeInfo, Debug)]
+#[non_exhaustive]
+pub struct Settings {
+    pub verbose: u8,
+}
+
+#[derive(reflectix::TypeInfo, Debug)]
+pub enum Query {
+    #[non_exhaustive]
+    Get {
+        path: String,
+    },
+    Ping,
+}
+
+#[test]
+pub fn test_non_exhaustive() {
+    assert!(Settings::INFO.non_exhaustive);
+    assert!(!Query::INFO.non_exhaustive);
+    let Data::Enum(variants) = &Query::INFO.data else {
+        unreachable!()
+    };
+    assert!(variants.variants[0].non_exhaustive);
+    assert!(!variants.variants[1].non_exhaustive);
+
+    // defining crate is free to construct it's own non-exhaustive types
+    let settings = Settings { verbose: 0 }
+        .construct_struct(vec![Box::new(2u8)])
+        .unwrap();
+    assert_eq!(settings.downcast::<Settings>().unwrap().verbose, 2);
+}
+
+#[derive(reflectix::TypeInfo)]
+#[reflectix(static_only)]
+pub struct Schema {
+    pub version: u32,
+    pub tags: Vec<String>,
+}
+
+#[test]
+pub fn test_static_only() {
+    let field = Schema::INFO.find_field("tags").unwrap();
+    assert_eq!(field.ty.ident, "Vec");
+
+    let schema = Schema {
+        version: 1,
+        tags: Vec::new(),
+    };
+    assert!(matches!(
+        schema.field("version".into()),
+        Err(FieldAccessError::StaticOnly)
+    ));
+    assert!(matches!(
+        schema.construct_struct(vec![Box::new(2u32), Box::new(Vec::<String>::new())]),
+        Err(RuntimeConstructError::StaticOnly)
+    ));
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+#[reflectix(static_only, deny_unknown_variants)]
+pub enum Tier {
+    Low,
+    #[reflectix(rename = "HIGH")]
+    High,
+    Custom(u8),
+}
+
+#[test]
+pub fn test_static_only_variants() {
+    assert_eq!(Tier::High.variant().unwrap().ident, "HIGH");
+    assert_eq!(Tier::Custom(3).variant().unwrap().ident, "Custom");
+
+    assert_eq!(Tier::from_unit_variant("HIGH"), Some(Tier::High));
+    assert_eq!(Tier::from_unit_variant("Custom"), None);
+    assert_eq!(Tier::from_variant_name("Low").unwrap(), Tier::Low);
+    assert!(Tier::from_variant_name("Medium").is_err());
+
+    // variants are still matched, but can't be built dynamically
+    assert!(matches!(
+        Tier::Low.construct_enum("Custom", vec![Box::new(3u8)]),
+        Err(RuntimeConstructError::StaticOnly)
+    ));
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+#[reflectix(constructor = "Self::new_checked")]
+pub struct Range {
+    pub start: u32,
+    #[reflectix(default = "100")]
+    pub end: u32,
+}
+
+impl Range {
+    fn new_checked(start: u32, end: u32) -> Result<Self, String> {
+        if start > end {
+            return Err(format!("{} is past {}", start, end));
+        }
+        Ok(Self { start, end })
+    }
+}
+
+#[test]
+fn test_custom_constructor() {
+    let prototype = Range { start: 0, end: 1 };
+    let range = prototype
+        .construct_struct(vec![Box::new(5u32), Box::new(10u32)])
+        .unwrap();
+    assert_eq!(
+        *range.downcast::<Range>().unwrap(),
+        Range { start: 5, end: 10 }
+    );
+
+    let defaulted = prototype.construct_struct(vec![Box::new(5u32)]).unwrap();
+    assert_eq!(defaulted.downcast_ref::<Range>().unwrap().end, 100);
+
+    let error = prototype
+        .construct_struct(vec![Box::new(10u32), Box::new(5u32)])
+        .err()
+        .unwrap();
+    assert!(matches!(error, RuntimeConstructError::Custom(_)));
+    assert_eq!(error.to_string(), "10 is past 5");
+}
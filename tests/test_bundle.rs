@@ -0,0 +1,97 @@
+#![cfg(feature = "serde")]
+use reflectix::registry::Registry;
+use reflectix::schema::{MetadataBundle, SchemaMismatch};
+use reflectix::{TypeInfo, TypeInfoDynamic};
+
+mod worker {
+    #[derive(reflectix::TypeInfo, Default)]
+    pub struct Job {
+        pub id: u64,
+        pub payload: String,
+    }
+
+    #[derive(reflectix::TypeInfo, Default)]
+    pub enum Status {
+        #[default]
+        Idle,
+        Busy(u64),
+    }
+}
+
+mod host {
+    #[derive(reflectix::TypeInfo, Default)]
+    pub struct Job {
+        pub id: u32,
+        pub payload: String,
+    }
+
+    #[derive(reflectix::TypeInfo, Default)]
+    pub struct Ping;
+}
+
+#[test]
+pub fn test_export_import_bundle() {
+    let mut worker = Registry::new();
+    worker.register_default::<worker::Job>().unwrap();
+    worker.register_default::<worker::Status>().unwrap();
+
+    let bundle = worker.export_bundle();
+    let names: Vec<_> = bundle
+        .types
+        .iter()
+        .map(|schema| schema.ident.as_str())
+        .collect();
+    assert_eq!(names, ["Job", "Status"]);
+
+    let serialized = serde_json::to_string(&bundle).unwrap();
+    let imported: MetadataBundle = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(imported, bundle);
+
+    let mut host = Registry::new();
+    host.import_bundle(imported);
+
+    assert!(host.remote_schema("Status").is_some());
+    assert_eq!(host.check_remote(worker::Job::INFO), Ok(()));
+    assert_eq!(
+        host.check_remote(worker::Status::Busy(1).get_dynamic()),
+        Ok(())
+    );
+    assert_eq!(
+        host.check_remote(host::Job::INFO),
+        Err(SchemaMismatch::Differs("Job".to_string()))
+    );
+    assert_eq!(
+        host.check_remote(host::Ping::INFO),
+        Err(SchemaMismatch::Unknown("Ping".to_string()))
+    );
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Wrapper<T> {
+    pub value: T,
+}
+
+#[test]
+pub fn test_bundle_generic_instantiations() {
+    let mut worker = Registry::new();
+    worker.register_default::<Wrapper<i32>>().unwrap();
+    worker.register_default::<Wrapper<String>>().unwrap();
+
+    let bundle = worker.export_bundle();
+    let names: Vec<_> = bundle
+        .types
+        .iter()
+        .map(|schema| schema.full_name())
+        .collect();
+    assert_eq!(names, ["Wrapper<String>", "Wrapper<i32>"]);
+
+    let mut host = Registry::new();
+    host.import_bundle(bundle);
+    assert!(host.remote_schema("Wrapper<i32>").is_some());
+    assert_eq!(host.check_remote(Wrapper::<i32>::INFO), Ok(()));
+    assert_eq!(host.check_remote(Wrapper::<String>::INFO), Ok(()));
+    assert_eq!(
+        host.check_remote(Wrapper::<u8>::INFO),
+        Err(SchemaMismatch::Unknown("Wrapper<u8>".to_string()))
+    );
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "console")]
+use reflectix::console::{Console, ConsoleError};
+use reflectix::RuntimeConstructError;
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Player {
+    pub name: String,
+    pub position: Position,
+}
+
+#[test]
+pub fn test_console_commands() {
+    let mut player = Player::default();
+
+    {
+        let mut console = Console::new();
+        console.register("player", &mut player);
+
+        assert_eq!(console.execute("ls").unwrap(), "player: Player");
+        assert_eq!(
+            console.execute("ls player").unwrap(),
+            "name: String\nposition: Position"
+        );
+
+        console.execute("set player.name John Doe").unwrap();
+        console.execute("set player.position.x 5").unwrap();
+        console.execute("construct player.position 1 2").unwrap();
+        console.execute("set player.position.y 10").unwrap();
+
+        assert_eq!(console.execute("get player.position.y").unwrap(), "10");
+        assert_eq!(
+            console.execute("get player").unwrap(),
+            "Player { name: John Doe, position: Position { x: 1, y: 10 } }"
+        );
+
+        assert!(matches!(
+            console.execute("set player.position.x abc"),
+            Err(ConsoleError::Parse { ty: "i32", .. })
+        ));
+        assert!(matches!(
+            console.execute("get player.z"),
+            Err(ConsoleError::UnknownField { .. })
+        ));
+        assert!(matches!(
+            console.execute("construct player.position 1 2 3"),
+            Err(ConsoleError::Construct(RuntimeConstructError::TooManyArgs))
+        ));
+        assert_eq!(console.execute("get player.position.x").unwrap(), "1");
+    }
+
+    assert_eq!(player.name, "John Doe");
+    assert_eq!(player.position.x, 1);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Squad {
+    pub members: [Position; 3],
+}
+
+#[test]
+pub fn test_console_indexes() {
+    let mut squad = Squad::default();
+    {
+        let mut console = Console::new();
+        console.register("squad", &mut squad);
+
+        console.execute("set squad.members.1.x 4").unwrap();
+        assert_eq!(console.execute("get squad.members.1.x").unwrap(), "4");
+        assert!(console.execute("get squad.members.3").is_err());
+    }
+    assert_eq!(squad.members[1].x, 4);
+}
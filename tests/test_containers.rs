@@ -0,0 +1,923 @@
+use reflectix::diff::diff;
+use reflectix::*;
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Inventory {
+    pub items: Vec<u32>,
+    pub owner: Option<String>,
+}
+
+#[test]
+fn test_container_metadata() {
+    let Data::Struct(Fields::Named(fields)) = &Inventory::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(fields[0].ty.data, Data::List(TypeRef::of::<u32>()));
+    assert_eq!(fields[1].ty.data, Data::Optional(TypeRef::of::<String>()));
+}
+
+#[test]
+fn test_container_access() {
+    let mut inventory = Inventory {
+        items: vec![1, 2],
+        owner: None,
+    };
+
+    let items = inventory.field("items".into()).unwrap();
+    assert_eq!(
+        items.field(1.into()).unwrap().downcast_ref::<u32>(),
+        Some(&2)
+    );
+    assert!(inventory
+        .field("owner".into())
+        .unwrap()
+        .field(0.into())
+        .is_err());
+
+    let erased: &mut dyn TypeInfoDynamic = &mut inventory;
+    erased
+        .set_field("owner".into(), Box::new(Some("ferris".to_string())))
+        .unwrap();
+    let owner = erased
+        .field_mut("owner".into())
+        .unwrap()
+        .field_mut(0.into());
+    owner.unwrap().downcast_mut::<String>().unwrap().push('!');
+    assert_eq!(inventory.owner.as_deref(), Some("ferris!"));
+}
+
+#[test]
+fn test_container_construction() {
+    let items = Vec::<u32>::new()
+        .construct_struct(vec![Box::new(1u32), Box::new(2u32)])
+        .unwrap();
+    assert_eq!(*items.downcast::<Vec<u32>>().unwrap(), vec![1, 2]);
+
+    let prototype: Option<String> = None;
+    let some = prototype
+        .construct_enum("Some", vec![Box::new("a".to_string())])
+        .unwrap();
+    assert_eq!(
+        *some.downcast::<Option<String>>().unwrap(),
+        Some("a".to_string())
+    );
+    assert!(matches!(
+        prototype.construct_enum("Some", vec![Box::new(1u8)]),
+        Err(RuntimeConstructError::UnexpectedType { index: 0, .. })
+    ));
+}
+
+#[test]
+fn test_container_diff() {
+    let left = Inventory {
+        items: vec![1, 2],
+        owner: Some("a".to_string()),
+    };
+    let right = Inventory {
+        items: vec![1],
+        owner: None,
+    };
+
+    let differences = diff(&left, &right)
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        differences,
+        ["items.1: 2 != <missing>", "owner.0: a != None"]
+    );
+}
+
+#[test]
+fn test_list_mutation() {
+    let mut inventory = Inventory {
+        items: vec![1, 2],
+        owner: None,
+    };
+    let erased: &mut dyn TypeInfoDynamic = &mut inventory;
+
+    erased.list_push("items".into(), Box::new(3u32)).unwrap();
+    assert!(matches!(
+        erased.list_push("items".into(), Box::new(3u8)),
+        Err(FieldAccessError::UnmatchingType)
+    ));
+    assert!(matches!(
+        erased.list_len("owner".into()),
+        Err(FieldAccessError::UnmatchingType)
+    ));
+
+    let removed = erased.list_remove("items".into(), 0).unwrap();
+    assert_eq!(removed.downcast_ref::<u32>(), Some(&1));
+    assert!(matches!(
+        erased.list_remove("items".into(), 5),
+        Err(FieldAccessError::NotFound)
+    ));
+    assert_eq!(erased.list_len("items".into()).unwrap(), 2);
+    assert_eq!(inventory.items, [2, 3]);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Scores {
+    pub by_name: std::collections::HashMap<String, u32>,
+    pub by_rank: std::collections::BTreeMap<u8, String>,
+}
+
+#[test]
+fn test_map_mutation() {
+    let mut scores = Scores::default();
+    let erased: &mut dyn TypeInfoDynamic = &mut scores;
+
+    erased
+        .map_insert("by_name".into(), Box::new("ferris"), Box::new(10u32))
+        .unwrap();
+    let previous = erased
+        .map_insert(
+            "by_name".into(),
+            Box::new("ferris".to_string()),
+            Box::new(12u32),
+        )
+        .unwrap();
+    assert_eq!(previous.unwrap().downcast_ref::<u32>(), Some(&10));
+    erased
+        .map_insert(
+            "by_rank".into(),
+            Box::new("1"),
+            Box::new("gold".to_string()),
+        )
+        .unwrap();
+    erased
+        .map_insert(
+            "by_rank".into(),
+            Box::new(2u8),
+            Box::new("silver".to_string()),
+        )
+        .unwrap();
+    assert!(matches!(
+        erased.map_insert("by_rank".into(), Box::new(3u32), Box::new("x".to_string())),
+        Err(FieldAccessError::UnmatchingType)
+    ));
+
+    assert_eq!(
+        erased
+            .map_get("by_rank".into(), Box::new(1u8))
+            .unwrap()
+            .downcast_ref::<String>()
+            .unwrap(),
+        "gold"
+    );
+    let removed = erased.map_remove("by_rank".into(), Box::new("2")).unwrap();
+    assert_eq!(removed.downcast_ref::<String>().unwrap(), "silver");
+    assert!(matches!(
+        erased.map_get("by_rank".into(), Box::new(2u8)),
+        Err(FieldAccessError::NotFound)
+    ));
+
+    let by_name = erased.field("by_name".into()).unwrap();
+    assert_eq!(
+        by_name
+            .field("ferris".into())
+            .unwrap()
+            .downcast_ref::<u32>(),
+        Some(&12)
+    );
+    assert_eq!(scores.by_rank.len(), 1);
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Eq, Hash, Debug)]
+pub enum LogLevel {
+    Debug,
+    #[reflectix(rename = "warning")]
+    Warn,
+    Custom(u8),
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct LogConfig {
+    pub targets: std::collections::HashMap<LogLevel, String>,
+}
+
+#[test]
+fn test_enum_map_keys() {
+    let Data::Map { key, value } = &LogConfig::INFO.find_field("targets").unwrap().ty.data else {
+        unreachable!()
+    };
+    assert_eq!(*key, LogLevel::INFO);
+    assert_eq!(*value, String::INFO);
+
+    assert_eq!(LogLevel::from_unit_variant("warning"), Some(LogLevel::Warn));
+    assert_eq!(LogLevel::from_unit_variant("Warn"), None);
+    assert_eq!(LogLevel::from_unit_variant("Custom"), None);
+
+    let mut config = LogConfig::default();
+    let erased: &mut dyn TypeInfoDynamic = &mut config;
+    erased
+        .map_insert(
+            "targets".into(),
+            Box::new("Debug"),
+            Box::new("stderr".to_string()),
+        )
+        .unwrap();
+    erased
+        .map_insert(
+            "targets".into(),
+            Box::new(LogLevel::Warn),
+            Box::new("file".to_string()),
+        )
+        .unwrap();
+    assert!(matches!(
+        erased.map_insert("targets".into(), Box::new("Trace"), Box::new(String::new())),
+        Err(FieldAccessError::UnmatchingType)
+    ));
+
+    let targets = erased.field("targets".into()).unwrap();
+    assert_eq!(
+        targets
+            .field("warning".into())
+            .unwrap()
+            .downcast_ref::<String>()
+            .unwrap(),
+        "file"
+    );
+    assert_eq!(config.targets[&LogLevel::Debug], "stderr");
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Packet {
+    pub id: [u8; 4],
+    pub checksum: u32,
+}
+
+#[test]
+fn test_array_fields() {
+    let Data::Struct(Fields::Named(fields)) = &Packet::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(
+        fields[0].ty.data,
+        Data::Array {
+            element: TypeRef::of::<u8>(),
+            len: 4
+        }
+    );
+
+    let mut packet = Packet {
+        id: [1, 2, 3, 4],
+        checksum: 0,
+    };
+    *packet
+        .field_mut("id".into())
+        .unwrap()
+        .field_mut(2.into())
+        .unwrap()
+        .downcast_mut::<u8>()
+        .unwrap() = 9;
+    assert_eq!(packet.id, [1, 2, 9, 4]);
+    assert!(packet.field("id".into()).unwrap().field(4.into()).is_err());
+
+    let id = [0u8; 4]
+        .construct_struct((1..=4u8).map(|x| Box::new(x) as _).collect())
+        .unwrap();
+    assert_eq!(*id.downcast::<[u8; 4]>().unwrap(), [1, 2, 3, 4]);
+    assert!(matches!(
+        [0u8; 4].construct_struct(vec![Box::new(1u8)]),
+        Err(RuntimeConstructError::NotEnoughArgs)
+    ));
+
+    let constructed = packet
+        .construct_struct(vec![Box::new([5u8, 6, 7, 8]), Box::new(1u32)])
+        .unwrap();
+    assert_eq!(
+        *constructed.downcast::<Packet>().unwrap(),
+        Packet {
+            id: [5, 6, 7, 8],
+            checksum: 1
+        }
+    );
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug, Default, Clone, Copy)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Polygon {
+    pub corners: [Point; 4],
+}
+
+#[test]
+fn test_struct_array_fields() {
+    let corners = Polygon::INFO.find_field("corners").unwrap();
+    assert_eq!(
+        corners.ty.data,
+        Data::Array {
+            element: TypeRef::of::<Point>(),
+            len: 4
+        }
+    );
+
+    let mut polygon = Polygon {
+        corners: [Point::default(); 4],
+    };
+    assert_eq!(
+        polygon
+            .field("corners".into())
+            .unwrap()
+            .downcast_ref::<[Point; 4]>(),
+        Some(&[Point::default(); 4])
+    );
+    *polygon
+        .field_mut("corners".into())
+        .unwrap()
+        .field_mut(3.into())
+        .unwrap()
+        .field_mut("y".into())
+        .unwrap()
+        .downcast_mut::<i32>()
+        .unwrap() = -1;
+    assert_eq!(polygon.corners[3], Point { x: 0, y: -1 });
+
+    let square = polygon
+        .construct_struct(vec![Box::new([Point { x: 1, y: 1 }; 4])])
+        .unwrap();
+    assert_eq!(square.downcast::<Polygon>().unwrap().corners[0].x, 1);
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Edge {
+    pub ends: (u32, u32),
+    pub label: (String,),
+}
+
+#[test]
+fn test_tuple_fields() {
+    let ends = Edge::INFO.find_field("ends").unwrap();
+    assert_eq!(ends.ty.ident, "Tuple");
+    let Data::Tuple(Fields::Indexed(elements)) = &ends.ty.data else {
+        unreachable!()
+    };
+    assert_eq!(elements.len(), 2);
+    assert_eq!(elements[1].ty, u32::INFO);
+
+    let mut edge = Edge {
+        ends: (1, 2),
+        label: ("road".to_string(),),
+    };
+    *edge
+        .field_mut("ends".into())
+        .unwrap()
+        .field_mut(1.into())
+        .unwrap()
+        .downcast_mut::<u32>()
+        .unwrap() = 5;
+    assert_eq!(edge.ends, (1, 5));
+    assert!(edge.field("label".into()).unwrap().field(1.into()).is_err());
+
+    let constructed = edge
+        .construct_struct(vec![
+            Box::new((3u32, 4u32)),
+            Box::new(("rail".to_string(),)),
+        ])
+        .unwrap();
+    assert_eq!(
+        *constructed.downcast::<Edge>().unwrap(),
+        Edge {
+            ends: (3, 4),
+            label: ("rail".to_string(),)
+        }
+    );
+    assert!(matches!(
+        (0u8, 0u8).construct_struct(vec![Box::new(1u8), Box::new(2u16)]),
+        Err(RuntimeConstructError::UnexpectedType { index: 1, .. })
+    ));
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Task {
+    pub state: std::pin::Pin<Box<Inventory>>,
+}
+
+#[test]
+fn test_pinned_fields() {
+    assert_eq!(Task::INFO.find_field("state").unwrap().ty, Inventory::INFO);
+
+    let mut task = Task {
+        state: Box::pin(Inventory {
+            items: vec![4],
+            owner: None,
+        }),
+    };
+    let state = task.field("state".into()).unwrap();
+    assert_eq!(
+        state
+            .field("items".into())
+            .unwrap()
+            .downcast_ref::<Vec<u32>>(),
+        Some(&vec![4])
+    );
+    assert!(matches!(
+        task.field_mut("state".into())
+            .unwrap()
+            .field_mut("items".into()),
+        Err(FieldAccessError::Pinned)
+    ));
+
+    let constructed = task
+        .state
+        .construct_struct(vec![Box::new(vec![1u32]), Box::new(None::<String>)])
+        .unwrap();
+    let pinned = constructed
+        .downcast::<std::pin::Pin<Box<Inventory>>>()
+        .unwrap();
+    assert_eq!(pinned.items, vec![1]);
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Flags {
+    pub enabled: bool,
+    pub separator: char,
+    pub marker: (),
+    pub label: &'static str,
+}
+
+#[test]
+fn test_basic_primitives() {
+    assert_eq!(bool::INFO.ident, "bool");
+    assert_eq!(char::INFO.data, Data::Primitive);
+    assert_eq!(<()>::INFO.data, Data::Unit);
+    assert_eq!(<&'static str>::INFO.ident, "str");
+
+    let flags = Flags {
+        enabled: true,
+        separator: ',',
+        marker: (),
+        label: "csv",
+    };
+    let constructed = flags
+        .construct_struct(vec![
+            Box::new(false),
+            Box::new(';'),
+            Box::new(()),
+            Box::new("tsv"),
+        ])
+        .unwrap();
+    let constructed = constructed.downcast::<Flags>().unwrap();
+    assert_eq!(constructed.separator, ';');
+
+    let differences = diff(&flags, &*constructed);
+    assert_eq!(differences.len(), 3);
+    assert_eq!(
+        reflectix::hash::content_hash(&flags).unwrap(),
+        reflectix::hash::content_hash(&Flags { ..flags }).unwrap()
+    );
+}
+
+#[test]
+fn test_tuple_arity() {
+    type Twelve = (u8, u16, u32, u64, i8, i16, i32, i64, f32, f64, String, char);
+    let Data::Tuple(Fields::Indexed(elements)) = &Twelve::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(elements.len(), 12);
+    assert_eq!(elements[11].ty, char::INFO);
+
+    let value: Twelve = (1, 2, 3, 4, 5, 6, 7, 8, 9.0, 10.0, "eleven".to_string(), 'c');
+    assert_eq!(
+        value.field(10.into()).unwrap().downcast_ref::<String>(),
+        Some(&"eleven".to_string())
+    );
+    assert!(value.field(12.into()).is_err());
+
+    let args: Vec<Box<dyn std::any::Any>> = vec![Box::new(1u8)];
+    assert!(matches!(
+        (1u8,).construct_struct(vec![Box::new(7u8)]),
+        Ok(one) if one.downcast_ref::<(u8,)>() == Some(&(7,))
+    ));
+    assert!(value.construct_struct(args).is_err());
+}
+
+#[test]
+fn test_empty_array() {
+    let empty: [u32; 0] = [];
+    assert_eq!(
+        <[u32; 0]>::INFO.data,
+        Data::Array {
+            element: TypeRef::of::<u32>(),
+            len: 0
+        }
+    );
+    assert!(empty.field(0.into()).is_err());
+    let constructed = empty.construct_struct(Vec::new()).unwrap();
+    assert!(constructed.is::<[u32; 0]>());
+    assert!(empty.construct_struct(vec![Box::new(1u32)]).is_err());
+}
+
+#[test]
+fn test_optional_helpers() {
+    let mut inventory = Inventory {
+        items: vec![1],
+        owner: None,
+    };
+    assert!(!inventory
+        .field("owner".into())
+        .unwrap()
+        .as_dynamic()
+        .is_some()
+        .unwrap());
+    assert!(matches!(
+        inventory
+            .field("items".into())
+            .unwrap()
+            .as_dynamic()
+            .is_some(),
+        Err(FieldAccessError::UnmatchingType)
+    ));
+
+    inventory.owner = Some("ferris".to_string());
+    let owner = inventory.field_mut("owner".into()).unwrap();
+    let inner = owner.into_dynamic().as_some_mut().unwrap().unwrap();
+    inner.downcast_mut::<String>().unwrap().push('!');
+
+    let owner = inventory.field("owner".into()).unwrap();
+    let inner = owner.as_dynamic().as_some().unwrap().unwrap();
+    assert_eq!(inner.downcast_ref::<String>().unwrap(), "ferris!");
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Palette {
+    pub colors: Box<[u32]>,
+    pub names: &'static [&'static str],
+}
+
+#[test]
+fn test_slices() {
+    let Data::Struct(Fields::Named(fields)) = &Palette::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(fields[0].ty.data, Data::List(TypeRef::of::<u32>()));
+    assert_eq!(fields[1].ty.data, Data::List(TypeRef::of::<&'static str>()));
+
+    let mut palette = Palette {
+        colors: Box::new([0xff0000, 0x00ff00]),
+        names: &["red", "green"],
+    };
+    let erased: &mut dyn TypeInfoDynamic = &mut palette;
+    assert_eq!(erased.list_len("names".into()).unwrap(), 2);
+    assert_eq!(
+        erased
+            .field("names".into())
+            .unwrap()
+            .field(1.into())
+            .unwrap()
+            .downcast_ref::<&str>(),
+        Some(&"green")
+    );
+    assert!(matches!(
+        erased.list_push("names".into(), Box::new("blue")),
+        Err(FieldAccessError::ReadOnly)
+    ));
+
+    erased
+        .list_push("colors".into(), Box::new(0x0000ffu32))
+        .unwrap();
+    erased.list_remove("colors".into(), 0).unwrap();
+    assert_eq!(&*palette.colors, &[0x00ff00, 0x0000ff]);
+
+    let constructed = palette
+        .colors
+        .construct_struct(vec![Box::new(1u32)])
+        .unwrap();
+    assert_eq!(
+        &**constructed.downcast::<Box<[u32]>>().unwrap(),
+        &[1u32][..]
+    );
+}
+
+#[test]
+fn test_map_metadata() {
+    let Data::Map { key, value } = &Scores::INFO.find_field("by_rank").unwrap().ty.data else {
+        unreachable!()
+    };
+    assert_eq!(*key, u8::INFO);
+    assert_eq!(*value, String::INFO);
+    let by_rank = Scores::INFO.find_field("by_rank").unwrap().ty;
+    assert_eq!(by_rank.ident, "BTreeMap");
+    assert_eq!(
+        by_rank
+            .generics
+            .iter()
+            .map(|param| param.name)
+            .collect::<Vec<_>>(),
+        ["K", "V"]
+    );
+
+    let constructed = std::collections::BTreeMap::<u8, String>::new()
+        .construct_struct(vec![
+            Box::new(2u8),
+            Box::new("silver".to_string()),
+            Box::new(1u8),
+            Box::new("gold".to_string()),
+        ])
+        .unwrap();
+    let constructed = constructed
+        .downcast::<std::collections::BTreeMap<u8, String>>()
+        .unwrap();
+    assert_eq!(constructed.values().collect::<Vec<_>>(), ["gold", "silver"]);
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Attempt {
+    pub outcome: Result<u32, String>,
+}
+
+#[test]
+fn test_result() {
+    let Data::Enum(variants) = &<Result<u32, String>>::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(variants.variants.len(), 2);
+    assert_eq!(variants.variants[0].ident, "Ok");
+    assert_eq!(variants.variants[1].ident, "Err");
+
+    let mut attempt = Attempt { outcome: Ok(3) };
+    let outcome = attempt.field("outcome".into()).unwrap();
+    let outcome = outcome.as_dynamic();
+    assert_eq!(outcome.variant().unwrap().ident, "Ok");
+    assert_eq!(
+        outcome.field(0.into()).unwrap().downcast_ref::<u32>(),
+        Some(&3)
+    );
+
+    let failed = outcome
+        .construct_enum("Err", vec![Box::new("timeout".to_string())])
+        .unwrap();
+    assert_eq!(failed.as_dynamic().variant().unwrap().ident, "Err");
+    assert!(matches!(
+        outcome.construct_enum("Err", vec![Box::new(1u32)]),
+        Err(RuntimeConstructError::UnexpectedType { .. })
+    ));
+    attempt.outcome = *failed.downcast::<Result<u32, String>>().unwrap();
+
+    let outcome = attempt.field_mut("outcome".into()).unwrap();
+    let error = outcome.into_dynamic().field_mut(0.into()).unwrap();
+    error.downcast_mut::<String>().unwrap().push('!');
+    assert_eq!(attempt.outcome, Err("timeout!".to_string()));
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Shared {
+    pub local: std::rc::Rc<Inventory>,
+    pub global: std::sync::Arc<Inventory>,
+}
+
+#[test]
+fn test_shared_pointers() {
+    assert_eq!(
+        Shared::INFO.find_field("local").unwrap().ty,
+        Inventory::INFO
+    );
+    assert_eq!(
+        Shared::INFO.find_field("global").unwrap().ty,
+        Inventory::INFO
+    );
+
+    let inventory = Inventory {
+        items: vec![4],
+        owner: None,
+    };
+    let mut shared = Shared {
+        local: std::rc::Rc::new(inventory),
+        global: std::sync::Arc::new(Inventory {
+            items: vec![],
+            owner: None,
+        }),
+    };
+    shared
+        .field_mut("local".into())
+        .unwrap()
+        .field_mut("items".into())
+        .unwrap()
+        .downcast_mut::<Vec<u32>>()
+        .unwrap()
+        .push(5);
+    assert_eq!(shared.local.items, vec![4, 5]);
+
+    let other = shared.global.clone();
+    assert!(matches!(
+        shared
+            .field_mut("global".into())
+            .unwrap()
+            .field_mut("items".into()),
+        Err(FieldAccessError::ReadOnly)
+    ));
+    drop(other);
+
+    let constructed = shared
+        .global
+        .construct_struct(vec![Box::new(vec![1u32]), Box::new(None::<String>)])
+        .unwrap();
+    let global = constructed.downcast::<std::sync::Arc<Inventory>>().unwrap();
+    assert_eq!(global.items, vec![1]);
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Boxed {
+    pub count: Box<u32>,
+    pub name: std::rc::Rc<String>,
+    pub pinned: std::pin::Pin<Box<i64>>,
+}
+
+fn boxed(count: u32, name: &str) -> Boxed {
+    Boxed {
+        count: Box::new(count),
+        name: std::rc::Rc::new(name.to_string()),
+        pinned: Box::pin(-1),
+    }
+}
+
+#[test]
+fn test_wrapped_leaves() {
+    let (left, right) = (boxed(1, "left"), boxed(2, "left"));
+    assert_ne!(
+        reflectix::hash::content_hash(&left).unwrap(),
+        reflectix::hash::content_hash(&right).unwrap()
+    );
+    assert_eq!(
+        reflectix::hash::content_hash(&left).unwrap(),
+        reflectix::hash::content_hash(&boxed(1, "left")).unwrap()
+    );
+    let differences = diff(&left, &right);
+    assert_eq!(differences.len(), 1);
+    assert_eq!(differences[0].path, "count");
+    assert_eq!(
+        (differences[0].left.as_str(), differences[0].right.as_str()),
+        ("1", "2")
+    );
+
+    assert_eq!(
+        reflectix::key::extract_key(&left, "name").unwrap(),
+        reflectix::key::KeyValue::Str("left".to_string())
+    );
+    assert_eq!(
+        reflectix::query::to_query_string(&left).unwrap(),
+        "count=1&name=left&pinned=-1"
+    );
+    assert_eq!(
+        reflectix::csv::to_csv(&[&left, &right]).unwrap(),
+        "count,name,pinned\n1,left,-1\n2,left,-1\n"
+    );
+
+    // wrapped leaves are copied in place, unless wrapper doesn't allow to mutate them
+    let mut copy = boxed(0, "copy");
+    copy.field_mut("count".into())
+        .unwrap()
+        .clone_from_dynamic(&right.count)
+        .unwrap();
+    assert_eq!(*copy.count, 2);
+    let shared = copy.name.clone();
+    assert!(matches!(
+        copy.field_mut("name".into())
+            .unwrap()
+            .clone_from_dynamic(&right.name),
+        Err(reflectix::apply::ApplyError::Access(
+            FieldAccessError::ReadOnly
+        ))
+    ));
+    drop(shared);
+    copy.field_mut("name".into())
+        .unwrap()
+        .clone_from_dynamic(&right.name)
+        .unwrap();
+    assert_eq!(*copy.name, "left");
+    assert!(matches!(
+        copy.clone_from_dynamic(&right),
+        Err(reflectix::apply::ApplyError::Access(
+            FieldAccessError::Pinned
+        ))
+    ));
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Endpoint {
+    pub host: std::borrow::Cow<'static, str>,
+    pub ports: std::borrow::Cow<'static, [u16]>,
+}
+
+#[test]
+fn test_cow() {
+    assert_eq!(Endpoint::INFO.find_field("host").unwrap().ty, String::INFO);
+    assert_eq!(
+        Endpoint::INFO.find_field("ports").unwrap().ty,
+        <Vec<u16>>::INFO
+    );
+
+    let mut endpoint = Endpoint {
+        host: "localhost".into(),
+        ports: std::borrow::Cow::Borrowed(&[80]),
+    };
+    let other = Endpoint {
+        host: "example.com".into(),
+        ports: vec![80].into(),
+    };
+    let changes = diff(&endpoint, &other);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].path, "host");
+
+    let endpoint_dyn: &mut dyn TypeInfoDynamic = &mut endpoint;
+    endpoint_dyn
+        .list_push("ports".into(), Box::new(443u16))
+        .unwrap();
+    assert!(matches!(endpoint.ports, std::borrow::Cow::Owned(_)));
+    assert_eq!(&*endpoint.ports, &[80, 443]);
+
+    let constructed = endpoint
+        .ports
+        .construct_struct(vec![Box::new(8080u16)])
+        .unwrap();
+    let ports = constructed
+        .downcast::<std::borrow::Cow<'static, [u16]>>()
+        .unwrap();
+    assert_eq!(&**ports, &[8080]);
+}
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug)]
+pub struct Server {
+    pub timeout: std::time::Duration,
+    pub started: std::time::SystemTime,
+    pub root: std::path::PathBuf,
+    pub address: std::net::IpAddr,
+}
+
+#[test]
+fn test_std_leaves() {
+    let field_ty = |name| Server::INFO.find_field(name).unwrap().ty.get();
+    assert_eq!(field_ty("timeout").ident, "Duration");
+    assert_eq!(field_ty("address").data, Data::Primitive);
+
+    let server = Server {
+        timeout: std::time::Duration::from_secs(30),
+        started: std::time::UNIX_EPOCH,
+        root: "/srv".into(),
+        address: std::net::Ipv4Addr::LOCALHOST.into(),
+    };
+    let mut copy = server.clone();
+    copy.address = std::net::Ipv6Addr::LOCALHOST.into();
+    let changes = diff(&server, &copy);
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].right, "::1");
+    assert_ne!(
+        reflectix::hash::content_hash(&server).unwrap(),
+        reflectix::hash::content_hash(&copy).unwrap()
+    );
+
+    copy.clone_from_dynamic(&server).unwrap();
+    assert_eq!(copy, server);
+}
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug)]
+pub struct Offset {
+    pub shard: std::num::NonZeroU16,
+    pub delta: std::num::NonZeroI32,
+}
+
+#[test]
+fn test_nonzero_fields() {
+    let field_ty = |name| Offset::INFO.find_field(name).unwrap().ty.get();
+    assert_eq!(field_ty("delta").ident, "NonZeroI32");
+    assert_eq!(field_ty("delta").data, Data::Primitive);
+
+    let offset = Offset {
+        shard: std::num::NonZeroU16::new(3).unwrap(),
+        delta: std::num::NonZeroI32::new(-5).unwrap(),
+    };
+    assert_eq!(
+        reflectix::key::extract_key(&offset, "delta").unwrap(),
+        reflectix::key::KeyValue::Int(-5)
+    );
+
+    let mut copy = Offset {
+        shard: std::num::NonZeroU16::MAX,
+        delta: std::num::NonZeroI32::MIN,
+    };
+    copy.clone_from_dynamic(&offset).unwrap();
+    assert_eq!(copy, offset);
+}
+
+#[test]
+fn test_diff_nonzero_fields() {
+    let left = Offset {
+        shard: std::num::NonZeroU16::new(1).unwrap(),
+        delta: std::num::NonZeroI32::new(-5).unwrap(),
+    };
+    let right = Offset {
+        shard: std::num::NonZeroU16::new(2).unwrap(),
+        ..left.clone()
+    };
+    let differences = diff(&left, &right)
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(differences, ["shard: 1 != 2"]);
+    assert!(diff(&left, &left.clone()).is_empty());
+}
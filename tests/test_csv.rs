@@ -0,0 +1,107 @@
+use reflectix::csv::{from_csv, to_csv, CsvError};
+use reflectix::{RuntimeConstructError, TypeInfoDynamic};
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug, Default)]
+pub struct Employee {
+    pub id: u32,
+    pub name: String,
+    pub salary: f64,
+    pub level: u8,
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Team {
+    pub lead: Employee,
+}
+
+fn employees() -> Vec<Employee> {
+    vec![
+        Employee {
+            id: 1,
+            name: "Doe, Jane".to_string(),
+            salary: 1200.5,
+            level: 3,
+        },
+        Employee {
+            id: 2,
+            name: "John \"JD\" Doe".to_string(),
+            salary: 900.0,
+            level: 1,
+        },
+    ]
+}
+
+#[test]
+fn test_csv_roundtrip() {
+    let employees = employees();
+    let rows = employees
+        .iter()
+        .map(|row| row as &dyn TypeInfoDynamic)
+        .collect::<Vec<_>>();
+
+    let table = to_csv(&rows).unwrap();
+    assert_eq!(
+        table,
+        "id,name,salary,level\n1,\"Doe, Jane\",1200.5,3\n2,\"John \"\"JD\"\" Doe\",900,1\n"
+    );
+
+    let parsed = from_csv(&Employee::default(), table.as_bytes())
+        .unwrap()
+        .into_iter()
+        .map(|row| *row.downcast::<Employee>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, employees);
+}
+
+#[test]
+fn test_csv_columns() {
+    let table = "name,level,salary,id\r\nAlice,2,10,7\r\n";
+    let parsed = from_csv(&Employee::default(), table.as_bytes()).unwrap();
+    assert_eq!(parsed[0].downcast_ref::<Employee>().unwrap().id, 7);
+
+    let unknown = "id,name,salary,level,age\n1,Bob,1,2,30\n";
+    assert!(matches!(
+        from_csv(&Employee::default(), unknown.as_bytes()),
+        Err(CsvError::Construct {
+            line: 2,
+            error: RuntimeConstructError::UnknownField(_)
+        })
+    ));
+
+    let invalid = "id,name,salary,level\n1,Bob,1,2\nx,Bob,1,2\n";
+    assert!(matches!(
+        from_csv(&Employee::default(), invalid.as_bytes()),
+        Err(CsvError::Parse { line: 3, .. })
+    ));
+
+    assert!(matches!(
+        to_csv(&[&Team::default()]),
+        Err(CsvError::Unsupported { .. })
+    ));
+}
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug, Default)]
+pub struct Tag {
+    pub name: String,
+}
+
+#[test]
+fn test_csv_single_empty_column() {
+    let tags = ["a", "", "b"].map(|name| Tag {
+        name: name.to_string(),
+    });
+    let rows = tags
+        .iter()
+        .map(|row| row as &dyn TypeInfoDynamic)
+        .collect::<Vec<_>>();
+
+    let table = to_csv(&rows).unwrap();
+    assert_eq!(table, "name\na\n\"\"\nb\n");
+
+    let parsed = from_csv(&Tag::default(), table.as_bytes())
+        .unwrap()
+        .into_iter()
+        .map(|row| *row.downcast::<Tag>().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, tags);
+}
@@ -10,3 +10,447 @@ pub struct Test {
 pub fn test_name() {
     assert_eq!(Test::INFO.ident, "Test")
 }
+
+static LOOKUP: Test = Test { a: -1, b: 1 };
+
+#[test]
+pub fn test_static_reference() {
+    assert_eq!(<&'static Test as TypeInfo>::INFO, Test::INFO);
+
+    let mut reference: &'static Test = &LOOKUP;
+    let erased: &mut dyn TypeInfoDynamic = &mut reference;
+
+    assert_eq!(erased.get_dynamic().ident, "Test");
+    assert_eq!(
+        erased.field("a".into()).unwrap().downcast_ref::<i32>(),
+        Some(&-1)
+    );
+    assert!(matches!(
+        erased.field_mut("a".into()),
+        Err(FieldAccessError::ReadOnly)
+    ));
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Borrowed {
+    pub lookup: &'static Test,
+    pub counter: &'static mut u32,
+}
+
+#[test]
+pub fn test_reference_fields() {
+    let fields = match &Borrowed::INFO.data {
+        Data::Struct(fields) => fields.as_slice(),
+        _ => unreachable!(),
+    };
+    assert_eq!(fields[0].ty, Test::INFO);
+    assert_eq!(fields[0].ownership, Ownership::Shared);
+    assert_eq!(fields[1].ownership, Ownership::Mutable);
+    assert_eq!(
+        Test::INFO.find_field("a").unwrap().ownership,
+        Ownership::Owned
+    );
+
+    let mut borrowed = Borrowed {
+        lookup: &LOOKUP,
+        counter: Box::leak(Box::new(0)),
+    };
+    let lookup = borrowed.field("lookup".into()).unwrap();
+    assert!(std::ptr::eq(
+        *lookup.downcast_ref::<&'static Test>().unwrap(),
+        &LOOKUP
+    ));
+    assert_eq!(
+        lookup.field("b".into()).unwrap().downcast_ref::<u32>(),
+        Some(&1)
+    );
+
+    **borrowed
+        .field_mut("counter".into())
+        .unwrap()
+        .downcast_mut::<&'static mut u32>()
+        .unwrap() += 1;
+    assert_eq!(*borrowed.counter, 1);
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Pair(pub String, pub u32);
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub enum Shape {
+    Empty,
+    Circle(u32),
+    Rect { w: u32, h: u32 },
+}
+
+#[test]
+pub fn test_tuple_struct() {
+    let pair = Pair("a".to_string(), 1);
+    assert_eq!(
+        pair.field(1.into()).unwrap().downcast_ref::<u32>(),
+        Some(&1)
+    );
+
+    let constructed = pair
+        .construct_struct(vec![Box::new("b".to_string()), Box::new(2u32)])
+        .unwrap();
+    assert_eq!(
+        *constructed.downcast::<Pair>().unwrap(),
+        Pair("b".to_string(), 2)
+    );
+}
+
+#[test]
+pub fn test_enum() {
+    let mut rect = Shape::Rect { w: 1, h: 2 };
+    assert_eq!(
+        rect.field("h".into()).unwrap().downcast_ref::<u32>(),
+        Some(&2)
+    );
+    assert!(matches!(
+        Shape::Empty.field("h".into()),
+        Err(FieldAccessError::Unit)
+    ));
+
+    let erased: &mut dyn TypeInfoDynamic = &mut rect;
+    erased.set_field("w".into(), Box::new(10u32)).unwrap();
+    assert_eq!(rect, Shape::Rect { w: 10, h: 2 });
+
+    let circle = rect.construct_enum("Circle", vec![Box::new(5u32)]).unwrap();
+    assert_eq!(*circle.downcast::<Shape>().unwrap(), Shape::Circle(5));
+}
+
+#[test]
+pub fn test_variant_args() {
+    let Data::Enum(variants) = &Shape::INFO.data else {
+        unreachable!()
+    };
+    let rect = variants
+        .variants
+        .iter()
+        .find(|x| x.ident == "Rect")
+        .unwrap();
+
+    assert!(rect.check_args(&[&1u32, &2u32]).is_ok());
+    assert!(matches!(
+        rect.check_args(&[&1u32]),
+        Err(RuntimeConstructError::NotEnoughArgs)
+    ));
+    assert!(matches!(
+        rect.check_args(&[&1u32, &"2"]),
+        Err(RuntimeConstructError::UnexpectedType {
+            index: 1,
+            field: FieldId::Named("h"),
+            variant: Some("Rect"),
+            ..
+        })
+    ));
+
+    let err = Shape::Empty
+        .construct_enum("Rect", vec![Box::new(1u32), Box::new(2u8)])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Argument 1 doesn't match field `h` of type `u32` in variant `Rect`"
+    );
+}
+
+#[test]
+pub fn test_payload() {
+    let mut circle = Shape::Circle(5);
+    let erased: &mut dyn TypeInfoDynamic = &mut circle;
+
+    assert_eq!(erased.payload().unwrap().downcast_ref::<u32>(), Some(&5));
+    *erased.payload_mut().unwrap().downcast_mut::<u32>().unwrap() = 6;
+    assert_eq!(circle, Shape::Circle(6));
+
+    let rect: &dyn TypeInfoDynamic = &Shape::Rect { w: 1, h: 2 };
+    assert!(rect.payload().is_none());
+    assert!((&Shape::Empty as &dyn TypeInfoDynamic).payload().is_none());
+}
+
+#[derive(reflectix::TypeInfo)]
+#[repr(u8)]
+pub enum Opcode {
+    Nop = 10,
+    Load,
+    Store = 12,
+}
+
+#[derive(reflectix::TypeInfo)]
+#[repr(i16)]
+pub enum Sparse {
+    Low = -5,
+    High = 5,
+}
+
+#[test]
+pub fn test_discriminant_repr() {
+    let (Data::Enum(opcode), Data::Enum(sparse), Data::Enum(shape)) =
+        (&Opcode::INFO.data, &Sparse::INFO.data, &Shape::INFO.data)
+    else {
+        panic!("not enums");
+    };
+
+    assert_eq!(opcode.repr, DiscriminantRepr::U8);
+    assert!(opcode.contiguous);
+
+    assert!(sparse.repr.is_signed());
+    assert!(!sparse.contiguous);
+
+    assert_eq!(shape.repr, DiscriminantRepr::Isize);
+    assert!(shape.contiguous);
+}
+
+#[derive(reflectix::TypeInfo)]
+#[repr(u8)]
+pub enum Status {
+    Active = 1,
+    Blocked = 10,
+    Deleted,
+}
+
+#[test]
+pub fn test_discriminator() {
+    let discriminators = |ty: &Type| match &ty.data {
+        Data::Enum(variants) => variants
+            .variants
+            .iter()
+            .map(|variant| variant.discriminator)
+            .collect::<Vec<_>>(),
+        _ => panic!("not enum"),
+    };
+
+    assert_eq!(discriminators(Opcode::INFO), [10, 11, 12]);
+    assert_eq!(discriminators(Sparse::INFO), [-5, 5]);
+    assert_eq!(discriminators(Shape::INFO), [0, 1, 2]);
+    assert_eq!(discriminators(Status::INFO), [1, 10, 11]);
+
+    let Data::Enum(variants) = &Status::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(variants.find_by_discriminator(11).unwrap().ident, "Deleted");
+    assert!(variants.find_by_discriminator(2).is_none());
+
+    let blocked: &dyn TypeInfoDynamic = &Status::Blocked;
+    assert_eq!(blocked.discriminator(), Some(10));
+    let pair: &dyn TypeInfoDynamic = &Pair("a".to_string(), 1);
+    assert_eq!(pair.discriminator(), None);
+}
+
+#[derive(reflectix::TypeInfo)]
+#[repr(i128)]
+pub enum Extreme {
+    Min = -170141183460469231731687303715884105728,
+    Max = 170141183460469231731687303715884105727,
+}
+
+#[test]
+pub fn test_extreme_discriminants() {
+    let Data::Enum(variants) = &Extreme::INFO.data else {
+        unreachable!()
+    };
+    let discriminators = variants
+        .variants
+        .iter()
+        .map(|variant| variant.discriminator)
+        .collect::<Vec<_>>();
+    assert_eq!(discriminators, [i128::MIN, i128::MAX]);
+    assert!(!variants.contiguous);
+}
+
+#[test]
+pub fn test_variant_helpers() {
+    let Data::Enum(variants) = &Shape::INFO.data else {
+        unreachable!()
+    };
+    let rect = variants.find("Rect").unwrap();
+    assert_eq!(rect.field_count(), 2);
+    assert!(!rect.is_unit());
+    assert!(variants.find("Empty").unwrap().is_unit());
+    assert!(variants.find("Square").is_none());
+
+    assert_eq!(variants.iter().len(), 3);
+    let counts = variants
+        .into_iter()
+        .map(|variant| variant.field_count())
+        .collect::<Vec<_>>();
+    assert_eq!(counts, [0, 1, 2]);
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Nested {
+    pub pair: Pair,
+    pub shape: Shape,
+}
+
+#[test]
+pub fn test_resolve_path() {
+    let field = Nested::INFO.resolve_path("pair.1").unwrap();
+    assert_eq!(field.id, FieldId::Index(1));
+    assert_eq!(field.ty, u32::INFO);
+
+    assert_eq!(Nested::INFO.resolve_path("shape.w").unwrap().ty, u32::INFO);
+    assert!(Nested::INFO.resolve_path("pair.2").is_none());
+    assert!(Nested::INFO.resolve_path("shape.w.x").is_none());
+}
+
+#[test]
+pub fn test_chained_projection() {
+    let mut nested = Nested {
+        pair: Pair("a".to_string(), 1),
+        shape: Shape::Circle(2),
+    };
+
+    let radius = nested
+        .field("shape".into())
+        .and_then(|shape| shape.field(0.into()))
+        .unwrap();
+    assert_eq!(radius.downcast_ref::<u32>(), Some(&2));
+
+    let first = nested
+        .field_mut("pair".into())
+        .and_then(|pair| pair.field_mut(0.into()))
+        .unwrap();
+    first.set(Box::new("b".to_string())).unwrap();
+    assert_eq!(nested.pair.0, "b");
+}
+
+#[test]
+pub fn test_owned_value() {
+    let prototype = Pair(String::new(), 0);
+    let pair = prototype
+        .construct_struct(vec![Box::new("a".to_string()), Box::new(1u32)])
+        .unwrap();
+
+    assert!(pair.is::<Pair>());
+    assert_eq!(pair.get_dynamic().ident, "Pair");
+    assert_eq!(
+        pair.as_dynamic()
+            .field(1.into())
+            .unwrap()
+            .downcast_ref::<u32>(),
+        Some(&1)
+    );
+
+    let circle = Shape::Empty
+        .construct_enum("Circle", vec![Box::new(1u32)])
+        .unwrap();
+    let constructed = Nested {
+        pair: Pair(String::new(), 0),
+        shape: Shape::Empty,
+    }
+    .construct_struct(vec![pair.into_any(), circle.into()])
+    .unwrap();
+
+    let constructed = constructed.downcast::<Nested>().unwrap();
+    assert_eq!(constructed.pair, Pair("a".to_string(), 1));
+    assert_eq!(constructed.shape, Shape::Circle(1));
+}
+
+mod geometry {
+    #[derive(reflectix::TypeInfo, PartialEq, Debug)]
+    pub struct Point {
+        pub x: i32,
+    }
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Qualified {
+    pub name: std::string::String,
+    pub origin: geometry::Point,
+    pub count: ::core::primitive::u16,
+}
+
+#[test]
+pub fn test_qualified_paths() {
+    let Data::Struct(Fields::Named(fields)) = &Qualified::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(fields[0].ty, String::INFO);
+    assert_eq!(fields[1].ty.ident, "Point");
+    assert_eq!(fields[2].ty, u16::INFO);
+
+    let value = Qualified {
+        name: "a".to_string(),
+        origin: geometry::Point { x: 1 },
+        count: 2,
+    };
+    let err = value
+        .construct_struct(vec![
+            Box::new("b".to_string()),
+            Box::new(0u8),
+            Box::new(3u16),
+        ])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Argument 1 doesn't match field `origin` of type `Point`"
+    );
+}
+
+#[derive(reflectix::TypeInfo)]
+#[repr(C)]
+pub union Word {
+    pub bits: u32,
+    pub value: f32,
+}
+
+#[test]
+pub fn test_union_metadata() {
+    let Data::Union(Fields::Named(fields)) = &Word::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(fields.len(), 2);
+    assert_eq!(Word::INFO.find_field("value").unwrap().ty, f32::INFO);
+
+    let mut word = Word { bits: 0 };
+    assert!(matches!(
+        word.field("bits".into()),
+        Err(FieldAccessError::Union)
+    ));
+    assert!(matches!(
+        word.field_mut("value".into()),
+        Err(FieldAccessError::Union)
+    ));
+    assert!(matches!(
+        word.construct_struct(vec![Box::new(1u32)]),
+        Err(RuntimeConstructError::Union)
+    ));
+}
+
+#[derive(reflectix::TypeInfo)]
+#[repr(C, align(16))]
+pub struct Aligned {
+    pub tag: u8,
+    pub value: u64,
+}
+
+#[test]
+pub fn test_layout() {
+    assert_eq!(Aligned::INFO.size, std::mem::size_of::<Aligned>());
+    assert_eq!(Aligned::INFO.align, 16);
+    assert_eq!(Word::INFO.size, 4);
+    assert_eq!(Shape::INFO.size, std::mem::size_of::<Shape>());
+
+    assert_eq!(u16::INFO.size, 2);
+    assert_eq!(<Vec<u8>>::INFO.size, std::mem::size_of::<Vec<u8>>());
+    assert_eq!(<[u32; 3]>::INFO.size, 12);
+    assert_eq!(<[u32; 3]>::INFO.align, 4);
+}
+
+#[test]
+pub fn test_type_id() {
+    assert_eq!(Pair::INFO.type_id, std::any::TypeId::of::<Pair>());
+    assert_eq!(
+        <Option<u8>>::INFO.type_id,
+        std::any::TypeId::of::<Option<u8>>()
+    );
+
+    let field = Pair::INFO.find_field("1").unwrap();
+    assert_eq!(field.ty.type_id, field.type_id);
+
+    let value: Box<dyn std::any::Any> = Box::new(Pair("a".to_string(), 1));
+    assert!(Pair::INFO.describes(&*value));
+    assert!(!Shape::INFO.describes(&*value));
+}
@@ -0,0 +1,180 @@
+use reflectix::assert_reflect_eq;
+use reflectix::diff::{diff, Difference};
+
+#[derive(reflectix::TypeInfo, Clone)]
+pub struct Inner {
+    pub value: u32,
+    pub label: String,
+}
+
+#[derive(reflectix::TypeInfo, Clone)]
+pub enum State {
+    Idle,
+    Running { ticks: u64 },
+}
+
+#[derive(reflectix::TypeInfo, Clone)]
+pub struct Outer {
+    pub inner: Inner,
+    pub state: State,
+    pub ratio: f32,
+}
+
+fn sample() -> Outer {
+    Outer {
+        inner: Inner {
+            value: 1,
+            label: "one".to_string(),
+        },
+        state: State::Running { ticks: 10 },
+        ratio: 0.5,
+    }
+}
+
+#[test]
+fn test_equal_values() {
+    assert!(diff(&sample(), &sample()).is_empty());
+    assert_reflect_eq!(sample(), sample());
+}
+
+#[test]
+fn test_nested_differences() {
+    let mut changed = sample();
+    changed.inner.label = "two".to_string();
+    changed.state = State::Running { ticks: 11 };
+
+    assert_eq!(
+        diff(&sample(), &changed),
+        vec![
+            Difference {
+                path: "inner.label".to_string(),
+                left: "one".to_string(),
+                right: "two".to_string(),
+            },
+            Difference {
+                path: "state.ticks".to_string(),
+                left: "10".to_string(),
+                right: "11".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_variant_difference() {
+    let mut changed = sample();
+    changed.state = State::Idle;
+
+    let differences = diff(&sample(), &changed);
+    assert_eq!(differences.len(), 1);
+    assert_eq!(
+        differences[0].to_string(),
+        "state: State::Running != State::Idle"
+    );
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Scores {
+    pub by_name: std::collections::HashMap<String, u32>,
+}
+
+#[test]
+fn test_map_differences() {
+    let scores = |entries: &[(&str, u32)]| Scores {
+        by_name: entries
+            .iter()
+            .map(|(name, score)| (name.to_string(), *score))
+            .collect(),
+    };
+    let left = scores(&[("ann", 1), ("bob", 2), ("cid", 3)]);
+    assert!(diff(&left, &scores(&[("cid", 3), ("bob", 2), ("ann", 1)])).is_empty());
+
+    let right = scores(&[("ann", 1), ("bob", 5), ("dan", 4)]);
+    assert_eq!(
+        diff(&left, &right)
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        [
+            "by_name.bob: 2 != 5",
+            "by_name.cid: 3 != <missing>",
+            "by_name.dan: <missing> != 4",
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "by_name.bob: 2 != 3")]
+fn test_assert_reports_map_entry() {
+    let scores = |score| Scores {
+        by_name: [("bob".to_string(), score)].into_iter().collect(),
+    };
+    assert_reflect_eq!(scores(2), scores(3));
+}
+
+#[test]
+#[should_panic(expected = "inner.value: 1 != 2")]
+fn test_assert_reports_path() {
+    let mut changed = sample();
+    changed.inner.value = 2;
+
+    assert_reflect_eq!(sample(), changed);
+}
+
+#[test]
+fn test_matches_shape() {
+    use reflectix::dynamic::{matches_shape, DynamicStruct};
+
+    let outer = sample();
+    let running = State::Running { ticks: 10 };
+    assert!(matches_shape(&outer, &DynamicStruct::new()));
+    assert!(matches_shape(
+        &outer,
+        &DynamicStruct::new()
+            .with("ratio", &0.5f32)
+            .with("state", &running)
+    ));
+    assert!(!matches_shape(
+        &outer,
+        &DynamicStruct::new().with("state", &State::Idle)
+    ));
+    // type of pattern field must match too
+    assert!(!matches_shape(
+        &outer,
+        &DynamicStruct::new().with("ratio", &0.5f64)
+    ));
+    assert!(!matches_shape(
+        &outer,
+        &DynamicStruct::new().with("missing", &1u8)
+    ));
+    assert!(matches_shape(
+        &outer.state,
+        &DynamicStruct::new().with("ticks", &10u64)
+    ));
+}
+
+#[test]
+fn test_project() {
+    use reflectix::dynamic::project;
+    use reflectix::{FieldAccessError, FieldId};
+
+    let outer = sample();
+    let projected = project(&outer, &["ratio", "inner"]).unwrap();
+    let ids = projected
+        .iter()
+        .map(|(id, _)| id.clone())
+        .collect::<Vec<_>>();
+    assert_eq!(ids, [FieldId::from("ratio"), FieldId::from("inner")]);
+    assert_eq!(projected.get_as::<f32>("ratio".into()).unwrap(), &0.5);
+    assert_eq!(
+        projected.get_as::<Inner>("inner".into()).unwrap().label,
+        "one"
+    );
+
+    let ticks = project(&outer.state, &["ticks"]).unwrap();
+    assert_eq!(ticks.get_as::<u64>("ticks".into()).unwrap(), &10);
+    assert!(matches!(
+        project(&outer, &["ratio", "missing"]),
+        Err(FieldAccessError::NotFound)
+    ));
+}
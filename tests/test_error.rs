@@ -0,0 +1,76 @@
+use std::error::Error;
+
+use reflectix::error::{ReflectError, ReflectErrorKind};
+use reflectix::*;
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Account {
+    pub id: u64,
+    pub name: String,
+}
+
+fn rename(account: &mut dyn TypeInfoDynamic, name: &'static str) -> Result<(), ReflectError> {
+    account
+        .field_mut(FieldId::Named(name))
+        .map_err(|err| {
+            ReflectError::from(err)
+                .with_type(Account::INFO.ident)
+                .with_field(FieldId::Named(name))
+        })?
+        .set(Box::new("renamed".to_string()))?;
+    Ok(())
+}
+
+#[test]
+fn test_reflect_error() {
+    let mut account = Account::default();
+    rename(&mut account, "name").unwrap();
+    assert_eq!(account.name, "renamed");
+
+    let error = rename(&mut account, "title").unwrap_err();
+    assert!(matches!(
+        error.kind(),
+        ReflectErrorKind::Access(FieldAccessError::NotFound)
+    ));
+    assert_eq!(
+        error.to_string(),
+        "Reflective operation failed at type `Account`, field `title`"
+    );
+    assert_eq!(error.source().unwrap().to_string(), "Field not found");
+
+    let error = ReflectError::from(
+        account
+            .construct_struct(vec![Box::new(1u64), Box::new(2u8)])
+            .unwrap_err(),
+    );
+    assert_eq!(error.field(), Some(&FieldId::Named("name")));
+    assert_eq!(error.ty(), None);
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Overdrawn(pub u64);
+
+impl std::fmt::Display for Overdrawn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "account is overdrawn by {}", self.0)
+    }
+}
+
+impl Error for Overdrawn {}
+
+#[test]
+fn test_custom_errors() {
+    let error = ReflectError::from(FieldAccessError::custom(Overdrawn(5)));
+    let ReflectErrorKind::Access(FieldAccessError::Custom(inner)) = error.kind() else {
+        unreachable!()
+    };
+    assert_eq!(inner.downcast_ref::<Overdrawn>(), Some(&Overdrawn(5)));
+    assert_eq!(
+        error.source().unwrap().to_string(),
+        "account is overdrawn by 5"
+    );
+
+    let error = RuntimeConstructError::custom("balance can't be negative");
+    assert!(matches!(error, RuntimeConstructError::Custom(_)));
+    assert_eq!(error.to_string(), "balance can't be negative");
+}
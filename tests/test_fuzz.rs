@@ -0,0 +1,87 @@
+#![cfg(feature = "fuzz")]
+use reflectix::fuzz::{arbitrary_case, check_case, exercise, Shape};
+use reflectix::TypeInfoDynamic;
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Point {
+    pub x: i32,
+    pub y: String,
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Body(pub Point, pub u8, pub f64);
+
+#[derive(reflectix::TypeInfo)]
+pub enum Event {
+    Tick,
+    Move { to: Point, speed: u16 },
+    Say(String, u64),
+}
+
+// deterministic stand-in for fuzzer input
+fn input(seed: u64, len: usize) -> Vec<u8> {
+    let mut state = seed;
+    (0..len)
+        .map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 56) as u8
+        })
+        .collect()
+}
+
+#[test]
+fn test_exercise_constructors() {
+    let event = Event::Move {
+        to: Point::default(),
+        speed: 0,
+    };
+    let prototypes: [&dyn TypeInfoDynamic; 3] = [&Point::default(), &Body::default(), &event];
+
+    for prototype in prototypes {
+        for seed in 0..64 {
+            exercise(prototype, &input(seed, 512)).unwrap();
+        }
+    }
+}
+
+#[test]
+fn test_extra_args_are_rejected() {
+    let prototype = Point::default();
+
+    let mut seen_extra = false;
+    for seed in 0..64 {
+        let data = input(seed, 64);
+        let mut u = arbitrary::Unstructured::new(&data);
+        let Ok(case) = arbitrary_case(&prototype, &mut u) else {
+            continue;
+        };
+        seen_extra |= matches!(case.shape, Shape::Extra { .. });
+        check_case(&prototype, case).unwrap();
+    }
+    assert!(seen_extra);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Config {
+    pub retries: u32,
+    #[reflectix(default)]
+    pub timeout: u32,
+}
+
+#[test]
+fn test_omitted_defaults_are_accepted() {
+    use reflectix::TypeInfo;
+
+    let fields = match &Config::INFO.data {
+        reflectix::Data::Struct(fields) => fields.as_slice(),
+        _ => unreachable!(),
+    };
+    assert!(!fields[0].has_default);
+    assert!(fields[1].has_default);
+
+    for seed in 0..200 {
+        exercise(&Config::default(), &input(seed, 512)).unwrap();
+    }
+}
@@ -0,0 +1,143 @@
+use std::marker::PhantomData;
+
+use reflectix::*;
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Pair<A, B> {
+    pub first: A,
+    pub second: B,
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Tagged<const N: usize> {
+    pub tag: u8,
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Id<T> {
+    pub raw: u64,
+    _marker: PhantomData<T>,
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub enum Marked<T> {
+    Value(PhantomData<T>, u32),
+    Empty(PhantomData<T>),
+}
+
+#[test]
+fn test_metadata_per_monomorphization() {
+    let Data::Struct(Fields::Named(fields)) = &<Pair<u8, String>>::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(fields[0].ty, u8::INFO);
+    assert_eq!(fields[1].ty, String::INFO);
+
+    let Data::Struct(Fields::Named(fields)) = &<Pair<f32, f64>>::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(fields[0].ty, f32::INFO);
+    assert_eq!(fields[1].ty, f64::INFO);
+
+    assert_eq!(Tagged::<3>::INFO.ident, "Tagged");
+}
+
+#[test]
+fn test_generic_access() {
+    let mut pair = Pair {
+        first: 1u8,
+        second: "a".to_string(),
+    };
+    let erased: &mut dyn TypeInfoDynamic = &mut pair;
+    erased.set_field("first".into(), Box::new(2u8)).unwrap();
+    assert_eq!(pair.first, 2);
+
+    let constructed = pair
+        .construct_struct(vec![Box::new(3u8), Box::new("b".to_string())])
+        .unwrap();
+    assert_eq!(
+        *constructed.downcast::<Pair<u8, String>>().unwrap(),
+        Pair {
+            first: 3,
+            second: "b".to_string()
+        }
+    );
+}
+
+#[test]
+fn test_generic_enum() {
+    let left: Either<u32, String> = Either::Left(5);
+    let erased: &dyn TypeInfoDynamic = &left;
+    assert_eq!(erased.payload().unwrap().downcast_ref::<u32>(), Some(&5));
+
+    let right = left
+        .construct_enum("Right", vec![Box::new("x".to_string())])
+        .unwrap();
+    assert_eq!(
+        *right.downcast::<Either<u32, String>>().unwrap(),
+        Either::Right("x".to_string())
+    );
+}
+
+#[test]
+fn test_phantom_fields() {
+    let Data::Struct(Fields::Named(fields)) = &<Id<String>>::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(fields.len(), 1);
+    assert_eq!(fields[0].id, FieldId::Named("raw"));
+
+    let id: Id<String> = Id {
+        raw: 7,
+        _marker: PhantomData,
+    };
+    let copy = id.construct_struct(vec![Box::new(9u64)]).unwrap();
+    assert_eq!(copy.downcast::<Id<String>>().unwrap().raw, 9);
+
+    let marked: Marked<String> = Marked::Value(PhantomData, 3);
+    assert_eq!(
+        marked
+            .field(FieldId::Index(1))
+            .unwrap()
+            .downcast_ref::<u32>(),
+        Some(&3)
+    );
+    let empty = marked.construct_enum("Empty", vec![]).unwrap();
+    assert_eq!(
+        *empty.downcast::<Marked<String>>().unwrap(),
+        Marked::Empty(PhantomData)
+    );
+    assert_eq!(
+        Marked::<String>::from_unit_variant("Empty"),
+        Some(Marked::Empty(PhantomData))
+    );
+}
+
+#[test]
+fn test_generic_params() {
+    let params = <Pair<i32, String>>::INFO.generics;
+    assert_eq!(params.len(), 2);
+    assert_eq!(params[0].name, "A");
+    assert!(matches!(&params[0].kind, GenericParamKind::Type(ty) if *ty == i32::INFO));
+    assert!(matches!(&params[1].kind, GenericParamKind::Type(ty) if *ty == String::INFO));
+    assert_ne!(
+        <Pair<i32, String>>::INFO.generics,
+        <Pair<String, String>>::INFO.generics
+    );
+
+    assert_eq!(
+        <Tagged<4>>::INFO.generics,
+        &[GenericParamInfo {
+            name: "N",
+            kind: GenericParamKind::Const(4),
+        }]
+    );
+    assert!(<Vec<u8>>::INFO.generics[0].kind == GenericParamKind::Type(TypeRef::of::<u8>()));
+    assert!(u8::INFO.generics.is_empty());
+}
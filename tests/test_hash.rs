@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use reflectix::hash::{canonical_bytes, content_hash};
+use reflectix::FieldAccessError;
+
+#[derive(reflectix::TypeInfo, Clone)]
+pub struct Asset {
+    pub path: String,
+    pub size: u64,
+    pub tags: HashMap<String, u32>,
+    pub parent: Option<String>,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Other {
+    pub path: String,
+    pub size: u64,
+}
+
+fn asset() -> Asset {
+    Asset {
+        path: "textures/grass.png".to_string(),
+        size: 4096,
+        tags: (0..16).map(|tag| (tag.to_string(), tag)).collect(),
+        parent: None,
+    }
+}
+
+#[test]
+fn test_content_hash() {
+    let original = asset();
+    // rebuilt map has the same contents, but may iterate in different order
+    let copy = Asset {
+        tags: original.tags.clone().into_iter().collect(),
+        ..original.clone()
+    };
+    assert_eq!(
+        content_hash(&original).unwrap(),
+        content_hash(&copy).unwrap()
+    );
+
+    let mut changed = original.clone();
+    changed.parent = Some("textures".to_string());
+    assert_ne!(
+        content_hash(&original).unwrap(),
+        content_hash(&changed).unwrap()
+    );
+
+    // same leaves under different type are different contents
+    let other = Other {
+        path: original.path.clone(),
+        size: original.size,
+    };
+    assert_ne!(
+        content_hash(&original).unwrap(),
+        content_hash(&other).unwrap()
+    );
+
+    // hash doesn't depend on process or compiler
+    assert_eq!(content_hash(&7u8).unwrap(), content_hash(&7u8).unwrap());
+    assert_eq!(content_hash(&String::new()).unwrap(), 0xadd3_d021_2304_4e66);
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Transfer {
+    pub to: String,
+    pub amount: u64,
+}
+
+fn push_str(bytes: &mut Vec<u8>, text: &str) {
+    bytes.extend((text.len() as u64).to_le_bytes());
+    bytes.extend(text.as_bytes());
+}
+
+#[test]
+fn test_canonical_bytes() {
+    let transfer = Transfer {
+        to: "bob".to_string(),
+        amount: 10,
+    };
+
+    let mut expected = Vec::new();
+    push_str(&mut expected, "Transfer");
+    expected.extend(2u64.to_le_bytes());
+    // fields are sorted by name
+    push_str(&mut expected, "amount");
+    push_str(&mut expected, "u64");
+    expected.extend(10u64.to_le_bytes());
+    push_str(&mut expected, "to");
+    push_str(&mut expected, "String");
+    push_str(&mut expected, "bob");
+    assert_eq!(canonical_bytes(&transfer).unwrap(), expected);
+
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for byte in &expected {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x100_0000_01b3);
+    }
+    assert_eq!(content_hash(&transfer).unwrap(), hash);
+}
+
+mod secret {
+    #[derive(reflectix::TypeInfo)]
+    #[reflectix(public_only)]
+    #[allow(dead_code)]
+    pub struct Secret {
+        pub id: u32,
+        key: String,
+    }
+
+    impl Secret {
+        pub fn new(key: &str) -> Self {
+            Self {
+                id: 1,
+                key: key.to_string(),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_inaccessible_field() {
+    // private field can't be read, so it's not silently left out of encoding
+    let secret = secret::Secret::new("hunter2");
+    assert!(matches!(
+        content_hash(&secret),
+        Err(FieldAccessError::Private)
+    ));
+    assert!(matches!(
+        canonical_bytes(&secret),
+        Err(FieldAccessError::Private)
+    ));
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "json")]
+use reflectix::*;
+use serde_json::{json, Value};
+
+#[test]
+pub fn test_json_value() {
+    let mut value = json!({
+        "name": "reflectix",
+        "tags": ["a", "b"],
+    });
+
+    assert_eq!(value.get_dynamic().ident, "Object");
+    let erased: &mut dyn TypeInfoDynamic = &mut value;
+
+    let tags = erased.field("tags".into()).unwrap().as_dynamic();
+    assert!(matches!(tags.get_dynamic().data, Data::List(_)));
+    assert_eq!(
+        tags.field(1.into()).unwrap().downcast_ref::<Value>(),
+        Some(&json!("b"))
+    );
+    assert_eq!(
+        tags.field(1.into()).unwrap().as_dynamic().get_dynamic(),
+        String::INFO
+    );
+
+    erased
+        .set_field("name".into(), Box::new(json!(42)))
+        .unwrap();
+    assert_eq!(value["name"], 42);
+}
+
+#[test]
+pub fn test_diff_json_scalars() {
+    use reflectix::diff::diff;
+
+    assert_eq!(diff(&json!(1), &json!(1)), vec![]);
+    let differences = diff(&json!(1), &json!(2));
+    assert_eq!(differences.len(), 1);
+    assert_eq!(differences[0].to_string(), "<root>: 1 != 2");
+
+    let differences = diff(&json!({"n": 1, "s": "x"}), &json!({"n": 2, "s": "y"}))
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>();
+    assert_eq!(differences, ["n: 1 != 2", "s: x != y"]);
+    assert!(diff(&json!({"n": 1, "s": "x"}), &json!({"n": 1, "s": "x"})).is_empty());
+}
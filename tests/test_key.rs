@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+use reflectix::key::{extract_key, group_by, KeyError, KeyValue};
+use reflectix::*;
+
+#[derive(reflectix::TypeInfo)]
+pub enum Tier {
+    Free,
+    Paid,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Address {
+    pub city: String,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Customer {
+    pub id: u32,
+    pub balance: i64,
+    pub score: f64,
+    pub tier: Tier,
+    pub address: Option<Address>,
+    pub tags: Vec<&'static str>,
+}
+
+fn customer() -> Customer {
+    Customer {
+        id: 7,
+        balance: -20,
+        score: f64::NAN,
+        tier: Tier::Paid,
+        address: Some(Address {
+            city: "Oslo".to_string(),
+        }),
+        tags: vec!["vip"],
+    }
+}
+
+#[test]
+fn test_extract_key() {
+    let mut customer = customer();
+    assert_eq!(extract_key(&customer, "id").unwrap(), KeyValue::UInt(7));
+    assert_eq!(
+        extract_key(&customer, "balance").unwrap(),
+        KeyValue::Int(-20)
+    );
+    assert_eq!(
+        extract_key(&customer, "tier").unwrap(),
+        KeyValue::Variant("Paid")
+    );
+    assert_eq!(
+        extract_key(&customer, "address.city").unwrap(),
+        KeyValue::Str("Oslo".to_string())
+    );
+    assert_eq!(
+        extract_key(&customer, "tags.0").unwrap(),
+        KeyValue::Str("vip".to_string())
+    );
+    assert_eq!(extract_key(&7u8, "").unwrap(), KeyValue::UInt(7));
+
+    customer.address = None;
+    assert_eq!(
+        extract_key(&customer, "address.city").unwrap(),
+        KeyValue::None
+    );
+    assert!(matches!(
+        extract_key(&customer, "tags"),
+        Err(KeyError::Unsupported("Vec"))
+    ));
+    assert!(matches!(
+        extract_key(&customer, "tags.1"),
+        Err(KeyError::Access(FieldAccessError::NotFound))
+    ));
+    assert!(matches!(
+        extract_key(&customer, "missing"),
+        Err(KeyError::NotFound { ty: "Customer", .. })
+    ));
+}
+
+#[test]
+fn test_key_order() {
+    let nan = extract_key(&customer(), "score").unwrap();
+    assert_eq!(nan, nan.clone());
+    assert!(KeyValue::Float(-0.0) < KeyValue::Float(0.0));
+    assert!(KeyValue::Float(1.0) < nan);
+    assert!(KeyValue::None < KeyValue::Int(i128::MIN));
+    assert!(KeyValue::Int(i128::MAX) < KeyValue::UInt(0));
+
+    let keys = [
+        KeyValue::Variant("Free"),
+        KeyValue::Variant("Free"),
+        nan.clone(),
+        nan,
+    ];
+    assert_eq!(keys.into_iter().collect::<HashSet<_>>().len(), 2);
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Invoice {
+    pub tier: Tier,
+    pub total: u64,
+}
+
+#[test]
+fn test_group_by() {
+    let free = Customer {
+        tier: Tier::Free,
+        ..customer()
+    };
+    let paid = customer();
+    let invoice = Invoice {
+        tier: Tier::Paid,
+        total: 100,
+    };
+    let records: [&dyn TypeInfoDynamic; 3] = [&paid, &free, &invoice];
+
+    let groups = group_by(records, "tier").unwrap();
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups[&KeyValue::Variant("Free")].len(), 1);
+    let paid_group = &groups[&KeyValue::Variant("Paid")];
+    assert_eq!(paid_group.len(), 2);
+    assert_eq!(paid_group[0].get_dynamic(), Customer::INFO);
+    assert_eq!(paid_group[1].get_dynamic(), Invoice::INFO);
+
+    assert!(matches!(
+        group_by(records, "total"),
+        Err(KeyError::NotFound { ty: "Customer", .. })
+    ));
+}
@@ -0,0 +1,152 @@
+use reflectix::*;
+
+// stands for a type generated by bindgen
+#[repr(C)]
+#[derive(PartialEq, Debug)]
+pub struct timeval {
+    pub tv_sec: i64,
+    pub tv_usec: i64,
+}
+
+reflectix::impl_type_info! {
+    struct timeval {
+        tv_sec: i64,
+        tv_usec: i64,
+    }
+}
+
+#[repr(C)]
+pub struct Header {
+    pub len: u32,
+    pub reserved: [u8; 4],
+}
+
+reflectix::impl_type_info! {
+    struct Header {
+        len: u32,
+        ..
+    }
+}
+
+#[test]
+fn test_manual_impl() {
+    assert_eq!(timeval::INFO.ident, "timeval");
+    let Data::Struct(Fields::Named(fields)) = &timeval::INFO.data else {
+        unreachable!()
+    };
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[1].ty, i64::INFO);
+
+    let mut time = timeval {
+        tv_sec: 1,
+        tv_usec: 0,
+    };
+    *time
+        .field_mut("tv_usec".into())
+        .unwrap()
+        .downcast_mut::<i64>()
+        .unwrap() = 500;
+    assert_eq!(time.tv_usec, 500);
+    assert!(time.field("tv_nsec".into()).is_err());
+
+    let constructed = time
+        .construct_struct(vec![Box::new(2i64), Box::new(3i64)])
+        .unwrap();
+    assert_eq!(
+        *constructed.downcast::<timeval>().unwrap(),
+        timeval {
+            tv_sec: 2,
+            tv_usec: 3
+        }
+    );
+    assert!(matches!(
+        time.construct_struct(vec![Box::new(2i64), Box::new(3u8)]),
+        Err(RuntimeConstructError::UnexpectedType { index: 1, .. })
+    ));
+}
+
+#[test]
+fn test_manual_partial_impl() {
+    let header = Header {
+        len: 8,
+        reserved: [0; 4],
+    };
+    assert!(Header::INFO.find_field("reserved").is_none());
+    assert_eq!(
+        header.field("len".into()).unwrap().downcast_ref::<u32>(),
+        Some(&8)
+    );
+    assert!(matches!(
+        header.construct_struct(vec![Box::new(1u32)]),
+        Err(RuntimeConstructError::PrivateFields)
+    ));
+}
+
+// hand-written impl, which mistakenly builds type it delegates to instead of itself
+pub struct Stamp(timeval);
+
+impl TypeInfoDynamic for Stamp {
+    fn get_dynamic(&self) -> &'static Type {
+        Self::INFO
+    }
+
+    fn construct_struct(
+        &self,
+        args: Vec<Box<dyn std::any::Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        self.0.construct_struct(args)
+    }
+
+    fn construct_enum(
+        &self,
+        _variant: &'static str,
+        _args: Vec<Box<dyn std::any::Any>>,
+    ) -> Result<UnsizeableOwned, RuntimeConstructError> {
+        Err(RuntimeConstructError::NotEnum)
+    }
+
+    fn field<'s>(&'s self, id: FieldId) -> Result<Unsizeable<'s>, FieldAccessError> {
+        self.0.field(id)
+    }
+
+    fn field_mut<'s>(&'s mut self, id: FieldId) -> Result<UnsizeableMut<'s>, FieldAccessError> {
+        self.0.field_mut(id)
+    }
+}
+
+impl TypeInfo for Stamp {
+    const INFO: &'static Type = timeval::INFO;
+}
+
+#[test]
+fn test_wrapper_of_foreign_constructor() {
+    let args = || -> Vec<Box<dyn std::any::Any>> { vec![Box::new(1i64), Box::new(2i64)] };
+    let stamp = || {
+        Stamp(timeval {
+            tv_sec: 0,
+            tv_usec: 0,
+        })
+    };
+
+    assert!(stamp().construct_struct(args()).is_ok());
+    assert!(matches!(
+        Box::new(stamp()).construct_struct(args()),
+        Err(RuntimeConstructError::ForeignValue { .. })
+    ));
+    assert!(matches!(
+        std::sync::Arc::new(stamp()).construct_struct(args()),
+        Err(RuntimeConstructError::ForeignValue { .. })
+    ));
+    assert!(matches!(
+        Box::pin(stamp()).construct_struct(args()),
+        Err(RuntimeConstructError::ForeignValue { .. })
+    ));
+    assert!(matches!(
+        std::sync::Mutex::new(stamp()).construct_struct(args()),
+        Err(RuntimeConstructError::ForeignValue { .. })
+    ));
+    assert!(matches!(
+        reflectix::frozen::Frozen::new(stamp()).construct_struct(args()),
+        Err(RuntimeConstructError::ForeignValue { .. })
+    ));
+}
@@ -0,0 +1,59 @@
+#![cfg(feature = "metrics")]
+
+use std::sync::Arc;
+
+use reflectix::metrics::{self, Counters, Metric};
+use reflectix::registry::Registry;
+use reflectix::*;
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Account {
+    pub owner: String,
+    pub balance: u64,
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Bank {
+    pub account: Account,
+}
+
+// sink is global, so everything is checked in single test
+#[test]
+fn test_counters() {
+    let counters = Arc::new(Counters::new());
+    metrics::set_sink(counters.clone());
+
+    let mut bank = Bank::default();
+    let account = bank.field("account".into()).unwrap();
+    assert!(account.field("owner".into()).is_ok());
+    assert!(account.field("missing".into()).is_err());
+    assert!(account
+        .field("balance".into())
+        .unwrap()
+        .try_downcast_ref::<String>()
+        .is_err());
+    let bank_dyn: &mut dyn TypeInfoDynamic = &mut bank;
+    assert!(bank_dyn
+        .field_by_name_mut("ACCOUNT", NameMatching::Lenient)
+        .is_ok());
+
+    let mut registry = Registry::new();
+    registry.register_default::<Account>().unwrap();
+    let registration = registry.get("Account").unwrap();
+    assert!(registration.construct_struct(Vec::new()).is_err());
+    assert!(registration
+        .construct_struct(vec![Box::new("ferris".to_string()), Box::new(1u64)])
+        .is_ok());
+
+    assert_eq!(counters.get(Account::INFO, Metric::FieldLookup), 3);
+    assert_eq!(counters.get(Account::INFO, Metric::LookupFailure), 1);
+    assert_eq!(counters.get(Bank::INFO, Metric::FieldLookup), 1);
+    assert_eq!(counters.get(u64::INFO, Metric::DowncastFailure), 1);
+    assert_eq!(counters.get(Account::INFO, Metric::ConstructError), 1);
+    assert_eq!(counters.snapshot()[0], ("Account", Metric::FieldLookup, 3));
+
+    metrics::clear_sink();
+    let account = bank.field("account".into()).unwrap();
+    assert!(account.field("missing".into()).is_err());
+    assert_eq!(counters.get(Account::INFO, Metric::LookupFailure), 1);
+}
@@ -1,3 +1,5 @@
+use reflectix::TypeInfoDynamic;
+
 #[derive(reflectix::TypeInfo, Default)]
 pub struct Foo {
     pub x: i32,
@@ -19,4 +21,239 @@ pub fn test_erased_mutation() {
     modify_field_of_erased(erased);
 
     assert_eq!(foo.x, 42);
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn test_update_field() {
+    let mut foo = Foo::default();
+    let erased: &mut dyn reflectix::TypeInfoDynamic = &mut foo;
+
+    erased
+        .update_field("y".into(), |y: &mut i32| *y += 5)
+        .unwrap();
+    assert!(matches!(
+        erased.update_field("y".into(), |_: &mut u32| ()),
+        Err(reflectix::FieldAccessError::UnmatchingType)
+    ));
+
+    assert_eq!(foo.y, 5);
+}
+
+#[test]
+pub fn test_try_downcast() {
+    let mut foo = Foo::default();
+
+    let error = foo
+        .field("x".into())
+        .unwrap()
+        .try_downcast_ref::<String>()
+        .unwrap_err();
+    assert_eq!(error.expected, "alloc::string::String");
+    assert_eq!(error.actual, "i32");
+
+    let field = foo.field_mut("y".into()).unwrap();
+    *field.try_downcast_mut::<i32>().unwrap() = 3;
+    assert_eq!(foo.y, 3);
+}
+
+#[test]
+pub fn test_field_handle() {
+    use reflectix::TypeInfo;
+
+    let y = Foo::INFO.handle("y").unwrap();
+    assert_eq!(y.field().id, reflectix::FieldId::Named("y"));
+
+    let mut foo = Foo::default();
+    y.set(&mut foo, 7i32).unwrap();
+    assert_eq!(foo.y, 7);
+    assert_eq!(y.get(&foo).unwrap().downcast_ref::<i32>(), Some(&7));
+
+    assert!(matches!(
+        y.set(&mut foo, 7u8),
+        Err(reflectix::FieldAccessError::UnmatchingType)
+    ));
+    assert!(matches!(
+        y.get(&0u8),
+        Err(reflectix::FieldAccessError::UnmatchingType)
+    ));
+    assert!(Foo::INFO.handle("z").is_none());
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Profile {
+    pub nickname: Option<String>,
+    pub age: u8,
+}
+
+#[test]
+pub fn test_optional_fields() {
+    let mut profile = Profile::default();
+    let erased: &mut dyn reflectix::TypeInfoDynamic = &mut profile;
+
+    erased
+        .set_field("nickname".into(), Box::new("ferris".to_string()))
+        .unwrap();
+    erased
+        .set_field("nickname".into(), Box::new(Some("crab".to_string())))
+        .unwrap();
+    assert!(matches!(
+        erased.set_field("nickname".into(), Box::new(5u8)),
+        Err(reflectix::FieldAccessError::UnmatchingType)
+    ));
+    assert!(matches!(
+        erased.clear_field("age".into()),
+        Err(reflectix::FieldAccessError::UnmatchingType)
+    ));
+    assert_eq!(profile.nickname.as_deref(), Some("crab"));
+
+    let erased: &mut dyn reflectix::TypeInfoDynamic = &mut profile;
+    erased.clear_field("nickname".into()).unwrap();
+    assert_eq!(profile.nickname, None);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct World {
+    pub tick: u64,
+    pub names: Vec<String>,
+}
+
+#[test]
+fn test_frozen() {
+    use reflectix::frozen::Frozen;
+    use reflectix::{FieldAccessError, TypeInfo};
+
+    let mut world = Frozen::new(World {
+        tick: 3,
+        names: vec!["ferris".to_string()],
+    });
+    assert_eq!(world.get_dynamic(), World::INFO);
+    assert_eq!(
+        world.field("tick".into()).unwrap().downcast_ref::<u64>(),
+        Some(&3)
+    );
+
+    let erased: &mut dyn TypeInfoDynamic = &mut world;
+    assert!(matches!(
+        erased.set_field("tick".into(), Box::new(4u64)),
+        Err(FieldAccessError::Frozen)
+    ));
+    assert!(matches!(
+        erased.list_push("names".into(), Box::new("crab".to_string())),
+        Err(FieldAccessError::Frozen)
+    ));
+    assert!(matches!(
+        erased.field_at_mut(0),
+        Err(FieldAccessError::Frozen)
+    ));
+    assert_eq!(world.tick, 3);
+    assert_eq!(world.into_inner().names, ["ferris"]);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Server {
+    pub hits: std::cell::Cell<u32>,
+    pub origin: std::cell::RefCell<Foo>,
+    pub state: std::sync::Arc<std::sync::Mutex<Foo>>,
+    pub config: std::sync::RwLock<Foo>,
+}
+
+#[test]
+fn test_interior_mutability() {
+    use reflectix::{FieldAccessError, TypeInfo};
+
+    let field_ty = |name| Server::INFO.find_field(name).unwrap().ty.get();
+    assert_eq!(field_ty("hits"), u32::INFO);
+    assert_eq!(field_ty("state"), Foo::INFO);
+
+    let mut server = Server::default();
+    let origin = server.field("origin".into()).unwrap();
+    assert!(matches!(
+        origin.field("x".into()),
+        Err(FieldAccessError::Shared)
+    ));
+
+    // shared access goes through guards
+    let shared = origin.as_dynamic().as_shared().unwrap();
+    {
+        let mut guard = shared.write().unwrap();
+        *guard
+            .field_mut("x".into())
+            .unwrap()
+            .downcast_mut::<i32>()
+            .unwrap() = 5;
+        assert!(matches!(shared.read(), Err(FieldAccessError::Borrowed)));
+    }
+    let guard = shared.read().unwrap();
+    assert_eq!(
+        guard.field("x".into()).unwrap().downcast_ref::<i32>(),
+        Some(&5)
+    );
+    drop(guard);
+
+    let state = server.field("state".into()).unwrap();
+    let shared = state.as_dynamic().as_shared().unwrap();
+    shared
+        .write()
+        .unwrap()
+        .set_field("y".into(), Box::new(7i32))
+        .unwrap();
+    assert_eq!(server.state.lock().unwrap().y, 7);
+
+    let hits = server.field("hits".into()).unwrap();
+    let shared = hits.as_dynamic().as_shared().unwrap();
+    assert!(matches!(shared.write(), Err(FieldAccessError::ReadOnly)));
+
+    // exclusive access doesn't need guards
+    modify_field_of_erased(server.field_mut("config".into()).unwrap().into_dynamic());
+    assert_eq!(server.config.read().unwrap().x, 42);
+    assert_eq!(server.origin.borrow().x, 5);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Counters {
+    pub total: std::sync::Mutex<u32>,
+    pub names: std::cell::RefCell<Vec<String>>,
+    pub origin: std::sync::RwLock<Foo>,
+}
+
+#[test]
+fn test_walk_interior_mutability() {
+    use reflectix::apply::ApplyError;
+    use reflectix::hash::content_hash;
+    use reflectix::{FieldAccessError, TypeInfo};
+
+    let (mut left, right) = (Counters::default(), Counters::default());
+    assert_eq!(content_hash(&left).unwrap(), content_hash(&right).unwrap());
+    assert!(reflectix::diff::diff(&left, &left).is_empty());
+
+    // values behind guards are read, rather than skipped
+    *right.total.lock().unwrap() = 2;
+    right.names.borrow_mut().push("crab".to_string());
+    right.origin.write().unwrap().x = 3;
+    assert_ne!(content_hash(&left).unwrap(), content_hash(&right).unwrap());
+    let paths = reflectix::diff::diff(&left, &right)
+        .into_iter()
+        .map(|difference| difference.path)
+        .collect::<Vec<_>>();
+    assert_eq!(paths, ["total", "names.0", "origin.x"]);
+    assert_eq!(
+        reflectix::key::extract_key(&right, "origin.x").unwrap(),
+        reflectix::key::KeyValue::Int(3)
+    );
+
+    // value, which can't be read, isn't skipped either
+    let guard = right.names.borrow_mut();
+    assert!(matches!(
+        content_hash(&right),
+        Err(FieldAccessError::Borrowed)
+    ));
+    assert!(matches!(
+        left.clone_from_dynamic(&right),
+        Err(ApplyError::Access(FieldAccessError::Borrowed))
+    ));
+    drop(guard);
+
+    left.clone_from_dynamic(&right).unwrap();
+    assert!(reflectix::diff::diff(&left, &right).is_empty());
+    assert_eq!(*left.names.borrow(), ["crab"]);
+}
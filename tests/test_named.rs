@@ -0,0 +1,113 @@
+use reflectix::named::{construct_enum_named, construct_struct_named, UnknownFields};
+use reflectix::*;
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub struct Endpoint {
+    pub host: String,
+    pub port: u16,
+    #[reflectix(default)]
+    pub retries: u8,
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub enum Event {
+    Click(i32, i32),
+    Key { code: u32 },
+}
+
+fn prototype() -> Endpoint {
+    Endpoint {
+        host: String::new(),
+        port: 0,
+        retries: 0,
+    }
+}
+
+#[test]
+fn test_construct_named() {
+    let constructed = construct_struct_named(
+        &prototype(),
+        vec![
+            ("port", Box::new(80u16)),
+            ("host", Box::new("localhost".to_string())),
+        ],
+        UnknownFields::Error,
+    )
+    .unwrap();
+    assert!(constructed.extras.is_empty());
+    assert_eq!(
+        *constructed.value.downcast::<Endpoint>().unwrap(),
+        Endpoint {
+            host: "localhost".to_string(),
+            port: 80,
+            retries: 0,
+        }
+    );
+
+    assert!(matches!(
+        construct_struct_named(
+            &prototype(),
+            vec![("port", Box::new(80u16))],
+            UnknownFields::Error
+        ),
+        Err(RuntimeConstructError::MissingField(FieldId::Named("host")))
+    ));
+
+    let click = construct_enum_named(
+        &Event::Key { code: 0 },
+        "Click",
+        vec![("1", Box::new(2i32)), ("0", Box::new(1i32))],
+        UnknownFields::Error,
+    )
+    .unwrap();
+    assert_eq!(
+        *click.value.downcast::<Event>().unwrap(),
+        Event::Click(1, 2)
+    );
+}
+
+#[test]
+fn test_unknown_fields() {
+    let args = || -> Vec<(&str, Box<dyn std::any::Any>)> {
+        vec![
+            ("host", Box::new("localhost".to_string())),
+            ("timeout", Box::new(30u64)),
+            ("port", Box::new(22u16)),
+        ]
+    };
+
+    assert!(matches!(
+        construct_struct_named(&prototype(), args(), UnknownFields::Error),
+        Err(RuntimeConstructError::UnknownField(name)) if name == "timeout"
+    ));
+
+    let ignored = construct_struct_named(&prototype(), args(), UnknownFields::Ignore).unwrap();
+    assert!(ignored.extras.is_empty());
+
+    let collected = construct_struct_named(&prototype(), args(), UnknownFields::Collect).unwrap();
+    assert_eq!(collected.value.downcast_ref::<Endpoint>().unwrap().port, 22);
+    let [(name, value)] = &collected.extras[..] else {
+        panic!("expected single extra argument");
+    };
+    assert_eq!(name, "timeout");
+    assert_eq!(value.downcast_ref::<u64>(), Some(&30));
+}
+
+#[test]
+fn test_duplicate_field() {
+    let result = construct_struct_named(
+        &prototype(),
+        vec![
+            ("host", Box::new("a".to_string())),
+            ("port", Box::new(1u16)),
+            ("host", Box::new("b".to_string())),
+        ],
+        UnknownFields::Error,
+    );
+    assert!(matches!(
+        result,
+        Err(RuntimeConstructError::DuplicateField(FieldId::Named(
+            "host"
+        )))
+    ));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "rayon")]
+
+use reflectix::diff::diff;
+use reflectix::hash::content_hash;
+use reflectix::parallel::{clone_from_all, content_hashes, deep_size_of_all, diff_pairs};
+use reflectix::size::deep_size_of;
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug, Default)]
+pub struct Row {
+    pub id: u64,
+    pub name: String,
+}
+
+fn rows(count: u64) -> Vec<Row> {
+    (0..count)
+        .map(|id| Row {
+            id,
+            name: format!("row-{}", id),
+        })
+        .collect()
+}
+
+#[test]
+fn test_parallel_matches_sequential() {
+    let left = rows(1000);
+    let mut right = left.clone();
+    right[500].name = "changed".to_string();
+
+    let differences = diff_pairs(&left, &right);
+    assert_eq!(differences.len(), left.len());
+    for (index, found) in differences.iter().enumerate() {
+        assert_eq!(found, &diff(&left[index], &right[index]));
+    }
+    assert_eq!(differences[500].len(), 1);
+
+    let hashes = content_hashes(&left).unwrap();
+    assert!(hashes
+        .iter()
+        .zip(&left)
+        .all(|(hash, row)| *hash == content_hash(row).unwrap()));
+
+    let total = left.iter().map(|row| deep_size_of(row)).sum::<usize>();
+    assert_eq!(deep_size_of_all(&left), total);
+}
+
+#[test]
+fn test_parallel_clone_from() {
+    let src = rows(256);
+    let mut dst = vec![Row::default(); src.len()];
+
+    clone_from_all(&mut dst, &src).unwrap();
+    assert_eq!(dst, src);
+
+    let names = vec![String::new(); 4];
+    assert!(clone_from_all(&mut dst, &names).is_err());
+}
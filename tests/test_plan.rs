@@ -0,0 +1,73 @@
+use reflectix::plan::{Leaf, LeafKind, Plan};
+use reflectix::TypeInfo;
+
+#[derive(reflectix::TypeInfo)]
+pub struct Position(pub f32, pub f32);
+
+#[derive(reflectix::TypeInfo)]
+pub enum Team {
+    Red,
+    Blue,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Player {
+    pub name: String,
+    pub position: Position,
+    pub team: Team,
+    pub score: u32,
+}
+
+#[test]
+fn test_plan_steps() {
+    let plan = Plan::new(Player::INFO).unwrap();
+
+    let steps = plan
+        .steps()
+        .iter()
+        .map(|step| (step.path.as_str(), step.kind))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        steps,
+        [
+            ("name", LeafKind::String),
+            ("position.0", LeafKind::F32),
+            ("position.1", LeafKind::F32),
+            ("team", LeafKind::Dynamic),
+            ("score", LeafKind::U32),
+        ]
+    );
+
+    assert!(Plan::new(Team::INFO).is_none());
+}
+
+#[test]
+fn test_plan_visit() {
+    let player = Player {
+        name: "ferris".to_string(),
+        position: Position(1.0, 2.0),
+        team: Team::Blue,
+        score: 3,
+    };
+    let plan = Plan::new(Player::INFO).unwrap();
+
+    let mut leaves = Vec::new();
+    plan.visit(&player, |step, field| match step.leaf(&field) {
+        Some(leaf) => leaves.push(leaf),
+        None => assert_eq!(field.as_dynamic().variant().unwrap().ident, "Blue"),
+    })
+    .unwrap();
+
+    let name = "ferris".to_string();
+    assert_eq!(
+        leaves,
+        [
+            Leaf::String(&name),
+            Leaf::F32(&1.0),
+            Leaf::F32(&2.0),
+            Leaf::U32(&3)
+        ]
+    );
+
+    assert!(plan.visit(&Position(0.0, 0.0), |_, _| {}).is_err());
+}
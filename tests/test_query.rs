@@ -0,0 +1,89 @@
+use reflectix::query::{from_pairs, from_query_str, to_query_string, QueryError};
+use reflectix::RuntimeConstructError;
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug, Default)]
+pub enum Order {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug, Default)]
+pub struct Page {
+    pub offset: u32,
+    pub limit: Option<u32>,
+}
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug, Default)]
+pub struct Search {
+    pub text: String,
+    pub order: Order,
+    pub page: Page,
+}
+
+#[test]
+fn test_from_query_str() {
+    let search = from_query_str(
+        "text=red+shoes%21&order=Desc&page.offset=20&page.limit=10",
+        &Search::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        *search.downcast::<Search>().unwrap(),
+        Search {
+            text: "red shoes!".to_string(),
+            order: Order::Desc,
+            page: Page {
+                offset: 20,
+                limit: Some(10),
+            },
+        }
+    );
+
+    let search = from_pairs(
+        [("page.offset", "5"), ("text", "x"), ("order", "Asc")],
+        &Search::default(),
+    )
+    .unwrap();
+    assert_eq!(search.downcast_ref::<Search>().unwrap().page.limit, None);
+}
+
+#[test]
+fn test_query_errors() {
+    let prototype = Search::default();
+    assert!(matches!(
+        from_query_str("text=a&order=Sideways", &prototype),
+        Err(QueryError::Parse { .. })
+    ));
+    assert!(matches!(
+        from_query_str("text=a&text=b", &prototype),
+        Err(QueryError::Conflict(key)) if key == "text"
+    ));
+    assert!(matches!(
+        from_query_str("text=%zz", &prototype),
+        Err(QueryError::Encoding(_))
+    ));
+    assert!(matches!(
+        from_query_str("text=a&color=red", &prototype),
+        Err(QueryError::Construct(RuntimeConstructError::UnknownField(
+            _
+        )))
+    ));
+}
+
+#[test]
+fn test_to_query_string() {
+    let search = Search {
+        text: "a&b c".to_string(),
+        order: Order::Asc,
+        page: Page {
+            offset: 0,
+            limit: None,
+        },
+    };
+    let query = to_query_string(&search).unwrap();
+    assert_eq!(query, "text=a%26b+c&order=Asc&page.offset=0");
+
+    let parsed = from_query_str(&query, &Search::default()).unwrap();
+    assert_eq!(*parsed.downcast::<Search>().unwrap(), search);
+}
@@ -0,0 +1,104 @@
+use reflectix::{Data, FieldId, TypeInfo, TypeInfoDynamic};
+
+#[derive(reflectix::TypeInfo)]
+pub struct Node {
+    pub value: u32,
+    pub next: Option<Box<Node>>,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub enum Expr {
+    Literal(i64),
+    Call(Box<Call>),
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Call {
+    pub name: String,
+    pub args: Vec<Expr>,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Tree<T> {
+    pub value: T,
+    pub children: Vec<Tree<T>>,
+}
+
+#[test]
+pub fn test_self_referential() {
+    let next = Node::INFO.find_field("next").unwrap();
+    let Data::Optional(inner) = &next.ty.data else {
+        panic!("expected optional field");
+    };
+    assert!(std::ptr::eq(inner.get(), Node::INFO));
+
+    let list = Node {
+        value: 1,
+        next: Some(Box::new(Node {
+            value: 2,
+            next: None,
+        })),
+    };
+    let second = list.field("next".into()).unwrap();
+    let value = second
+        .as_dynamic()
+        .field(FieldId::Index(0))
+        .unwrap()
+        .field("value".into())
+        .unwrap();
+    assert_eq!(value.downcast_ref::<u32>(), Some(&2));
+}
+
+#[test]
+pub fn test_mutually_recursive() {
+    assert_eq!(Expr::INFO, Expr::INFO);
+    assert_eq!(Call::INFO.resolve_path("args").unwrap().ty.ident, "Vec");
+
+    let expr = Expr::Call(Box::new(Call {
+        name: "max".to_string(),
+        args: vec![Expr::Literal(1), Expr::Literal(2)],
+    }));
+    let call = (&expr as &dyn TypeInfoDynamic).payload().unwrap();
+    assert_eq!(call.as_dynamic().get_dynamic().ident, "Call");
+}
+
+#[test]
+pub fn test_generic_recursive() {
+    let Data::List(children) = &Tree::<u8>::INFO.find_field("children").unwrap().ty.data else {
+        panic!("expected list field");
+    };
+    assert_eq!(children.get(), Tree::<u8>::INFO);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+pub fn test_recursive_schema() {
+    use reflectix::schema::{DataSchema, FieldsSchema, TypeSchema};
+
+    let schema = TypeSchema::from(Node::INFO);
+    let DataSchema::Struct(FieldsSchema::Named(fields)) = &schema.data else {
+        panic!("expected struct schema");
+    };
+    let DataSchema::Optional(inner) = &fields[1].ty.data else {
+        panic!("expected optional schema");
+    };
+    assert_eq!(inner.ident, "Node");
+    assert_eq!(inner.data, DataSchema::Recursive);
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct List<T> {
+    pub next: Option<Box<List<T>>>,
+    pub value: T,
+}
+
+#[test]
+pub fn test_generic_recursive_eq() {
+    assert_ne!(List::<i32>::INFO, List::<u32>::INFO);
+    assert_eq!(List::<i32>::INFO, List::<i32>::INFO);
+    assert_eq!(List::<i32>::INFO.clone(), *List::<i32>::INFO);
+
+    let next = &List::<i32>::INFO.find_field("next").unwrap().ty;
+    let other = &List::<u32>::INFO.find_field("next").unwrap().ty;
+    assert_ne!(next, other);
+}
@@ -0,0 +1,282 @@
+use reflectix::registry::{Registry, RegistryError};
+use reflectix::test_support;
+
+#[derive(reflectix::TypeInfo, Default, PartialEq, Debug)]
+pub struct Player {
+    pub name: String,
+    pub level: u32,
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+pub enum Team {
+    Red,
+    Blue,
+}
+
+mod other {
+    #[derive(reflectix::TypeInfo, Default)]
+    pub struct Player {
+        pub id: u64,
+    }
+}
+
+#[test]
+fn test_construct_by_name() {
+    let registry = test_support::registry()
+        .with::<Player>()
+        .with_value(Team::Red)
+        .build();
+    assert_eq!(registry.len(), 2);
+
+    let player = registry
+        .get("Player")
+        .unwrap()
+        .construct_struct(vec![Box::new("ferris".to_string()), Box::new(3u32)])
+        .unwrap();
+    assert_eq!(
+        *player.downcast::<Player>().unwrap(),
+        Player {
+            name: "ferris".to_string(),
+            level: 3
+        }
+    );
+
+    let team = registry
+        .get("Team")
+        .unwrap()
+        .construct_enum("Blue", Vec::new())
+        .unwrap();
+    assert_eq!(*team.downcast::<Team>().unwrap(), Team::Blue);
+}
+
+#[test]
+fn test_conflicts() {
+    let mut registry = Registry::new();
+    registry.register_default::<Player>().unwrap();
+
+    assert_eq!(
+        registry.register_default::<Player>(),
+        Err(RegistryError::AlreadyRegistered("Player"))
+    );
+    assert_eq!(
+        registry.register_default::<other::Player>(),
+        Err(RegistryError::NameConflict("Player"))
+    );
+    assert!(!registry.contains::<other::Player>());
+}
+
+#[test]
+fn test_isolated_registries() {
+    let first = test_support::registry().with::<Player>().build();
+    let second = test_support::registry().with::<other::Player>().build();
+
+    assert!(first.contains::<Player>());
+    assert!(!second.contains::<Player>());
+}
+
+#[test]
+fn test_merge() {
+    let mut core = test_support::registry().with::<Player>().build();
+    let plugin = test_support::registry().with_value(Team::Red).build();
+
+    core.merge(plugin).unwrap();
+    let mut names = core.iter().map(|x| x.ty().ident).collect::<Vec<_>>();
+    names.sort();
+    assert_eq!(names, ["Player", "Team"]);
+
+    let conflicting = test_support::registry()
+        .with_value(Team::Blue)
+        .with::<other::Player>()
+        .build();
+    assert!(core.merge(conflicting).is_err());
+    assert_eq!(core.len(), 2);
+    assert!(!core.contains::<other::Player>());
+}
+
+#[test]
+fn test_replace_invalidates_handles() {
+    let mut registry = test_support::registry().with::<Player>().build();
+    let handle = registry.handle("Player").unwrap();
+    assert!(registry.resolve(&handle).is_some());
+
+    // e.g. type from reloaded library, which has the same name
+    let replaced = registry.replace(other::Player::default()).unwrap();
+    assert_eq!(replaced.generation(), handle.generation());
+    assert!(registry.resolve(&handle).is_none());
+    assert!(registry.contains::<other::Player>());
+    assert!(!registry.contains::<Player>());
+
+    let handle = registry.handle("Player").unwrap();
+    assert!(registry.unregister("Player").is_some());
+    assert!(registry.resolve(&handle).is_none());
+    assert!(registry.is_empty());
+}
+
+mod foreign {
+    pub struct Uuid;
+}
+
+#[test]
+fn test_register_primitive() {
+    let mut registry = test_support::registry().with::<Player>().build();
+
+    let ty = registry
+        .register_primitive::<foreign::Uuid>("Uuid")
+        .unwrap();
+    assert_eq!(ty.ident, "Uuid");
+    assert_eq!(ty.data, reflectix::Data::Primitive);
+    assert_eq!(
+        registry.primitive(std::any::TypeId::of::<foreign::Uuid>()),
+        Some(ty)
+    );
+    assert_eq!(registry.primitive_by_name("Uuid"), Some(ty));
+
+    assert_eq!(
+        registry.register_primitive::<foreign::Uuid>("Id"),
+        Err(RegistryError::AlreadyRegistered("Id"))
+    );
+    assert_eq!(
+        registry.register_primitive::<u8>("Player"),
+        Err(RegistryError::NameConflict("Player"))
+    );
+}
+
+#[test]
+fn test_nonzero_primitives() {
+    use reflectix::{TypeInfo, TypeInfoDynamic};
+    use std::num::NonZeroU64;
+
+    let id = NonZeroU64::new(7).unwrap();
+    assert_eq!(id.get_dynamic().ident, "NonZeroU64");
+    assert_eq!(NonZeroU64::INFO.data, reflectix::Data::Primitive);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Profile {
+    pub user_name: String,
+    pub login_count: u32,
+}
+
+#[test]
+fn test_name_matching() {
+    use reflectix::{NameMatching, TypeInfo, TypeInfoDynamic};
+
+    let mut profile = Profile {
+        user_name: "ferris".to_string(),
+        login_count: 3,
+    };
+    let erased: &mut dyn TypeInfoDynamic = &mut profile;
+    assert!(erased
+        .field_by_name("userName", NameMatching::Exact)
+        .is_err());
+    assert_eq!(
+        erased
+            .field_by_name("UserName", NameMatching::Lenient)
+            .unwrap()
+            .downcast_ref::<String>()
+            .map(String::as_str),
+        Some("ferris")
+    );
+    assert!(Profile::INFO
+        .find_field_with("login-count", NameMatching::Lenient)
+        .is_some());
+
+    let mut registry = Registry::new();
+    assert!(registry.field(erased, "loginCount").is_err());
+    registry.set_name_matching(NameMatching::Lenient);
+    *registry
+        .field_mut(erased, "loginCount")
+        .unwrap()
+        .downcast_mut::<u32>()
+        .unwrap() += 1;
+    assert_eq!(profile.login_count, 4);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+#[reflectix(tag = "component", tag = "serializable")]
+pub struct Position {
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+#[reflectix(tag = "component")]
+pub struct Velocity {
+    pub dx: f32,
+}
+
+#[test]
+fn test_types_with_tag() {
+    use reflectix::TypeInfo;
+
+    assert_eq!(
+        Position::INFO.tags().collect::<Vec<_>>(),
+        ["component", "serializable"]
+    );
+    assert!(!Player::INFO.has_tag("component"));
+
+    let registry = test_support::registry()
+        .with::<Player>()
+        .with::<Position>()
+        .with::<Velocity>()
+        .build();
+    let mut components = registry
+        .types_with_tag("component")
+        .map(|registration| registration.ty().ident)
+        .collect::<Vec<_>>();
+    components.sort();
+    assert_eq!(components, ["Position", "Velocity"]);
+    assert_eq!(registry.types_with_tag("serializable").count(), 1);
+    assert_eq!(registry.types_with_tag("system").count(), 0);
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Slot<T> {
+    pub value: T,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Grid<const N: usize> {
+    pub cells: [u8; N],
+}
+
+#[test]
+fn test_generic_instantiations() {
+    let mut registry = Registry::new();
+    registry.register_default::<Slot<i32>>().unwrap();
+    registry.register_default::<Slot<String>>().unwrap();
+    registry.register_default::<Slot<Slot<u8>>>().unwrap();
+    registry.register(Grid { cells: [0; 3] }).unwrap();
+    assert_eq!(registry.len(), 4);
+
+    let slot = registry.get("Slot<String>").unwrap();
+    assert_eq!(slot.ty().full_name(), "Slot<String>");
+    assert!(registry.get("Slot<Slot<u8>>").is_some());
+    assert!(registry.get("Grid<3>").is_some());
+    assert!(registry.get("Slot").is_none());
+
+    let handle = registry.handle("Slot<i32>").unwrap();
+    assert!(registry.unregister("Slot<i32>").is_some());
+    assert!(registry.resolve(&handle).is_none());
+    assert!(registry.get("Slot<String>").is_some());
+
+    // wrapper shares metadata, and so name, of the type it wraps
+    registry.register(Box::new(1i32)).unwrap();
+    assert_eq!(
+        registry.register(1i32),
+        Err(RegistryError::NameConflict("i32"))
+    );
+}
+
+#[test]
+fn test_tuple_instantiations() {
+    use reflectix::TypeInfo;
+
+    assert_eq!(<(i32, u8)>::INFO.full_name(), "Tuple<i32, u8>");
+    assert_eq!(<(Slot<u8>,)>::INFO.full_name(), "Tuple<Slot<u8>>");
+
+    let mut registry = Registry::new();
+    registry.register((1i32, 2u8)).unwrap();
+    registry.register((String::new(),)).unwrap();
+    assert!(registry.get("Tuple<String>").is_some());
+}
@@ -0,0 +1,103 @@
+use reflectix::*;
+
+// stands for a crate, which doesn't derive `TypeInfo`
+mod geometry {
+    #[derive(PartialEq, Debug)]
+    pub struct Size {
+        pub width: u32,
+        pub height: u32,
+    }
+
+    #[derive(PartialEq, Debug)]
+    pub enum Shape {
+        Square(u32),
+        Rect { width: u32, height: u32 },
+        Empty,
+    }
+
+    #[derive(PartialEq, Debug)]
+    #[non_exhaustive]
+    pub struct Limits {
+        pub max: u32,
+    }
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+#[reflectix(remote = "geometry::Size")]
+pub struct SizeDef {
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+#[reflectix(remote = "geometry::Shape")]
+pub enum ShapeDef {
+    Square(u32),
+    Rect { width: u32, height: u32 },
+    Empty,
+}
+
+#[derive(reflectix::TypeInfo, PartialEq, Debug)]
+#[reflectix(remote = "geometry::Limits")]
+#[non_exhaustive]
+pub struct LimitsDef {
+    pub max: u32,
+}
+
+#[test]
+fn test_remote_struct() {
+    assert_eq!(SizeDef::INFO.ident, "Size");
+
+    let mut mirror = SizeDef::from(geometry::Size {
+        width: 2,
+        height: 3,
+    });
+    *mirror
+        .field_mut("height".into())
+        .unwrap()
+        .downcast_mut::<u32>()
+        .unwrap() = 5;
+    assert_eq!(
+        geometry::Size::from(mirror),
+        geometry::Size {
+            width: 2,
+            height: 5
+        }
+    );
+}
+
+#[test]
+fn test_remote_enum() {
+    assert_eq!(ShapeDef::INFO.ident, "Shape");
+
+    let mirror = ShapeDef::from(geometry::Shape::Square(4));
+    assert_eq!(mirror.variant().unwrap().ident, "Square");
+    assert_eq!(ShapeDef::from_unit_variant("Empty"), Some(ShapeDef::Empty));
+
+    let rect = mirror
+        .construct_enum("Rect", vec![Box::new(1u32), Box::new(2u32)])
+        .unwrap()
+        .downcast::<ShapeDef>()
+        .unwrap();
+    assert_eq!(
+        geometry::Shape::from(*rect),
+        geometry::Shape::Rect {
+            width: 1,
+            height: 2
+        }
+    );
+}
+
+#[test]
+fn test_remote_non_exhaustive() {
+    let mirror = LimitsDef::from(geometry::Limits { max: 10 });
+    assert!(LimitsDef::INFO.non_exhaustive);
+    assert_eq!(
+        mirror.field("max".into()).unwrap().downcast_ref::<u32>(),
+        Some(&10)
+    );
+    assert!(matches!(
+        mirror.construct_struct(vec![Box::new(1u32)]),
+        Err(RuntimeConstructError::NonExhaustive)
+    ));
+}
@@ -0,0 +1,84 @@
+use reflectix::router::{RouteError, Router};
+use reflectix::{test_support, FieldId, TypeInfo};
+
+#[derive(reflectix::TypeInfo, Default)]
+pub enum Message {
+    #[default]
+    Ping,
+    Move {
+        x: i32,
+        y: i32,
+    },
+    Chat(String),
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Login {
+    pub user: String,
+}
+
+#[test]
+fn test_dispatch() {
+    let registry = test_support::registry().with::<Message>().build();
+    let mut moves = Vec::new();
+    let mut chat = Vec::new();
+
+    {
+        let mut router = Router::new(registry.get("Message").unwrap().ty()).unwrap();
+        router
+            .on("Move", |fields| {
+                let x = *fields.get_as::<i32>("x".into()).unwrap();
+                let y = *fields.get_as::<i32>("y".into()).unwrap();
+                moves.push((x, y));
+            })
+            .unwrap();
+        router
+            .on("Chat", |fields| {
+                chat.push(fields.get_as::<String>(FieldId::Index(0)).unwrap().clone());
+            })
+            .unwrap();
+
+        router.dispatch(&Message::Move { x: 1, y: -2 }).unwrap();
+        router.dispatch(&Message::Chat("hi".to_string())).unwrap();
+        assert_eq!(
+            router.dispatch(&Message::Ping),
+            Err(RouteError::Unrouted("Ping"))
+        );
+        assert_eq!(
+            router.dispatch(&Login::default()),
+            Err(RouteError::UnmatchingType {
+                expected: "Message",
+                actual: "Login"
+            })
+        );
+    }
+
+    assert_eq!(moves, [(1, -2)]);
+    assert_eq!(chat, ["hi"]);
+}
+
+#[test]
+fn test_routing_errors() {
+    assert_eq!(
+        Router::new(Login::INFO).unwrap_err(),
+        RouteError::NotEnum("Login")
+    );
+
+    let mut router = Router::new(Message::INFO).unwrap();
+    router
+        .on("Ping", |fields| assert!(fields.is_empty()))
+        .unwrap();
+    assert!(router.is_routed("Ping"));
+    assert_eq!(
+        router.on("Ping", |_| {}),
+        Err(RouteError::AlreadyRouted("Ping"))
+    );
+    assert_eq!(
+        router.on("Pong", |_| {}),
+        Err(RouteError::UnknownVariant {
+            ty: "Message",
+            variant: "Pong".to_string()
+        })
+    );
+    router.dispatch(&Message::Ping).unwrap();
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "serde")]
+use reflectix::schema::TypeSchema;
+use reflectix::TypeInfo;
+
+#[derive(reflectix::TypeInfo)]
+pub struct Inner {
+    pub value: u64,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Outer {
+    pub name: String,
+    pub inner: Inner,
+}
+
+#[test]
+pub fn test_schema_round_trip() {
+    let serialized = serde_json::to_string(Outer::INFO).unwrap();
+    let schema: TypeSchema = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(schema, TypeSchema::from(Outer::INFO));
+    assert_eq!(schema.ident, "Outer");
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Wrapper<T> {
+    pub value: T,
+}
+
+#[test]
+pub fn test_schema_generics() {
+    use reflectix::schema::GenericParamKindSchema;
+
+    let numbers = TypeSchema::from(<Wrapper<i32>>::INFO);
+    let strings = TypeSchema::from(<Wrapper<String>>::INFO);
+    assert_eq!(numbers.ident, strings.ident);
+    assert_ne!(numbers, strings);
+    assert!(matches!(
+        &numbers.generics[0].kind,
+        GenericParamKindSchema::Type(argument) if argument.ident == "i32"
+    ));
+
+    let serialized = serde_json::to_string(<Wrapper<i32>>::INFO).unwrap();
+    let schema: TypeSchema = serde_json::from_str(&serialized).unwrap();
+    assert_eq!(schema, numbers);
+}
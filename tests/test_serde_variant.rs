@@ -0,0 +1,74 @@
+#![cfg(feature = "serde")]
+use reflectix::{Data, TypeInfo, UnknownVariants};
+
+#[derive(reflectix::TypeInfo, Debug, PartialEq)]
+#[reflectix(deny_unknown_variants, rename_all = "lowercase")]
+pub enum Color {
+    Red,
+    Green,
+    Custom(u32),
+}
+
+#[derive(reflectix::TypeInfo, Debug, PartialEq)]
+#[reflectix(unknown_variants = "skip")]
+pub enum Permission {
+    Read,
+    Write,
+}
+
+#[derive(reflectix::TypeInfo, Debug, PartialEq)]
+#[reflectix(fallback_variant = "Other")]
+pub enum Platform {
+    Linux,
+    Windows,
+    Other,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+pub struct Settings {
+    #[serde(with = "reflectix::serde_variant")]
+    pub color: Color,
+    #[serde(with = "reflectix::serde_variant")]
+    pub platform: Platform,
+    #[serde(with = "reflectix::serde_variant::seq")]
+    pub permissions: Vec<Permission>,
+}
+
+#[test]
+fn test_from_variant_name() {
+    assert_eq!(Color::from_variant_name("green").unwrap(), Color::Green);
+
+    let error = Color::from_variant_name("blue").unwrap_err();
+    assert_eq!(error.expected, ["red", "green"]);
+    assert_eq!(
+        error.to_string(),
+        "Enum `Color` has no unit variant `blue`, expected one of: red, green"
+    );
+
+    let Data::Enum(variants) = &Platform::INFO.data else {
+        panic!("Platform is not an enum");
+    };
+    assert_eq!(variants.unknown, UnknownVariants::Fallback("Other"));
+}
+
+#[test]
+fn test_unknown_variants() {
+    let json = r#"{"color":"red","platform":"Haiku","permissions":["Read","Execute","Write"]}"#;
+    let settings: Settings = serde_json::from_str(json).unwrap();
+    assert_eq!(
+        settings,
+        Settings {
+            color: Color::Red,
+            platform: Platform::Other,
+            permissions: vec![Permission::Read, Permission::Write],
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&settings).unwrap(),
+        r#"{"color":"red","platform":"Other","permissions":["Read","Write"]}"#
+    );
+
+    let json = r#"{"color":"blue","platform":"Linux","permissions":[]}"#;
+    let error = serde_json::from_str::<Settings>(json).unwrap_err();
+    assert!(error.to_string().contains("expected one of: red, green"));
+}
@@ -0,0 +1,33 @@
+use std::collections::HashMap;
+use std::mem::size_of;
+
+use reflectix::size::deep_size_of;
+
+#[derive(reflectix::TypeInfo)]
+pub struct Document {
+    pub title: String,
+    pub pages: Vec<u32>,
+    pub cover: Option<String>,
+    pub index: HashMap<String, u64>,
+}
+
+#[test]
+fn test_deep_size_of() {
+    assert_eq!(deep_size_of(&5u64), 8);
+
+    let mut title = String::with_capacity(16);
+    title.push_str("manual");
+    let document = Document {
+        title,
+        pages: vec![1, 2, 3],
+        cover: Some("red".to_string()),
+        index: HashMap::from([("intro".to_string(), 1)]),
+    };
+
+    let expected = size_of::<Document>()
+        + 16
+        + 3 * size_of::<u32>()
+        + 3
+        + (size_of::<String>() + 5 + size_of::<u64>());
+    assert_eq!(deep_size_of(&document), expected);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "tracing")]
+
+use std::sync::{Arc, Mutex};
+
+use reflectix::trace::{record_fields, REDACTED};
+use tracing::field::{Empty, Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+// Remembers every recorded field as `name=value`
+#[derive(Clone, Default)]
+struct Recorder {
+    records: Arc<Mutex<Vec<String>>>,
+}
+
+impl Visit for Recorder {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.records
+            .lock()
+            .unwrap()
+            .push(format!("{}={:?}", field.name(), value));
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _span: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _span: &Id, values: &Record<'_>) {
+        values.record(&mut self.clone());
+    }
+
+    fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &Id) {}
+
+    fn exit(&self, _span: &Id) {}
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Address {
+    pub city: String,
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Login {
+    pub user: String,
+    #[reflectix(meta(redact = "true"))]
+    pub password: String,
+    pub attempts: u32,
+    pub address: Option<Address>,
+}
+
+#[test]
+fn test_record_fields() {
+    let recorder = Recorder::default();
+    let login = Login {
+        user: "ferris".to_string(),
+        password: "hunter2".to_string(),
+        attempts: 3,
+        address: Some(Address {
+            city: "Berlin".to_string(),
+        }),
+    };
+
+    tracing::subscriber::with_default(recorder.clone(), || {
+        let span = tracing::info_span!(
+            "login",
+            user = Empty,
+            password = Empty,
+            attempts = Empty,
+            address.city = Empty
+        );
+        record_fields(&span, &login);
+    });
+
+    let records = recorder.records.lock().unwrap();
+    assert_eq!(
+        *records,
+        [
+            "user=\"ferris\"".to_string(),
+            format!("password={:?}", REDACTED),
+            "attempts=3".to_string(),
+            "address.city=\"Berlin\"".to_string(),
+        ]
+    );
+}
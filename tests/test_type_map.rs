@@ -0,0 +1,44 @@
+use reflectix::type_map::TypeMap;
+
+#[derive(reflectix::TypeInfo, Default, PartialEq, Debug)]
+pub struct Gravity {
+    pub strength: f32,
+}
+
+#[derive(reflectix::TypeInfo, Default, PartialEq, Debug)]
+pub struct Score(pub u64);
+
+#[test]
+fn test_typed_access() {
+    let mut map = TypeMap::new();
+    assert!(map.insert(Score(1)).is_none());
+    assert_eq!(map.insert(Score(2)), Some(Score(1)));
+    map.insert(Gravity { strength: 9.8 });
+
+    assert_eq!(map.len(), 2);
+    map.get_mut::<Score>().unwrap().0 += 1;
+    assert_eq!(map.get::<Score>(), Some(&Score(3)));
+    assert_eq!(map.remove::<Score>(), Some(Score(3)));
+    assert!(!map.contains::<Score>());
+}
+
+#[test]
+fn test_reflective_access() {
+    let mut map = TypeMap::new();
+    map.insert(Gravity { strength: 9.8 });
+
+    let gravity = map.get_by_name_mut("Gravity").unwrap();
+    gravity
+        .set_field("strength".into(), Box::new(1.6f32))
+        .unwrap();
+
+    let gravity = map.get_dynamic(std::any::TypeId::of::<Gravity>()).unwrap();
+    assert_eq!(
+        gravity
+            .field("strength".into())
+            .unwrap()
+            .downcast_ref::<f32>(),
+        Some(&1.6)
+    );
+    assert!(map.get_by_name("Score").is_none());
+}
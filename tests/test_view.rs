@@ -0,0 +1,140 @@
+use reflectix::view::{view_bytes, ViewError};
+use reflectix::{FieldId, TypeInfo};
+
+#[derive(reflectix::TypeInfo, Clone, Copy, Default)]
+#[repr(C)]
+pub struct Vec3 {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+#[derive(reflectix::TypeInfo, Clone, Copy, Default)]
+#[repr(C)]
+pub struct Packet {
+    pub kind: u8,
+    pub sequence: u32,
+    pub position: Vec3,
+    pub flags: [u16; 2],
+}
+
+#[derive(reflectix::TypeInfo, Default)]
+pub struct Unordered {
+    pub a: u8,
+    pub b: u64,
+}
+
+fn packet_bytes(packet: &Packet) -> Vec<u64> {
+    // u64 storage keeps buffer aligned
+    let mut storage = vec![0u64; std::mem::size_of::<Packet>().div_ceil(8)];
+    unsafe {
+        std::ptr::copy_nonoverlapping(
+            (packet as *const Packet).cast::<u8>(),
+            storage.as_mut_ptr().cast::<u8>(),
+            std::mem::size_of::<Packet>(),
+        );
+    }
+    storage
+}
+
+#[test]
+fn test_view_fields() {
+    let packet = Packet {
+        kind: 3,
+        sequence: 77,
+        position: Vec3 {
+            x: 1.0,
+            y: 2.5,
+            z: -4.0,
+        },
+        flags: [1, 0xffff],
+    };
+    assert_eq!(
+        Packet::INFO.find_field("sequence").unwrap().offset,
+        Some(std::mem::offset_of!(Packet, sequence))
+    );
+
+    let storage = packet_bytes(&packet);
+    let bytes =
+        unsafe { std::slice::from_raw_parts(storage.as_ptr().cast::<u8>(), storage.len() * 8) };
+    let view = view_bytes(bytes, Packet::INFO).unwrap();
+
+    assert_eq!(view.field("kind".into()).unwrap().read::<u8>(), Ok(3));
+    assert_eq!(view.field("sequence".into()).unwrap().read::<u32>(), Ok(77));
+    let position = view.field("position".into()).unwrap();
+    assert_eq!(position.field("y".into()).unwrap().read::<f32>(), Ok(2.5));
+    let flags = view.field("flags".into()).unwrap();
+    assert_eq!(
+        flags.field(FieldId::Index(1)).unwrap().read::<u16>(),
+        Ok(0xffff)
+    );
+
+    assert_eq!(
+        view.field("sequence".into()).unwrap().read::<i64>(),
+        Err(ViewError::UnmatchingType {
+            expected: "i64",
+            actual: "u32"
+        })
+    );
+    assert_eq!(
+        flags.field(FieldId::Index(2)).unwrap_err(),
+        ViewError::NotFound(FieldId::Index(2))
+    );
+}
+
+#[test]
+fn test_view_checks() {
+    let storage = [0u64; 8];
+    let bytes = unsafe { std::slice::from_raw_parts(storage.as_ptr().cast::<u8>(), 64) };
+
+    assert_eq!(
+        view_bytes(&bytes[..4], Packet::INFO).unwrap_err(),
+        ViewError::TooShort {
+            expected: std::mem::size_of::<Packet>(),
+            actual: 4
+        }
+    );
+    assert_eq!(
+        view_bytes(&bytes[1..], Packet::INFO).unwrap_err(),
+        ViewError::Misaligned(4)
+    );
+    assert_eq!(
+        view_bytes(bytes, Unordered::INFO).unwrap_err(),
+        ViewError::NoLayout("Unordered")
+    );
+}
+
+#[derive(reflectix::TypeInfo)]
+#[repr(C)]
+#[reflectix(endian = "big")]
+pub struct WireHeader {
+    pub magic: u32,
+    #[reflectix(endian = "little")]
+    pub length: u16,
+    pub checksum: u16,
+}
+
+#[test]
+fn test_view_endian() {
+    use reflectix::view::Endian;
+
+    let storage: [u32; 2] = [
+        u32::from_ne_bytes([0xca, 0xfe, 0xba, 0xbe]),
+        u32::from_ne_bytes([0x10, 0x00, 0x12, 0x34]),
+    ];
+    let bytes = unsafe { std::slice::from_raw_parts(storage.as_ptr().cast::<u8>(), 8) };
+    let view = view_bytes(bytes, WireHeader::INFO).unwrap();
+    assert_eq!(view.endian(), Endian::Big);
+
+    let magic = view.field("magic".into()).unwrap();
+    assert_eq!(magic.read::<u32>(), Ok(0xcafebabe));
+    assert_eq!(magic.read_with::<u32>(Endian::Little), Ok(0xbebafeca));
+
+    let length = view.field("length".into()).unwrap();
+    assert_eq!(length.endian(), Endian::Little);
+    assert_eq!(length.read::<u16>(), Ok(16));
+    assert_eq!(
+        view.field("checksum".into()).unwrap().read::<u16>(),
+        Ok(0x1234)
+    );
+}
@@ -0,0 +1,144 @@
+#![cfg(feature = "xml")]
+
+use reflectix::registry::Registry;
+use reflectix::xml::{from_xml, to_xml, XmlError};
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug, Default)]
+pub struct Address {
+    pub city: String,
+    pub zip: Option<u32>,
+}
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug, Default)]
+pub enum Role {
+    #[default]
+    Guest,
+    Member(u32),
+}
+
+#[derive(reflectix::TypeInfo, Clone, PartialEq, Debug, Default)]
+pub struct Customer {
+    #[reflectix(xml_attr)]
+    pub id: u64,
+    #[reflectix(xml_attr)]
+    pub nickname: Option<String>,
+    pub name: String,
+    pub role: Role,
+    pub addresses: Vec<Address>,
+}
+
+fn customer() -> Customer {
+    Customer {
+        id: 7,
+        nickname: None,
+        name: "Tom & Jerry".to_string(),
+        role: Role::Member(3),
+        addresses: vec![
+            Address {
+                city: "Berlin".to_string(),
+                zip: Some(10115),
+            },
+            Address {
+                city: "Oslo".to_string(),
+                zip: None,
+            },
+        ],
+    }
+}
+
+fn registry() -> Registry {
+    let mut registry = Registry::new();
+    registry.register_default::<Address>().unwrap();
+    registry.register_default::<Role>().unwrap();
+    registry
+}
+
+#[test]
+fn test_xml_roundtrip() {
+    let customer = customer();
+    let xml = to_xml(&customer).unwrap();
+    assert_eq!(
+        xml,
+        "<Customer id=\"7\"><name>Tom &amp; Jerry</name><role><Member><_0>3</_0></Member></role>\
+         <addresses><item><city>Berlin</city><zip>10115</zip></item><item><city>Oslo</city></item></addresses>\
+         </Customer>"
+    );
+
+    let parsed = from_xml(&registry(), &Customer::default(), &xml).unwrap();
+    assert_eq!(*parsed.downcast::<Customer>().unwrap(), customer);
+}
+
+#[test]
+fn test_xml_input() {
+    let xml = r#"<?xml version="1.0"?>
+        <Customer id="1" nickname="tj">
+            <!-- pretty-printed input -->
+            <name>Tom</name>
+            <role><Guest/></role>
+            <addresses/>
+        </Customer>"#;
+    let parsed = from_xml(&registry(), &Customer::default(), xml).unwrap();
+    let parsed = parsed.downcast::<Customer>().unwrap();
+    assert_eq!(parsed.nickname.as_deref(), Some("tj"));
+    assert_eq!(parsed.role, Role::Guest);
+    assert!(parsed.addresses.is_empty());
+
+    assert!(matches!(
+        from_xml(&registry(), &Customer::default(), "<Address/>"),
+        Err(XmlError::UnexpectedElement { .. })
+    ));
+    // nested types can't be constructed without prototypes
+    let xml = "<Customer id=\"1\"><name/><role><Guest/></role><addresses><item><city>A</city></item></addresses></Customer>";
+    assert!(matches!(
+        from_xml(&Registry::new(), &Customer::default(), xml),
+        Err(XmlError::NoPrototype("Address"))
+    ));
+}
+
+mod secret {
+    #[derive(reflectix::TypeInfo)]
+    #[reflectix(public_only)]
+    #[allow(dead_code)]
+    pub struct Credentials {
+        pub user: String,
+        password: String,
+    }
+
+    impl Credentials {
+        pub fn new(user: &str, password: &str) -> Self {
+            Self {
+                user: user.to_string(),
+                password: password.to_string(),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_xml_inaccessible_field() {
+    // private field can't be read, so it's not silently left out of output
+    let credentials = secret::Credentials::new("root", "hunter2");
+    assert!(matches!(
+        to_xml(&credentials),
+        Err(XmlError::Access(reflectix::FieldAccessError::Private))
+    ));
+}
+
+#[derive(reflectix::TypeInfo)]
+pub struct Session {
+    pub address: std::sync::Mutex<Address>,
+}
+
+#[test]
+fn test_xml_interior_mutability() {
+    let session = Session {
+        address: std::sync::Mutex::new(Address {
+            city: "Oslo".to_string(),
+            zip: Some(150),
+        }),
+    };
+    assert_eq!(
+        to_xml(&session).unwrap(),
+        "<Session><address><city>Oslo</city><zip>150</zip></address></Session>"
+    );
+}